@@ -1,29 +1,78 @@
 struct Camera {
     view_proj: mat4x4<f32>,
-    // Será usado quando for implementado texturas e sistemas de iluminação
-    view_position: vec4<f32>
+    // Posição da câmera no espaço do mundo, usada pelos sistemas de iluminação
+    view_position: vec4<f32>,
+    // As matrizes de visão e de projeção separadas, cujo produto é `view_proj`
+    view: mat4x4<f32>,
+    proj: mat4x4<f32>
+}
+
+struct Material {
+    // Cor refletida igualmente em todas as direções, multiplicada pela cor do vértice
+    diffuse: vec4<f32>,
+    // Cor do brilho refletido na direção da câmera
+    specular: vec4<f32>,
+    shininess: f32
 }
 
 struct Uniforms {
     // Objeto que possui a matriz de transformação da câmera + a posição dela no espaço.
     camera: Camera,
 
-    // Matriz de transformação do modelo.
-    // Será utilizada para transformar os vértices do modelo para o espaço de tela.
-    model: mat4x4<f32>
+    // Matriz de transformação do modelo, própria de cada desenho (o buffer possui um objeto
+    // uniforms por desenho, escolhido por um deslocamento dinâmico).
+    // É utilizada para transformar os vértices do modelo para o espaço do mundo.
+    model: mat4x4<f32>,
+
+    // Inversa transposta de `model`, usada para levar as normais para o espaço do mundo.
+    normal: mat4x4<f32>,
+
+    // Material do desenho, usado somente pelo pipeline iluminado.
+    material: Material
 }
 
 @group(0) @binding(0)
 var<uniform> uniforms: Uniforms;
 
+const LIGHT_DIRECTIONAL: u32 = 0u;
+const LIGHT_POINT: u32 = 1u;
+const LIGHT_SPOT: u32 = 2u;
+
+struct Light {
+    position: vec4<f32>,
+    // Direção normalizada para onde a luz aponta
+    direction: vec4<f32>,
+    // Cor já multiplicada pela intensidade
+    color: vec4<f32>,
+    // Termos constante, linear e quadrático da atenuação
+    attenuation: vec4<f32>,
+    kind: u32,
+    // Cossenos dos ângulos interno e externo do cone do holofote
+    inner_cos: f32,
+    outer_cos: f32
+}
+
+struct Lights {
+    // Soma das luzes ambientes
+    ambient: vec4<f32>,
+    count: u32,
+    lights: array<Light, 8>
+}
+
+@group(1) @binding(0)
+var<uniform> lights: Lights;
+
 struct VertexInput {
     @location(0) position: vec3<f32>,
-    @location(1) color: vec3<f32>
+    @location(1) color: vec3<f32>,
+    @location(2) normal: vec3<f32>
 };
 
 struct VertexOutput {
     @builtin(position) clip_space: vec4<f32>,
-    @location(0) color: vec3<f32>
+    @location(0) color: vec3<f32>,
+    @location(1) world_position: vec3<f32>,
+    @location(2) normal: vec3<f32>
 };
 
 @vertex
@@ -32,12 +81,20 @@ fn vs_main(
 ) -> VertexOutput {
     var out: VertexOutput;
     out.color = model.color;
-    let world_space = vec4<f32>(model.position, 1.0);
+    let world_space = uniforms.model * vec4<f32>(model.position, 1.0);
 
     // Transforma o vértice para o espaço de tela
-    // out.clip_space = camera.view_proj * world_space;
+    out.clip_space = uniforms.camera.view_proj * world_space;
+
+    out.world_position = world_space.xyz;
 
-    out.clip_space = uniforms.camera.view_proj * uniforms.model * world_space;
+    // A matriz das normais não preserva o comprimento. Vértices sem normal continuam com a
+    // normal zero
+    let normal = (uniforms.normal * vec4<f32>(model.normal, 0.0)).xyz;
+    out.normal = vec3<f32>(0.0);
+    if (length(normal) > 0.0) {
+        out.normal = normalize(normal);
+    }
 
     return out;
 }
@@ -45,4 +102,64 @@ fn vs_main(
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     return vec4<f32>(in.color, 1.0);
-}
\ No newline at end of file
+}
+
+// Ilumina a superfície com o modelo de Blinn-Phong, somando a luz ambiente às contribuições
+// difusa e especular de cada luz.
+@fragment
+fn fs_lit(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Sem normal no vértice, usa a normal do triângulo, calculada pela variação da posição
+    // entre os pixels vizinhos
+    let face_normal = normalize(cross(dpdy(in.world_position), dpdx(in.world_position)));
+
+    var normal = face_normal;
+    if (length(in.normal) > 0.0001) {
+        normal = normalize(in.normal);
+    }
+
+    // Nas projeções ortográficas, todos os raios da câmera são paralelos, e `view_position`
+    // já é a direção para a câmera
+    let camera = uniforms.camera;
+    var view_dir = normalize(camera.view_position.xyz);
+    if (camera.view_position.w != 0.0) {
+        view_dir = normalize(camera.view_position.xyz - in.world_position);
+    }
+    let material = uniforms.material;
+    let base_color = in.color * material.diffuse.rgb;
+
+    var color = lights.ambient.rgb * base_color;
+
+    for (var i = 0u; i < lights.count; i = i + 1u) {
+        let light = lights.lights[i];
+
+        var light_dir = -light.direction.xyz;
+        var attenuation = 1.0;
+
+        if (light.kind != LIGHT_DIRECTIONAL) {
+            let to_light = light.position.xyz - in.world_position;
+            let distance = length(to_light);
+            light_dir = to_light / distance;
+
+            let factors = light.attenuation;
+            attenuation = 1.0 / (factors.x + factors.y * distance + factors.z * distance * distance);
+
+            if (light.kind == LIGHT_SPOT) {
+                let cos_angle = dot(-light_dir, light.direction.xyz);
+                attenuation = attenuation * smoothstep(light.outer_cos, light.inner_cos, cos_angle);
+            }
+        }
+
+        let diffuse = max(dot(normal, light_dir), 0.0);
+
+        // A superfície de costas para a luz não recebe brilho especular
+        var specular = 0.0;
+        if (diffuse > 0.0) {
+            let half_dir = normalize(light_dir + view_dir);
+            specular = pow(max(dot(normal, half_dir), 0.0), material.shininess);
+        }
+
+        color = color + light.color.rgb * attenuation * (diffuse * base_color + specular * material.specular.rgb);
+    }
+
+    return vec4<f32>(color, 1.0);
+}
@@ -1,78 +1,151 @@
 use std::time::Instant;
 
-use cgdraw_core::{graphic::Texture, uniforms::UniformsFloat32};
+use cgdraw_core::{
+    color::SrgbColor,
+    graphic::{DepthMode, PresentMode},
+    uniforms::UniformsFloat32,
+};
 use cgdraw_render::{Render, RenderState};
 use cgdraw_state::State;
-use cgdraw_ui::window::{Window, WindowEvent};
-
-use crate::{events::AppEvent, graphics::Graphics, handler::AppHandler};
-
-pub struct App {}
+use cgdraw_ui::window::{Window, WindowConfig, WindowControl, WindowEvent};
+
+use crate::{
+    events::AppEvent,
+    graphics::{CullingStats, Graphics},
+    handler::AppHandler,
+    input::InputState,
+};
+
+pub struct App {
+    pub(crate) window_config: WindowConfig,
+    pub(crate) present_mode: PresentMode,
+    pub(crate) depth_mode: DepthMode,
+    pub(crate) clear_color: Option<SrgbColor>,
+}
 
 impl App {
     async fn run_async<F>(self, mut event_handler: F) -> !
     where
         F: 'static + FnMut(AppEvent),
     {
-        let window = Window::default();
-
-        let mut state = State::new(&window.window, UniformsFloat32::default()).await;
+        let window = Window::new(&self.window_config);
+
+        let mut state = State::new(
+            &window.window,
+            UniformsFloat32::default(),
+            self.present_mode,
+        )
+        .await;
+
+        state.set_depth_mode(self.depth_mode);
+
+        if let Some(color) = self.clear_color {
+            state.clear_color = wgpu::Color {
+                r: color.r as f64,
+                g: color.g as f64,
+                b: color.b as f64,
+                a: 1.0,
+            };
+        }
 
         let mut last_render_time = Instant::now();
 
-        window.run(move |window_event| match window_event {
-            WindowEvent::Resumed => {
-                event_handler(AppEvent::Setup);
+        let mut input = InputState::default();
 
-                state
-                    .surface
-                    .configure(&state.device, &state.surface_config);
-            }
+        let mut exit_requested = false;
 
-            WindowEvent::Redraw => {
-                let now = Instant::now();
-                let delta_time = now - last_render_time;
-                last_render_time = now;
+        let mut culling_stats = CullingStats::default();
 
-                let handler = &mut AppHandler::new(&mut state);
+        window.run(move |window_event| {
+            match window_event {
+                WindowEvent::Resumed => {
+                    event_handler(AppEvent::Setup);
 
-                event_handler(AppEvent::Update {
-                    handler,
-                    delta_time,
-                });
+                    state.configure_surface();
+                }
 
-                let mut render = Render::new(&mut state, RenderState::default());
+                WindowEvent::Redraw => {
+                    let now = Instant::now();
+                    let delta_time = now - last_render_time;
+                    last_render_time = now;
 
-                let graphics = &mut Graphics::new(&mut render.render_state, render.state);
-                event_handler(AppEvent::Draw { graphics });
+                    // As regiões das câmeras são adicionadas novamente a cada quadro
+                    state.viewports.clear();
 
-                render.build();
-            }
+                    let handler = &mut AppHandler::new(
+                        &mut state,
+                        &input,
+                        &mut exit_requested,
+                        culling_stats,
+                    );
 
-            WindowEvent::Close => event_handler(AppEvent::Finished),
-
-            WindowEvent::Resize { size } => {
-                if size.width > 0 && size.height > 0 {
-                    state.surface_config.width = size.width;
-                    state.surface_config.height = size.height;
-                    state
-                        .surface
-                        .configure(&state.device, &state.surface_config);
-                    state.depth_view =
-                        Texture::create_depth_texture(&state.device, &state.surface_config).view;
-
-                    event_handler(AppEvent::Resize {
-                        width: size.width,
-                        height: size.height,
+                    event_handler(AppEvent::Update {
+                        handler,
+                        delta_time,
                     });
+
+                    let mut render = Render::new(&mut state, RenderState::default());
+
+                    let graphics = &mut Graphics::new(&mut render.render_state, render.state);
+                    graphics.report_culling_stats(&mut culling_stats);
+                    event_handler(AppEvent::Draw { graphics });
+
+                    render.build();
+
+                    input.end_frame();
                 }
-            }
 
-            WindowEvent::KeyPressed { key_code } => {
-                event_handler(AppEvent::KeyPressed { key_code })
+                WindowEvent::Close => event_handler(AppEvent::Finished),
+
+                WindowEvent::Resize { size } => {
+                    if size.width > 0 && size.height > 0 {
+                        state.resize(size.width, size.height);
+
+                        event_handler(AppEvent::Resize {
+                            width: size.width,
+                            height: size.height,
+                        });
+                    }
+                }
+
+                WindowEvent::KeyPressed { key_code } => {
+                    input.key_pressed(key_code);
+                    event_handler(AppEvent::KeyPressed { key_code })
+                }
+
+                WindowEvent::KeyUp { key_code } => {
+                    input.key_released(key_code);
+                    event_handler(AppEvent::KeyUp { key_code })
+                }
+
+                WindowEvent::ModifiersChanged { modifiers } => input.modifiers_changed(modifiers),
+
+                WindowEvent::MouseMoved { x, y, dx, dy } => {
+                    input.mouse_moved(x, y, dx, dy);
+                    event_handler(AppEvent::MouseMoved { x, y, dx, dy })
+                }
+
+                WindowEvent::MouseButtonPressed { button, x, y } => {
+                    input.mouse_pressed(button);
+                    event_handler(AppEvent::MouseButtonPressed { button, x, y })
+                }
+
+                WindowEvent::MouseButtonReleased { button, x, y } => {
+                    input.mouse_released(button);
+                    event_handler(AppEvent::MouseButtonReleased { button, x, y })
+                }
+
+                WindowEvent::MouseWheel { delta } => {
+                    input.mouse_wheel(delta);
+                    event_handler(AppEvent::MouseWheel { delta })
+                }
             }
 
-            WindowEvent::KeyUp { key_code } => event_handler(AppEvent::KeyUp { key_code }),
+            if exit_requested {
+                WindowControl::Exit
+            } else {
+                WindowControl::Continue
+            }
         })
     }
 
@@ -1,12 +1,97 @@
+use cgdraw_core::{
+    color::SrgbColor,
+    graphic::{DepthMode, PresentMode},
+    keyboard::keys::VirtualKeyCode,
+};
+use cgdraw_ui::window::WindowConfig;
+
 use crate::App;
 
-pub struct AppBuilder {}
+pub struct AppBuilder {
+    window_config: WindowConfig,
+    present_mode: PresentMode,
+    depth_mode: DepthMode,
+    clear_color: Option<SrgbColor>,
+}
 
 impl Default for AppBuilder {
     /// Cria um novo construtor de aplicativos.
     #[inline]
     fn default() -> Self {
-        Self {}
+        Self {
+            window_config: WindowConfig::default(),
+            present_mode: PresentMode::default(),
+            depth_mode: DepthMode::default(),
+            clear_color: None,
+        }
+    }
+}
+
+/// Configurações da janela
+impl AppBuilder {
+    /// Define o título da janela.
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.window_config.title = title.into();
+        self
+    }
+
+    /// Define o tamanho inicial da área de desenho da janela, em pixels.
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.window_config.size = (width, height);
+        self
+    }
+
+    /// Define o tamanho mínimo da área de desenho da janela, em pixels.
+    pub fn min_size(mut self, width: u32, height: u32) -> Self {
+        self.window_config.min_size = Some((width, height));
+        self
+    }
+
+    /// Define se o usuário pode redimensionar a janela.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.window_config.resizable = resizable;
+        self
+    }
+
+    /// Define se a janela abre em tela cheia.
+    pub fn fullscreen(mut self, fullscreen: bool) -> Self {
+        self.window_config.fullscreen = fullscreen;
+        self
+    }
+
+    /// Define se a janela possui bordas e barra de título.
+    pub fn decorations(mut self, decorations: bool) -> Self {
+        self.window_config.decorations = decorations;
+        self
+    }
+
+    /// Define a tecla que fecha o aplicativo, por padrão `Escape`. Com `None`, o aplicativo
+    /// só é fechado pela janela ou por [`AppHandler::exit`](crate::handler::AppHandler::exit).
+    pub fn exit_key(mut self, key_code: Option<VirtualKeyCode>) -> Self {
+        self.window_config.exit_key = key_code;
+        self
+    }
+}
+
+/// Configurações da renderização
+impl AppBuilder {
+    /// Define como os quadros são apresentados na tela (sincronização vertical).
+    pub fn present_mode(mut self, present_mode: PresentMode) -> Self {
+        self.present_mode = present_mode;
+        self
+    }
+
+    /// Define o modo do teste de profundidade. Use `DepthMode::ReversedZ` junto com as
+    /// projeções `perspective_reversed_z`.
+    pub fn depth_mode(mut self, depth_mode: DepthMode) -> Self {
+        self.depth_mode = depth_mode;
+        self
+    }
+
+    /// Define a cor usada para limpar a tela no início de cada quadro.
+    pub fn clear_color<C: Into<SrgbColor>>(mut self, color: C) -> Self {
+        self.clear_color = Some(color.into());
+        self
     }
 }
 
@@ -14,6 +99,11 @@ impl AppBuilder {
     /// Constrói o aplicativo.
     #[inline]
     pub fn build(self) -> App {
-        App {}
+        App {
+            window_config: self.window_config,
+            present_mode: self.present_mode,
+            depth_mode: self.depth_mode,
+            clear_color: self.clear_color,
+        }
     }
 }
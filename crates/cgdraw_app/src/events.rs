@@ -1,12 +1,17 @@
 use std::time::Duration;
 
-use cgdraw_core::keyboard::keys::VirtualKeyCode;
+use cgdraw_core::{
+    keyboard::keys::VirtualKeyCode,
+    mouse::buttons::{MouseButton, MouseScrollDelta},
+};
 
 use crate::{graphics::Graphics, handler::AppHandler};
 
 pub enum AppEvent<'a> {
     Setup,
 
+    /// Último evento do aplicativo, enviado quando ele é fechado pela janela, pela tecla de
+    /// saída ou por `AppHandler::exit`.
     Finished,
 
     Update {
@@ -25,6 +30,39 @@ pub enum AppEvent<'a> {
         key_code: VirtualKeyCode,
     },
 
+    /// O cursor se moveu para `(x, y)`, em pixels a partir do canto superior esquerdo da
+    /// janela. `dx` e `dy` são o deslocamento em pixels desde o último movimento, com `dy`
+    /// positivo para baixo. O primeiro movimento depois que o cursor entra na janela tem
+    /// deslocamento zero.
+    MouseMoved {
+        x: f32,
+        y: f32,
+        dx: f32,
+        dy: f32,
+    },
+
+    /// Um botão do mouse foi pressionado com o cursor em `(x, y)`, em pixels a partir do
+    /// canto superior esquerdo da janela.
+    MouseButtonPressed {
+        button: MouseButton,
+        x: f32,
+        y: f32,
+    },
+
+    /// Um botão do mouse foi solto com o cursor em `(x, y)`, em pixels a partir do canto
+    /// superior esquerdo da janela.
+    MouseButtonReleased {
+        button: MouseButton,
+        x: f32,
+        y: f32,
+    },
+
+    /// A roda do mouse ou o touchpad foi rolado. A rolagem vem em linhas ou em pixels,
+    /// dependendo do dispositivo, com `y` positivo para cima.
+    MouseWheel {
+        delta: MouseScrollDelta,
+    },
+
     Resize {
         width: u32,
         height: u32,
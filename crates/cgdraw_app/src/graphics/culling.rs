@@ -0,0 +1,17 @@
+/// Quantos desenhos foram enviados para a GPU e quantos foram descartados por estarem fora
+/// do frustum da câmera em um quadro.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct CullingStats {
+    /// Malhas e passos `begin`/`end` desenhados
+    pub drawn: usize,
+
+    /// Malhas e passos `begin`/`end` descartados
+    pub culled: usize,
+}
+
+impl CullingStats {
+    /// O total de desenhos pedidos no quadro, desenhados ou não.
+    pub fn total(&self) -> usize {
+        self.drawn + self.culled
+    }
+}
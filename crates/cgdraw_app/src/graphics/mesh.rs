@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use cgdraw_core::{color::Color, graphic::Vertex, uniforms::MaterialUniformFloat32};
+use cgdraw_math::{geometry::Aabb3, matrix::Matrix4x4, point::Point3};
+use cgdraw_render::VertexBufferState;
+
+use super::Primitive;
+
+/// Os buffers de vértices e índices de uma malha, enviados para a GPU uma única vez.
+struct MeshBuffers {
+    vertex_buffer: Arc<wgpu::Buffer>,
+    index_buffer: Arc<wgpu::Buffer>,
+}
+
+impl MeshBuffers {
+    fn new(device: &wgpu::Device, vertices: &[Vertex], indices: &[u16]) -> Self {
+        Self {
+            vertex_buffer: Arc::new(Vertex::create_buffer(device, vertices)),
+            index_buffer: Arc::new(Vertex::create_buffer_for_index(device, indices)),
+        }
+    }
+}
+
+struct MeshData {
+    vertices: Vec<Vertex>,
+    indices: Vec<u16>,
+    primitive: Primitive,
+    bounds: Option<Aabb3<f32>>,
+
+    /// É `None` quando a malha foi criada sem um dispositivo do wgpu (rasterizador por
+    /// software).
+    buffers: Option<MeshBuffers>,
+}
+
+/// Uma malha (mesh) retida: os vértices são enviados para a GPU quando ela é criada e podem
+/// ser desenhados em vários quadros com `Graphics::draw_mesh`, sem serem enviados novamente.
+///
+/// Clonar a malha é barato, já que os clones compartilham os mesmos buffers.
+#[derive(Clone)]
+pub struct Mesh {
+    inner: Arc<MeshData>,
+}
+
+impl Mesh {
+    /// Cria a malha e envia os seus vértices e índices para a GPU. Caso `indices` esteja
+    /// vazio, os índices são gerados na ordem dos vértices.
+    pub fn new(
+        device: &wgpu::Device,
+        vertices: Vec<Vertex>,
+        indices: Vec<u16>,
+        primitive: Primitive,
+    ) -> Self {
+        let mut mesh = MeshData::new(vertices, indices, primitive);
+        mesh.buffers = Some(MeshBuffers::new(device, &mesh.vertices, &mesh.indices));
+
+        Self {
+            inner: Arc::new(mesh),
+        }
+    }
+
+    /// Cria a malha somente na memória da CPU, usada pelo rasterizador por software.
+    pub(crate) fn new_cpu(vertices: Vec<Vertex>, indices: Vec<u16>, primitive: Primitive) -> Self {
+        Self {
+            inner: Arc::new(MeshData::new(vertices, indices, primitive)),
+        }
+    }
+
+    /// Os vértices da malha.
+    pub fn vertices(&self) -> &[Vertex] {
+        &self.inner.vertices
+    }
+
+    /// Os índices da malha.
+    pub fn indices(&self) -> &[u16] {
+        &self.inner.indices
+    }
+
+    /// A primitiva usada para desenhar a malha.
+    pub fn primitive(&self) -> Primitive {
+        self.inner.primitive
+    }
+
+    /// A caixa que envolve os vértices da malha, antes de qualquer transformação. É `None`
+    /// quando a malha não possui vértices.
+    pub fn bounds(&self) -> Option<Aabb3<f32>> {
+        self.inner.bounds
+    }
+
+    /// Cria o estado de buffer usado para desenhar a malha com a matriz `model` e o material
+    /// da iluminação, caso haja. Caso a malha não possua buffers na GPU, eles são criados
+    /// somente para este desenho.
+    pub(crate) fn vertex_buffer_state(
+        &self,
+        device: &wgpu::Device,
+        model: Matrix4x4<f32>,
+        material: Option<MaterialUniformFloat32>,
+    ) -> VertexBufferState {
+        let (vertex_buffer, index_buffer) = match self.inner.buffers.as_ref() {
+            Some(buffers) => (buffers.vertex_buffer.clone(), buffers.index_buffer.clone()),
+            None => {
+                let buffers = MeshBuffers::new(device, self.vertices(), self.indices());
+                (buffers.vertex_buffer, buffers.index_buffer)
+            }
+        };
+
+        VertexBufferState {
+            vertex_buffer,
+            index_buffer,
+            num_elements: self.inner.indices.len() as u32,
+            primitive_topology: self.inner.primitive.to_wgpu_primitive(),
+            model: model.into(),
+            material,
+        }
+    }
+}
+
+impl MeshData {
+    fn new(vertices: Vec<Vertex>, mut indices: Vec<u16>, primitive: Primitive) -> Self {
+        if indices.is_empty() {
+            indices = (0..vertices.len() as u16).collect();
+        }
+
+        Self {
+            bounds: vertices_bounds(&vertices),
+            vertices,
+            indices,
+            primitive,
+            buffers: None,
+        }
+    }
+}
+
+/// A caixa que envolve as posições dos vértices, ou `None` caso não haja nenhum vértice.
+pub(crate) fn vertices_bounds(vertices: &[Vertex]) -> Option<Aabb3<f32>> {
+    Aabb3::from_points(vertices.iter().map(|v| Point3::from(v.position)))
+}
+
+/// Responsável por montar os vértices de uma malha, da mesma forma que um passo
+/// `begin`/`end` do `Graphics`.
+pub struct MeshBuilder {
+    color: Color,
+    normal: [f32; 3],
+    vertices: Vec<Vertex>,
+    indices: Vec<u16>,
+}
+
+impl MeshBuilder {
+    pub(crate) fn new(color: Color) -> Self {
+        Self {
+            color,
+            normal: [0.0; 3],
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+
+    /// Define a cor dos próximos vértices.
+    pub fn color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    /// Define a normal dos próximos vértices, usada pela iluminação. Sem normal, os
+    /// triângulos iluminados usam a normal da própria face.
+    pub fn normal(&mut self, x: f32, y: f32, z: f32) {
+        self.normal = [x, y, z];
+    }
+
+    /// Adiciona um novo vértice 3D à malha.
+    pub fn v3d(&mut self, x: f32, y: f32, z: f32) {
+        self.vertices.push(Vertex {
+            position: [x, y, z],
+            color: self.color.to_vector(),
+            normal: self.normal,
+        });
+    }
+
+    /// Adiciona um vértice já montado à malha.
+    pub fn vertex(&mut self, vertex: Vertex) {
+        self.vertices.push(vertex);
+    }
+
+    /// Define a lista de indices da malha. Caso não seja repassado, a lista será gerada na
+    /// ordem que os vértices foram adicionados.
+    pub fn indices(&mut self, indices: Vec<u16>) {
+        self.indices = indices;
+    }
+
+    /// Finaliza a malha, enviando os seus buffers para a GPU quando há um dispositivo.
+    pub(crate) fn build(self, device: Option<&wgpu::Device>, primitive: Primitive) -> Mesh {
+        match device {
+            Some(device) => Mesh::new(device, self.vertices, self.indices, primitive),
+            None => Mesh::new_cpu(self.vertices, self.indices, primitive),
+        }
+    }
+}
@@ -1,25 +1,50 @@
+mod culling;
+mod mesh;
 mod primitive;
 mod state;
 mod step;
 mod transform;
 
+pub use culling::*;
+pub use mesh::*;
 pub use primitive::*;
 pub use state::*;
 pub use step::*;
 pub use transform::*;
 
-use cgdraw_core::{color::Color, graphic::Vertex};
-use cgdraw_math::vector::Vec4;
+use cgdraw_core::{
+    color::Color,
+    graphic::Vertex,
+    light::{Light, Material},
+    uniforms::MaterialUniformFloat32,
+};
+use cgdraw_math::{
+    geometry::{Aabb3, Frustum},
+    matrix::Matrix4x4,
+};
 use cgdraw_render::RenderState;
+use cgdraw_software::SoftwareRenderer;
 use cgdraw_state::State;
 
+/// O destino dos passos de renderização finalizados pelo `Graphics`.
+enum GraphicsTarget<'a> {
+    /// Os passos viram buffers do wgpu que são desenhados pela GPU.
+    Gpu {
+        /// A estrutura que armazena os buffers de renderização
+        render_state: &'a mut RenderState,
+
+        /// O estado da aplicação que armazena os recursos do wgpu
+        state: &'a mut State,
+    },
+
+    /// Os passos são rasterizados imediatamente na CPU.
+    Software(&'a mut SoftwareRenderer),
+}
+
 /// Responsável por ser usado como interface para a renderização de gráficos na tela.
 pub struct Graphics<'a> {
-    /// A estrutura que armazena os buffers de renderização
-    render_state: &'a mut RenderState,
-
-    /// O estado da aplicação que armazena os recursos do wgpu
-    state: &'a mut State,
+    /// Para onde os passos de renderização são enviados
+    target: GraphicsTarget<'a>,
 
     /// A estrutura que armazena o próximo passo de renderização que
     /// irá ser adicionado aos buffers de renderização
@@ -28,6 +53,19 @@ pub struct Graphics<'a> {
     /// O estado gráfico que armazena as configurações de renderização
     graphics_state: GraphicsState,
 
+    /// Os frustums das câmeras no espaço do mundo, um por região da tela, usados para
+    /// descartar o que está fora de todas elas
+    frustums: Vec<Frustum<f32>>,
+
+    /// Indica se as malhas e passos fora do frustum são descartados
+    culling: bool,
+
+    /// Os desenhos feitos e descartados neste quadro
+    culling_stats: CullingStats,
+
+    /// Onde as estatísticas são copiadas para o `App`, que as mostra no próximo quadro
+    culling_stats_output: Option<&'a mut CullingStats>,
+
     /// Objeto que possui funções de transformações que você pode aplicar nos vértices antes
     /// de renderizá-los.
     pub t: GraphicsTransform,
@@ -37,18 +75,55 @@ pub struct Graphics<'a> {
 impl<'a> Graphics<'a> {
     /// Cria uma nova instância da estrutura `Graphics`
     pub fn new(render_state: &'a mut RenderState, state: &'a mut State) -> Self {
+        Self::with_target(GraphicsTarget::Gpu {
+            render_state,
+            state,
+        })
+    }
+
+    /// Cria uma nova instância da estrutura `Graphics` que desenha usando o rasterizador por
+    /// software, sem precisar de um adaptador do wgpu.
+    pub fn new_software(renderer: &'a mut SoftwareRenderer) -> Self {
+        Self::with_target(GraphicsTarget::Software(renderer))
+    }
+
+    /// Copia as estatísticas de descarte para `output` a cada desenho.
+    pub(crate) fn report_culling_stats(&mut self, output: &'a mut CullingStats) {
+        *output = self.culling_stats;
+        self.culling_stats_output = Some(output);
+    }
+
+    fn with_target(target: GraphicsTarget<'a>) -> Self {
         let graphics_state = GraphicsState {
             color: Color::WHITE,
+            normal: [0.0; 3],
+            material: Material::default(),
+            lighting: false,
+        };
+
+        let frustums = match &target {
+            GraphicsTarget::Gpu { state, .. } => state
+                .frame_viewports()
+                .iter()
+                .map(|viewport| Frustum::from_view_proj(&viewport.camera.view_proj.into()))
+                .collect(),
+            GraphicsTarget::Software(renderer) => vec![Frustum::from_view_proj(
+                &renderer.uniforms.camera.view_proj.into(),
+            )],
         };
 
         Self {
-            state,
-            render_state,
+            target,
             // Começa como `None` porque o primeiro passo de renderização tem que ser adicionado
             // pelo método `begin`
             buffer_step: None,
             graphics_state,
 
+            frustums,
+            culling: true,
+            culling_stats: CullingStats::default(),
+            culling_stats_output: None,
+
             t: GraphicsTransform::default(),
         }
     }
@@ -56,14 +131,13 @@ impl<'a> Graphics<'a> {
 
 /// Métodos de desenho
 impl Graphics<'_> {
-    /// Adiciona um novo vértice 3D ao passo de renderização atual.
+    /// Adiciona um novo vértice 3D ao passo de renderização atual. O vértice é transformado
+    /// na GPU pela matriz que `t` possuía quando o passo foi iniciado.
     pub fn v3d(&mut self, x: f32, y: f32, z: f32) {
-        let v4 = Vec4::new(x, y, z, 1.0);
-
-        let v3 = self.t.matrix * v4;
         let vertex = Vertex {
-            position: [v3.x, v3.y, v3.z],
+            position: [x, y, z],
             color: self.graphics_state.color.to_vector(),
+            normal: self.graphics_state.normal,
         };
 
         // Verifica se o passo de renderização atual já foi iniciado
@@ -88,6 +162,184 @@ impl Graphics<'_> {
     }
 }
 
+/// Métodos de malhas retidas
+impl Graphics<'_> {
+    /// Monta uma malha retida com a primitiva informada. Os vértices são enviados para a GPU
+    /// uma única vez, e a malha pode ser desenhada em vários quadros com `draw_mesh`.
+    pub fn build_mesh<F>(&mut self, primitive: Primitive, build: F) -> Mesh
+    where
+        F: FnOnce(&mut MeshBuilder),
+    {
+        let mut builder = MeshBuilder::new(self.graphics_state.color);
+        build(&mut builder);
+
+        let device = match &self.target {
+            GraphicsTarget::Gpu { state, .. } => Some(&state.device),
+            GraphicsTarget::Software(_) => None,
+        };
+
+        builder.build(device, primitive)
+    }
+
+    /// Desenha uma malha retida. A malha é transformada por `transform` e, em seguida, pela
+    /// matriz atual de `t`.
+    ///
+    /// A malha não é desenhada quando está totalmente fora do frustum da câmera.
+    pub fn draw_mesh(&mut self, mesh: &Mesh, transform: Matrix4x4<f32>) {
+        let model = self.t.matrix * transform;
+
+        if !self.is_visible(mesh.bounds(), &model) {
+            return;
+        }
+
+        let material = self.lit_material();
+
+        match &mut self.target {
+            GraphicsTarget::Gpu {
+                render_state,
+                state,
+            } => {
+                render_state.add_vertex_buffer_state(mesh.vertex_buffer_state(
+                    &state.device,
+                    model,
+                    material,
+                ));
+            }
+
+            GraphicsTarget::Software(renderer) => {
+                renderer.uniforms.model = model.into();
+                renderer.draw(
+                    mesh.vertices(),
+                    mesh.indices(),
+                    mesh.primitive().to_wgpu_primitive(),
+                );
+                renderer.uniforms.model = Matrix4x4::identity().into();
+            }
+        }
+    }
+}
+
+/// Métodos de descarte (culling)
+impl Graphics<'_> {
+    /// Ativa ou desativa o descarte das malhas e passos `begin`/`end` que estão totalmente
+    /// fora dos frustums de todas as câmeras. Vem ativado por padrão.
+    pub fn culling(&mut self, enabled: bool) {
+        self.culling = enabled;
+    }
+
+    /// Quantos desenhos foram feitos e descartados até agora neste quadro.
+    pub fn culling_stats(&self) -> CullingStats {
+        self.culling_stats
+    }
+
+    /// Verifica se a caixa `bounds`, transformada por `model`, está ao menos em parte dentro
+    /// do frustum, atualizando as estatísticas.
+    fn is_visible(&mut self, bounds: Option<Aabb3<f32>>, model: &Matrix4x4<f32>) -> bool {
+        let visible = match bounds {
+            Some(bounds) if self.culling => {
+                let bounds = bounds.transform(model);
+
+                self.frustums
+                    .iter()
+                    .any(|frustum| frustum.intersects_aabb(&bounds))
+            }
+            _ => true,
+        };
+
+        if visible {
+            self.culling_stats.drawn += 1;
+        } else {
+            self.culling_stats.culled += 1;
+        }
+
+        if let Some(output) = self.culling_stats_output.as_deref_mut() {
+            *output = self.culling_stats;
+        }
+
+        visible
+    }
+}
+
+/// Métodos de transformação
+impl Graphics<'_> {
+    /// Executa `draw` entre um `t.push()` e um `t.pop()`, de forma que as transformações
+    /// feitas dentro dele não afetam o que é desenhado depois.
+    ///
+    /// ```text
+    /// g.with_pushed(|g| {
+    ///     g.t.rotate_x(ombro);
+    ///     braco(g);
+    ///
+    ///     g.with_pushed(|g| {
+    ///         g.t.translate(0.0, 1.0, 0.0);
+    ///         g.t.rotate_x(cotovelo);
+    ///         antebraco(g);
+    ///     });
+    /// });
+    /// ```
+    pub fn with_pushed<F>(&mut self, draw: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.t.push();
+        draw(self);
+        self.t.pop();
+    }
+}
+
+/// Métodos de iluminação
+impl Graphics<'_> {
+    /// Adiciona uma luz ao quadro e ativa a iluminação dos próximos triângulos, como com
+    /// `lighting(true)`.
+    ///
+    /// Todos os triângulos iluminados do quadro recebem todas as luzes, inclusive as
+    /// adicionadas depois de eles serem desenhados. O rasterizador por software não simula a
+    /// iluminação.
+    ///
+    /// A iluminação usa a posição da câmera enviada por [`AppHandler::set_camera`],
+    /// [`AppHandler::add_viewport`] ou [`AppHandler::camera_view_proj`].
+    ///
+    /// # Panics
+    ///
+    /// Caso o quadro já tenha `MAX_LIGHTS` luzes que não são ambientes.
+    ///
+    /// [`AppHandler::set_camera`]: crate::handler::AppHandler::set_camera
+    /// [`AppHandler::add_viewport`]: crate::handler::AppHandler::add_viewport
+    /// [`AppHandler::camera_view_proj`]: crate::handler::AppHandler::camera_view_proj
+    pub fn light(&mut self, light: Light) {
+        if let GraphicsTarget::Gpu { render_state, .. } = &mut self.target {
+            render_state.lights.push(&light);
+        }
+
+        self.graphics_state.lighting = true;
+    }
+
+    /// Ativa ou desativa a iluminação dos próximos passos e malhas. Os triângulos iluminados
+    /// usam o modelo de Blinn-Phong com o material atual; linhas e pontos nunca são
+    /// iluminados. Vem desativada em cada quadro até a primeira luz.
+    pub fn lighting(&mut self, enabled: bool) {
+        self.graphics_state.lighting = enabled;
+    }
+
+    /// Define o material dos próximos passos e malhas iluminados.
+    pub fn material(&mut self, material: Material) {
+        self.graphics_state.material = material;
+    }
+
+    /// Define a normal dos próximos vértices, usada pela iluminação. Sem normal, os
+    /// triângulos iluminados usam a normal da própria face.
+    pub fn normal(&mut self, x: f32, y: f32, z: f32) {
+        self.graphics_state.normal = [x, y, z];
+    }
+
+    /// O material dos próximos desenhos, ou `None` quando a iluminação está desativada.
+    fn lit_material(&self) -> Option<MaterialUniformFloat32> {
+        self.graphics_state
+            .lighting
+            .then(|| self.graphics_state.material.into())
+    }
+}
+
 /// Métodos de configuração de renderização
 impl Graphics<'_> {
     /// Define a cor que será usada para renderizar os gráficos
@@ -95,7 +347,8 @@ impl Graphics<'_> {
         self.graphics_state.color = color;
     }
 
-    /// Inicia o passo de renderização gráfica.
+    /// Inicia o passo de renderização gráfica. Alterações em `t` só afetam os passos
+    /// iniciados depois delas.
     pub fn begin(&mut self, primitive: Primitive) {
         // Verifica se o passo de renderização atual já foi iniciado
         if self.buffer_step.is_some() {
@@ -107,9 +360,17 @@ impl Graphics<'_> {
 
         // Define o tipo de primitiva que será renderizada
         self.buffer_step.as_mut().unwrap().init(Some(primitive));
+
+        // Os vértices do passo serão transformados pela matriz atual
+        self.buffer_step.as_mut().unwrap().model(self.t.matrix);
+
+        // E iluminados com o material atual, caso a iluminação esteja ativada
+        let material = self.lit_material();
+        self.buffer_step.as_mut().unwrap().material(material);
     }
 
-    /// Finaliza o passo de renderização gráfica
+    /// Finaliza o passo de renderização gráfica. O passo não é desenhado quando todos os seus
+    /// vértices estão fora do frustum da câmera.
     pub fn end(&mut self) {
         // Verifica se o passo de renderização atual já foi iniciado
         if self.buffer_step.is_none() {
@@ -119,12 +380,24 @@ impl Graphics<'_> {
         // Finaliza o passo de renderização gráfica
         let mut step = self.buffer_step.take().unwrap();
 
-        // Cria o objeto de buffers que será usado para renderizar os gráficos
-        let vertex_buffer_state = step.end(&self.state.device);
+        if !self.is_visible(vertices_bounds(&step.vertices), &step.model) {
+            return;
+        }
+
+        match &mut self.target {
+            GraphicsTarget::Gpu {
+                render_state,
+                state,
+            } => {
+                // Cria o objeto de buffers que será usado para renderizar os gráficos
+                let vertex_buffer_state = step.end(&state.device);
 
-        // Adiciona o estado do buffer de vértices ao estado de renderização
-        self.render_state
-            .add_vertex_buffer_state(vertex_buffer_state);
+                // Adiciona o estado do buffer de vértices ao estado de renderização
+                render_state.add_vertex_buffer_state(vertex_buffer_state);
+            }
+
+            GraphicsTarget::Software(renderer) => step.rasterize(renderer),
+        }
 
         self.buffer_step = None;
     }
@@ -1,4 +1,5 @@
 /// A primitiva que será renderizada pelo renderizador
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Primitive {
     TriangleList,
     LineList,
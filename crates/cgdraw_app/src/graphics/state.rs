@@ -1,7 +1,16 @@
-use cgdraw_core::color::Color;
+use cgdraw_core::{color::Color, light::Material};
 
 /// Responsável por armazenar o estado global das configurações de renderização
 pub struct GraphicsState {
     /// A cor que será usada para renderizar os gráficos
     pub color: Color,
+
+    /// A normal dos próximos vértices
+    pub normal: [f32; 3],
+
+    /// O material dos próximos passos e malhas iluminados
+    pub material: Material,
+
+    /// Indica se os próximos triângulos são iluminados pelas luzes do quadro
+    pub lighting: bool,
 }
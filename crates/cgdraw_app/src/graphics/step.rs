@@ -1,5 +1,9 @@
-use cgdraw_core::graphic::Vertex;
+use std::sync::Arc;
+
+use cgdraw_core::{graphic::Vertex, uniforms::MaterialUniformFloat32};
+use cgdraw_math::matrix::Matrix4x4;
 use cgdraw_render::VertexBufferState;
+use cgdraw_software::SoftwareRenderer;
 
 use super::Primitive;
 
@@ -13,6 +17,12 @@ pub struct GraphicRenderStepBuffer {
 
     /// Os vertices que serão renderizados
     pub vertices: Vec<Vertex>,
+
+    /// A matriz `model` que transforma os vértices na GPU
+    pub model: Matrix4x4<f32>,
+
+    /// O material usado pela iluminação, ou `None` quando o passo não é iluminado
+    pub material: Option<MaterialUniformFloat32>,
 }
 
 /// Inicializa com os valores padrões
@@ -22,6 +32,8 @@ impl Default for GraphicRenderStepBuffer {
             primitive: None,
             indices: Vec::new(),
             vertices: Vec::new(),
+            model: Matrix4x4::identity(),
+            material: None,
         }
     }
 }
@@ -43,6 +55,16 @@ impl GraphicRenderStepBuffer {
         self.vertices = vertices;
     }
 
+    /// Define a matriz `model` que transforma os vértices
+    pub fn model(&mut self, model: Matrix4x4<f32>) {
+        self.model = model;
+    }
+
+    /// Define o material usado pela iluminação. Com `None`, o passo não é iluminado
+    pub fn material(&mut self, material: Option<MaterialUniformFloat32>) {
+        self.material = material;
+    }
+
     /// Inicia o passo de renderização gráfica
     pub fn init(&mut self, primitive: Option<Primitive>) {
         self.primitive = if let Some(p) = primitive {
@@ -55,13 +77,17 @@ impl GraphicRenderStepBuffer {
         self.vertices.clear();
     }
 
-    /// Limpa os dados do passo de renderização gráfica e retorna o buffer necessário para renderização
-    pub fn end(&mut self, device: &wgpu::Device) -> VertexBufferState {
-        // Verifica se os indices foram definidos
+    /// Gera os indices na ordem que os vértices foram adicionados, caso eles não tenham sido
+    /// definidos.
+    fn fill_indices(&mut self) {
         if self.indices.is_empty() {
-            // Gera os indices na ordem que os vértices foram adicionados
             self.indices = (0..self.vertices.len() as u16).collect();
         }
+    }
+
+    /// Limpa os dados do passo de renderização gráfica e retorna o buffer necessário para renderização
+    pub fn end(&mut self, device: &wgpu::Device) -> VertexBufferState {
+        self.fill_indices();
 
         // Cria o buffer de vértices
         let vertex_buffer = Vertex::create_buffer(device, &self.vertices.to_vec());
@@ -74,10 +100,12 @@ impl GraphicRenderStepBuffer {
 
         // Cria o estado do buffer de vértices
         let vertex_buffer_state = VertexBufferState {
-            vertex_buffer,
-            index_buffer,
+            vertex_buffer: Arc::new(vertex_buffer),
+            index_buffer: Arc::new(index_buffer),
             num_elements: self.indices.len() as u32,
             primitive_topology,
+            model: self.model.into(),
+            material: self.material,
         };
 
         // Limpa os dados do passo de renderização gráfica
@@ -85,7 +113,26 @@ impl GraphicRenderStepBuffer {
         self.primitive = None;
         self.indices.clear();
         self.vertices.clear();
+        self.model = Matrix4x4::identity();
+        self.material = None;
 
         vertex_buffer_state
     }
+
+    /// Rasteriza o passo de renderização gráfica na CPU e limpa os seus dados.
+    pub fn rasterize(&mut self, renderer: &mut SoftwareRenderer) {
+        self.fill_indices();
+
+        let primitive_topology = self.primitive.as_ref().unwrap().to_wgpu_primitive();
+
+        renderer.uniforms.model = self.model.into();
+        renderer.draw(&self.vertices, &self.indices, primitive_topology);
+        renderer.uniforms.model = Matrix4x4::identity().into();
+
+        self.primitive = None;
+        self.indices.clear();
+        self.vertices.clear();
+        self.model = Matrix4x4::identity();
+        self.material = None;
+    }
 }
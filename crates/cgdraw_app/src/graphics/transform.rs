@@ -1,13 +1,20 @@
-use cgdraw_math::{angle::Rad, matrix::Matrix4x4, vector::Vec3};
+use cgdraw_math::{
+    angle::Rad, euler::EulerOrder, matrix::Matrix4x4, quaternion::Quaternion, transform::Transform,
+    vector::Vec3,
+};
 
 pub struct GraphicsTransform {
     pub matrix: Matrix4x4<f32>,
+
+    /// A pilha de matrizes salvas com `push`, como a pilha de matrizes do OpenGL.
+    stack: Vec<Matrix4x4<f32>>,
 }
 
 impl Default for GraphicsTransform {
     fn default() -> Self {
         Self {
             matrix: Matrix4x4::identity(),
+            stack: Vec::new(),
         }
     }
 }
@@ -18,11 +25,22 @@ impl GraphicsTransform {
         self.matrix = self.matrix * Matrix4x4::from_translate(Vec3::new(x, y, z));
     }
 
-    /// Realiza a transformação de rotação na matriz atual.
+    /// Realiza a transformação de rotação na matriz atual, aplicando os ângulos na ordem `XYZ`.
     pub fn rotate(&mut self, angle_x: Rad<f32>, angle_y: Rad<f32>, angle_z: Rad<f32>) {
         self.matrix = self.matrix * Matrix4x4::from_rotate(angle_x, angle_y, angle_z);
     }
 
+    /// Realiza a transformação de rotação dos ângulos de Euler na ordem `order` na matriz atual.
+    pub fn rotate_euler(
+        &mut self,
+        order: EulerOrder,
+        angle_x: Rad<f32>,
+        angle_y: Rad<f32>,
+        angle_z: Rad<f32>,
+    ) {
+        self.matrix = self.matrix * Matrix4x4::from_euler(order, angle_x, angle_y, angle_z);
+    }
+
     /// Realiza a transformação de rotação em X na matriz atual.
     pub fn rotate_x(&mut self, angle: Rad<f32>) {
         self.matrix = self.matrix * Matrix4x4::from_rotate_x(angle);
@@ -33,12 +51,22 @@ impl GraphicsTransform {
         self.matrix = self.matrix * Matrix4x4::from_rotate_y(angle);
     }
 
+    /// Realiza a transformação de rotação descrita por um quatérnio na matriz atual.
+    pub fn rotate_quat(&mut self, rotation: Quaternion<f32>) {
+        self.matrix = self.matrix * Matrix4x4::from(rotation);
+    }
+
+    /// Aplica uma transformação de translação, rotação e escala na matriz atual.
+    pub fn apply(&mut self, transform: Transform<f32>) {
+        self.matrix = self.matrix * Matrix4x4::from(transform);
+    }
+
     /// Realiza a transformação de escala na matriz atual.
     pub fn scale(&mut self, x: f32, y: f32, z: f32) {
         self.matrix = self.matrix * Matrix4x4::from_scale(Vec3::new(x, y, z));
     }
 
-    /// Realiza a transformação de escala em X na matriz atual.
+    // /// Realiza a transformação de escala em X na matriz atual.
     // pub fn scale_x(&mut self, x: f32) {
     //     self.matrix = self.matrix * Matrix4x4::from_scale_x(x);
     // }
@@ -63,3 +91,28 @@ impl GraphicsTransform {
         self.matrix = matrix;
     }
 }
+
+/// Pilha de matrizes
+impl GraphicsTransform {
+    /// Salva a matriz atual no topo da pilha. As transformações feitas depois podem ser
+    /// desfeitas com `pop`.
+    pub fn push(&mut self) {
+        self.stack.push(self.matrix);
+    }
+
+    /// Restaura a matriz salva pelo último `push`, removendo-a da pilha.
+    pub fn pop(&mut self) {
+        if let Some(matrix) = self.stack.pop() {
+            self.matrix = matrix;
+        } else {
+            panic!(
+                "A pilha de matrizes está vazia! Cada `pop` precisa de um `push` correspondente."
+            );
+        }
+    }
+
+    /// A quantidade de matrizes salvas na pilha.
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+}
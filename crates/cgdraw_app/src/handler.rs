@@ -1,18 +1,161 @@
+use cgdraw_camera::Camera;
+use cgdraw_core::{
+    color::Color,
+    graphic::{CameraViewport, ScreenRegion, Vertex},
+    uniforms::CameraUniformFloat32,
+};
+use cgdraw_math::matrix::Matrix4x4;
 use cgdraw_state::State;
 
+use crate::{
+    graphics::{CullingStats, Mesh, MeshBuilder, Primitive},
+    input::InputState,
+};
+
 pub struct AppHandler<'a> {
     state: &'a mut State,
+    input: &'a InputState,
+    exit_requested: &'a mut bool,
+    culling_stats: CullingStats,
+}
+
+impl<'a> AppHandler<'a> {
+    pub fn new(
+        state: &'a mut State,
+        input: &'a InputState,
+        exit_requested: &'a mut bool,
+        culling_stats: CullingStats,
+    ) -> Self {
+        Self {
+            state,
+            input,
+            exit_requested,
+            culling_stats,
+        }
+    }
+}
+
+// Lifecycle Implementations
+impl<'a> AppHandler<'a> {
+    /// Fecha o aplicativo depois que o quadro atual for desenhado. O evento
+    /// `AppEvent::Finished` é enviado antes do encerramento.
+    pub fn exit(&mut self) {
+        *self.exit_requested = true;
+    }
+
+    /// O mesmo que [`AppHandler::exit`].
+    #[inline]
+    pub fn request_close(&mut self) {
+        self.exit();
+    }
 }
 
+// Input Implementations
 impl<'a> AppHandler<'a> {
-    pub fn new(state: &'a mut State) -> Self {
-        Self { state }
+    /// O estado do teclado e do mouse no quadro atual.
+    pub fn input(&self) -> &InputState {
+        self.input
+    }
+}
+
+// Mesh Implementations
+impl<'a> AppHandler<'a> {
+    /// Cria uma malha retida a partir dos vértices e índices informados. Caso `indices` esteja
+    /// vazio, os índices são gerados na ordem dos vértices.
+    pub fn create_mesh(
+        &self,
+        vertices: Vec<Vertex>,
+        indices: Vec<u16>,
+        primitive: Primitive,
+    ) -> Mesh {
+        Mesh::new(&self.state.device, vertices, indices, primitive)
+    }
+
+    /// Monta uma malha retida da mesma forma que um passo `begin`/`end` do `Graphics`.
+    pub fn build_mesh<F>(&self, primitive: Primitive, build: F) -> Mesh
+    where
+        F: FnOnce(&mut MeshBuilder),
+    {
+        let mut builder = MeshBuilder::new(Color::WHITE);
+        build(&mut builder);
+
+        builder.build(Some(&self.state.device), primitive)
+    }
+}
+
+// Statistics Implementations
+impl<'a> AppHandler<'a> {
+    /// Quantas malhas e passos `begin`/`end` foram desenhados e quantos foram descartados por
+    /// estarem fora do frustum da câmera no quadro anterior.
+    pub fn culling_stats(&self) -> CullingStats {
+        self.culling_stats
     }
 }
 
 // Camera Implementations
 impl<'a> AppHandler<'a> {
+    /// Define diretamente a matriz de visualização da projeção. A posição da câmera, usada
+    /// pela iluminação, é calculada a partir dela; prefira [`AppHandler::set_camera`], que
+    /// também envia as matrizes de visão e de projeção separadas.
     pub fn camera_view_proj(&mut self, view_proj: [[f32; 4]; 4]) {
-        self.state.uniforms.camera.view_proj = view_proj;
+        self.state
+            .uniforms
+            .camera
+            .set_view_proj(Matrix4x4::from(view_proj));
+    }
+
+    /// Usa a câmera para desenhar o quadro, enviando ao shader as matrizes de visão e de
+    /// projeção, o seu produto e a posição da câmera.
+    ///
+    /// Antes, a câmera recebe o tamanho atual da janela por [`Camera::resize`], então não é
+    /// preciso atualizar a sua proporção no `AppEvent::Resize`.
+    pub fn set_camera(&mut self, camera: &mut impl Camera<f32>) {
+        camera.resize(
+            self.state.surface_config.width,
+            self.state.surface_config.height,
+        );
+
+        self.state
+            .uniforms
+            .camera
+            .set_matrices(camera.view(), camera.projection());
+    }
+
+    /// Adiciona uma câmera desenhada na região `viewport` da tela, normalizada de 0 a 1 a
+    /// partir do canto superior esquerdo. Todos os desenhos do quadro são renderizados uma
+    /// vez por região, permitindo telas divididas e picture-in-picture. Com `scissor`, somente
+    /// os pixels dentro desse recorte, também normalizado, são desenhados.
+    ///
+    /// As regiões valem apenas para o quadro atual e devem ser adicionadas a cada
+    /// `AppEvent::Update`. Enquanto houver alguma região, a câmera de
+    /// [`AppHandler::set_camera`] é ignorada.
+    ///
+    /// Antes, a câmera recebe o tamanho da região em pixels por [`Camera::resize`], para que
+    /// a sua proporção acompanhe a da região. Uma região que passa das bordas da tela mantém
+    /// o seu tamanho, e somente a parte dentro da tela é desenhada.
+    pub fn add_viewport(
+        &mut self,
+        camera: &mut impl Camera<f32>,
+        viewport: ScreenRegion,
+        scissor: Option<ScreenRegion>,
+    ) {
+        let width = self.state.surface_config.width;
+        let height = self.state.surface_config.height;
+
+        let mut camera_viewport = CameraViewport::new(CameraUniformFloat32::default(), viewport);
+        camera_viewport.scissor = scissor;
+
+        if let Some(pixels) = camera_viewport.viewport_pixels(width, height) {
+            camera.resize(
+                pixels.width.round().max(1.0) as u32,
+                pixels.height.round().max(1.0) as u32,
+            );
+        }
+
+        camera_viewport
+            .camera
+            .set_matrices(camera.view(), camera.projection());
+
+        self.state.viewports.push(camera_viewport);
     }
 }
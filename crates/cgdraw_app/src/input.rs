@@ -0,0 +1 @@
+pub use cgdraw_core::input::InputState;
@@ -5,3 +5,4 @@ pub mod builder;
 pub mod events;
 pub mod graphics;
 pub mod handler;
+pub mod input;
@@ -0,0 +1,151 @@
+use cgdraw_app::{graphics::CullingStats, handler::AppHandler, input::InputState};
+use cgdraw_camera::{Camera, OrbitCamera, OrthoCamera2D};
+use cgdraw_core::uniforms::UniformsFloat32;
+use cgdraw_math::{
+    angle::Deg,
+    matrix::Matrix4x4,
+    point::{Point2, Point3},
+};
+use cgdraw_state::State;
+
+/// Verifica se os componentes diferem no máximo por `epsilon`.
+fn assert_close(actual: [f32; 4], expected: [f32; 4], epsilon: f32) {
+    let close = actual
+        .iter()
+        .zip(expected)
+        .all(|(a, e)| (a - e).abs() <= epsilon);
+
+    assert!(close, "{actual:?} != {expected:?}");
+}
+
+#[test]
+fn set_camera_uploads_all_camera_uniforms_and_resizes_the_camera() {
+    let mut state = pollster::block_on(State::new_headless(200, 100, UniformsFloat32::default()));
+    let input = InputState::default();
+    let mut exit_requested = false;
+
+    let mut camera = OrbitCamera::new(Point3::new(1.0, 2.0, 3.0), 10.0);
+    camera.set_angles(Deg(30.0).into(), Deg(15.0).into());
+
+    let mut handler = AppHandler::new(
+        &mut state,
+        &input,
+        &mut exit_requested,
+        CullingStats::default(),
+    );
+    handler.set_camera(&mut camera);
+
+    // A proporção da câmera segue o tamanho da janela
+    assert_eq!(camera.screen_size(), (200.0, 100.0));
+
+    let uniforms = state.uniforms.camera;
+    let view_proj: [[f32; 4]; 4] = camera.calc_view_proj().into();
+    let view: [[f32; 4]; 4] = camera.view().into();
+    let proj: [[f32; 4]; 4] = camera.projection().into();
+    let position = camera.position();
+
+    assert_eq!(uniforms.view_proj, view_proj);
+    assert_eq!(uniforms.view, view);
+    assert_eq!(uniforms.proj, proj);
+    assert_close(
+        uniforms.view_position,
+        [position.x, position.y, position.z, 1.0],
+        1e-3,
+    );
+}
+
+#[test]
+fn camera_view_proj_derives_the_camera_position() {
+    let mut state = pollster::block_on(State::new_headless(200, 100, UniformsFloat32::default()));
+    let input = InputState::default();
+    let mut exit_requested = false;
+
+    let mut camera = OrbitCamera::new(Point3::new(1.0, 2.0, 3.0), 10.0);
+    camera.set_angles(Deg(30.0).into(), Deg(15.0).into());
+    camera.resize(200, 100);
+
+    let mut handler = AppHandler::new(
+        &mut state,
+        &input,
+        &mut exit_requested,
+        CullingStats::default(),
+    );
+    handler.camera_view_proj(camera.calc_view_proj().into());
+
+    let uniforms = state.uniforms.camera;
+    let position = camera.position();
+
+    assert_close(
+        uniforms.view_position,
+        [position.x, position.y, position.z, 1.0],
+        1e-3,
+    );
+}
+
+#[test]
+fn orthographic_cameras_send_the_direction_to_the_camera() {
+    let mut state = pollster::block_on(State::new_headless(200, 100, UniformsFloat32::default()));
+    let input = InputState::default();
+    let mut exit_requested = false;
+
+    let mut camera = OrthoCamera2D::new(Point2::new(5.0, 5.0));
+
+    let mut handler = AppHandler::new(
+        &mut state,
+        &input,
+        &mut exit_requested,
+        CullingStats::default(),
+    );
+    handler.set_camera(&mut camera);
+    let from_camera = state.uniforms.camera.view_position;
+
+    AppHandler::new(
+        &mut state,
+        &input,
+        &mut exit_requested,
+        CullingStats::default(),
+    )
+    .camera_view_proj(camera.calc_view_proj().into());
+    let from_view_proj = state.uniforms.camera.view_position;
+
+    // A câmera 2D olha para -Z
+    assert_eq!(from_camera, [0.0, 0.0, 1.0, 0.0]);
+    assert_close(from_view_proj, [0.0, 0.0, 1.0, 0.0], 1e-6);
+}
+
+/// Uma câmera que implementa somente `calc_view_proj`, como as câmeras antigas.
+struct ViewProjCamera(Matrix4x4<f32>);
+
+impl Camera<f32> for ViewProjCamera {
+    fn calc_view_proj(&self) -> Matrix4x4<f32> {
+        self.0
+    }
+}
+
+#[test]
+fn cameras_with_only_the_view_proj_matrix_send_their_position() {
+    let mut state = pollster::block_on(State::new_headless(200, 100, UniformsFloat32::default()));
+    let input = InputState::default();
+    let mut exit_requested = false;
+
+    let mut orbit = OrbitCamera::new(Point3::new(1.0, 2.0, 3.0), 10.0);
+    orbit.set_angles(Deg(30.0).into(), Deg(15.0).into());
+    orbit.resize(200, 100);
+    let mut camera = ViewProjCamera(orbit.calc_view_proj());
+
+    AppHandler::new(
+        &mut state,
+        &input,
+        &mut exit_requested,
+        CullingStats::default(),
+    )
+    .set_camera(&mut camera);
+
+    let position = orbit.position();
+
+    assert_close(
+        state.uniforms.camera.view_position,
+        [position.x, position.y, position.z, 1.0],
+        1e-3,
+    );
+}
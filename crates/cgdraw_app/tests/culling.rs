@@ -0,0 +1,84 @@
+use cgdraw_app::graphics::{CullingStats, Graphics, Mesh, Primitive};
+use cgdraw_core::{color::Color, perspective};
+use cgdraw_math::{angle::Rad, matrix::Matrix4x4, vector::Vec3};
+use cgdraw_software::SoftwareRenderer;
+
+/// Câmera na origem olhando para -Z, com 90° de campo de visão. Nessa posição, a matriz de
+/// visualização é a identidade.
+fn view_proj() -> Matrix4x4<f32> {
+    perspective(1.0, Rad(std::f32::consts::FRAC_PI_2), 0.1, 100.0)
+}
+
+fn renderer() -> SoftwareRenderer {
+    let mut renderer = SoftwareRenderer::new(64, 64);
+    renderer.uniforms.camera.view_proj = view_proj().into();
+    renderer
+}
+
+fn quad(g: &mut Graphics) -> Mesh {
+    g.build_mesh(Primitive::TriangleList, |m| {
+        m.color(Color::RED);
+        m.v3d(-0.5, -0.5, 0.0);
+        m.v3d(0.5, -0.5, 0.0);
+        m.v3d(0.5, 0.5, 0.0);
+        m.v3d(-0.5, -0.5, 0.0);
+        m.v3d(0.5, 0.5, 0.0);
+        m.v3d(-0.5, 0.5, 0.0);
+    })
+}
+
+/// Desenha uma fileira de quadrados de X = -20 a 20, a 5 unidades na frente da câmera, e
+/// outros atrás dela.
+fn draw_scene(g: &mut Graphics) {
+    let quad = quad(g);
+
+    for i in -20..=20 {
+        let translate = Vec3::new(i as f32, 0.0, -5.0);
+        g.draw_mesh(&quad, Matrix4x4::from_translate(translate));
+    }
+
+    g.t.translate(0.0, 0.0, 5.0);
+    g.begin(Primitive::TriangleList);
+    g.v3d(-0.5, -0.5, 0.0);
+    g.v3d(0.5, -0.5, 0.0);
+    g.v3d(0.0, 0.5, 0.0);
+    g.end();
+}
+
+#[test]
+fn meshes_and_steps_outside_the_frustum_are_culled() {
+    let mut renderer = renderer();
+    let g = &mut Graphics::new_software(&mut renderer);
+
+    draw_scene(g);
+
+    // Com 90° de campo de visão, a 5 unidades a tela vai de X = -5 a 5
+    assert_eq!(
+        g.culling_stats(),
+        CullingStats {
+            drawn: 11,
+            culled: 31,
+        }
+    );
+    assert_eq!(g.culling_stats().total(), 42);
+}
+
+#[test]
+fn culling_does_not_change_the_image() {
+    let mut culled = renderer();
+    draw_scene(&mut Graphics::new_software(&mut culled));
+
+    let mut not_culled = renderer();
+    let g = &mut Graphics::new_software(&mut not_culled);
+    g.culling(false);
+    draw_scene(g);
+
+    assert_eq!(
+        g.culling_stats(),
+        CullingStats {
+            drawn: 42,
+            culled: 0,
+        }
+    );
+    assert_eq!(culled.to_image(), not_culled.to_image());
+}
@@ -0,0 +1,352 @@
+use cgdraw_app::{
+    graphics::{CullingStats, Graphics, Primitive},
+    handler::AppHandler,
+    input::InputState,
+};
+use cgdraw_camera::{Camera, FlyCamera, OrthoCamera2D};
+use cgdraw_core::{
+    color::Color,
+    graphic::RgbaImage,
+    light::{Attenuation, Light, Material},
+    orthographic_lh,
+    uniforms::{UniformsFloat32, MAX_LIGHTS},
+};
+use cgdraw_math::{
+    angle::Deg,
+    matrix::Matrix4x4,
+    point::{Point2, Point3},
+    vector::Vec3,
+};
+use cgdraw_render::{Render, RenderState};
+use cgdraw_state::State;
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const WHITE: [u8; 4] = [255, 255, 255, 255];
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+/// Desenha o quadro em uma tela de 32 x 32 pixels, escolhendo a câmera com `setup`.
+fn render_setup<S, F>(setup: S, draw: F) -> RgbaImage
+where
+    S: FnOnce(&mut AppHandler),
+    F: FnOnce(&mut Graphics),
+{
+    let mut state = pollster::block_on(State::new_headless(32, 32, UniformsFloat32::default()));
+    state.clear_color = wgpu::Color::BLACK;
+
+    let input = InputState::default();
+    let mut exit_requested = false;
+    setup(&mut AppHandler::new(
+        &mut state,
+        &input,
+        &mut exit_requested,
+        CullingStats::default(),
+    ));
+
+    let mut render = Render::new(&mut state, RenderState::default());
+    draw(&mut Graphics::new(&mut render.render_state, render.state));
+    render.build();
+
+    state.read_pixels().unwrap()
+}
+
+/// Desenha o quadro com a câmera informada em uma tela de 32 x 32 pixels.
+fn render_with<C, F>(camera: &mut C, draw: F) -> RgbaImage
+where
+    C: Camera<f32>,
+    F: FnOnce(&mut Graphics),
+{
+    render_setup(|handler| handler.set_camera(camera), draw)
+}
+
+/// Desenha o quadro com uma câmera a 3 unidades da origem, olhando para -Z.
+fn render<F>(draw: F) -> RgbaImage
+where
+    F: FnOnce(&mut Graphics),
+{
+    render_with(&mut FlyCamera::new(Point3::new(0.0, 0.0, 3.0)), draw)
+}
+
+/// Um quadrado branco de 2 x 2 unidades em `z = 0`, virado para +Z.
+fn square(g: &mut Graphics) {
+    g.color(Color::WHITE);
+    g.begin(Primitive::TriangleList);
+    g.v3d(-1.0, -1.0, 0.0);
+    g.v3d(1.0, -1.0, 0.0);
+    g.v3d(1.0, 1.0, 0.0);
+    g.v3d(-1.0, -1.0, 0.0);
+    g.v3d(1.0, 1.0, 0.0);
+    g.v3d(-1.0, 1.0, 0.0);
+    g.end();
+}
+
+fn head_on() -> Light {
+    Light::directional(Vec3::new(0.0, 0.0, -1.0), Color::WHITE)
+}
+
+#[test]
+fn a_head_on_light_shows_the_full_diffuse_color() {
+    let image = render(|g| {
+        g.light(head_on());
+        g.material(Material::matte(Color::RED));
+        square(g);
+    });
+
+    assert_eq!(image.pixel(16, 16), RED);
+}
+
+#[test]
+fn vertex_normals_match_the_face_normal_of_a_flat_square() {
+    let with_normals = render(|g| {
+        g.light(Light::directional(Vec3::new(0.5, -0.5, -1.0), Color::WHITE));
+        g.material(Material::matte(Color::RED));
+        g.normal(0.0, 0.0, 1.0);
+        square(g);
+    });
+
+    let without_normals = render(|g| {
+        g.light(Light::directional(Vec3::new(0.5, -0.5, -1.0), Color::WHITE));
+        g.material(Material::matte(Color::RED));
+        square(g);
+    });
+
+    assert_eq!(with_normals.pixel(16, 16), without_normals.pixel(16, 16));
+}
+
+#[test]
+fn surfaces_facing_away_from_the_light_are_dark() {
+    let image = render(|g| {
+        g.light(Light::directional(Vec3::new(0.0, 0.0, 1.0), Color::WHITE));
+        g.material(Material::matte(Color::RED));
+        square(g);
+    });
+
+    assert_eq!(image.pixel(16, 16), BLACK);
+}
+
+#[test]
+fn oblique_lights_are_dimmer() {
+    let image = render(|g| {
+        g.light(Light::directional(Vec3::new(1.0, 0.0, -1.0), Color::WHITE));
+        g.material(Material::matte(Color::RED));
+        square(g);
+    });
+
+    let [r, g, b, _] = image.pixel(16, 16);
+    assert!(r > 0 && r < 255, "{r}");
+    assert_eq!((g, b), (0, 0));
+}
+
+#[test]
+fn ambient_light_lights_every_surface() {
+    let lit = render(|g| {
+        g.light(Light::ambient(Color::WHITE).intensity(0.5));
+        g.material(Material::matte(Color::RED));
+        square(g);
+    });
+
+    let [r, _, _, _] = lit.pixel(16, 16);
+    assert!(r > 0 && r < 255, "{r}");
+
+    // Com a iluminação ativada e nenhuma luz, não há o que iluminar
+    let dark = render(|g| {
+        g.lighting(true);
+        square(g);
+    });
+
+    assert_eq!(dark.pixel(16, 16), BLACK);
+}
+
+#[test]
+fn disabling_lighting_draws_the_vertex_colors() {
+    let image = render(|g| {
+        g.light(Light::directional(Vec3::new(0.0, 0.0, 1.0), Color::WHITE));
+        g.lighting(false);
+        square(g);
+    });
+
+    assert_eq!(image.pixel(16, 16), WHITE);
+}
+
+#[test]
+fn shiny_materials_reflect_the_light_color() {
+    let image = render(|g| {
+        g.light(head_on());
+        g.material(Material::new(Color::RED, Color::WHITE, 8.0));
+        square(g);
+    });
+
+    // O brilho especular branco soma verde e azul ao vermelho difuso
+    let [r, g, b, _] = image.pixel(16, 16);
+    assert_eq!(r, 255);
+    assert!(g > 0 && b > 0);
+}
+
+#[test]
+fn point_lights_fade_with_the_distance() {
+    let brightness = |attenuation| {
+        render(|g| {
+            g.light(
+                Light::point(Point3::new(0.0, 0.0, 1.0), Color::WHITE).attenuation(attenuation),
+            );
+            g.material(Material::matte(Color::RED));
+            square(g);
+        })
+        .pixel(16, 16)[0]
+    };
+
+    let near = brightness(Attenuation::range(2.0));
+    let far = brightness(Attenuation::range(20.0));
+
+    assert!(near < far, "{near} {far}");
+}
+
+#[test]
+fn spot_lights_only_light_inside_the_cone() {
+    let image = render(|g| {
+        g.light(Light::spot(
+            Point3::new(0.0, 0.0, 2.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Color::WHITE,
+            Deg(5.0).into(),
+            Deg(8.0).into(),
+        ));
+        g.material(Material::matte(Color::RED));
+        square(g);
+    });
+
+    // O centro está no eixo do holofote; o canto está a quase 30° dele
+    assert_eq!(image.pixel(16, 16), RED);
+    assert_eq!(image.pixel(26, 6), BLACK);
+}
+
+#[test]
+fn orthographic_cameras_use_their_axis_as_the_view_direction() {
+    // A câmera 2D fica no mesmo plano do quadrado, então a direção até ela não serve para o
+    // brilho especular
+    let mut camera = OrthoCamera2D::new(Point2::new(0.0, 0.0));
+    camera.set_zoom(8.0);
+
+    let image = render_with(&mut camera, |g| {
+        g.light(head_on());
+        g.material(Material::new(Color::RED, Color::WHITE, 8.0));
+        square(g);
+    });
+
+    assert_eq!(image.pixel(16, 16), WHITE);
+}
+
+#[test]
+fn vertex_normals_survive_tiny_scales() {
+    // Com a escala 0.004, o quadrado continua ocupando o centro da tela
+    let mut camera = OrthoCamera2D::new(Point2::new(0.0, 0.0));
+    camera.max_zoom = 10_000.0;
+    camera.set_zoom(2_000.0);
+
+    let mut brightness = |tilted: bool| {
+        render_with(&mut camera, |g| {
+            g.light(head_on());
+            g.material(Material::matte(Color::RED));
+            g.t.scale(0.004, 0.002, 0.004);
+
+            // Uma normal inclinada 45° em X recebe menos luz que a normal da face
+            if tilted {
+                g.normal(1.0, 0.0, 1.0);
+            }
+            square(g);
+        })
+        .pixel(16, 16)[0]
+    };
+
+    let face = brightness(false);
+    let tilted = brightness(true);
+
+    assert_eq!(face, 255);
+    assert!(tilted > 0 && tilted < face, "{tilted}");
+}
+
+/// Um quadrado brilhante, iluminado de frente, cujo centro fica branco quando a direção até a
+/// câmera é a da luz refletida.
+fn shiny_square(g: &mut Graphics) {
+    g.light(head_on());
+    g.material(Material::new(Color::RED, Color::WHITE, 8.0));
+    square(g);
+}
+
+#[test]
+fn lighting_works_with_only_the_view_proj_matrix() {
+    let mut camera = FlyCamera::new(Point3::new(0.5, 0.0, 3.0));
+    camera.resize(32, 32);
+    let view_proj = camera.calc_view_proj().into();
+
+    let expected = render_with(&mut camera, shiny_square);
+    let image = render_setup(|handler| handler.camera_view_proj(view_proj), shiny_square);
+
+    assert_eq!(image, expected);
+}
+
+#[test]
+fn orthographic_lighting_works_with_only_the_view_proj_matrix() {
+    let mut camera = OrthoCamera2D::new(Point2::new(0.0, 0.0));
+    camera.resize(32, 32);
+    camera.set_zoom(8.0);
+    let view_proj = camera.calc_view_proj().into();
+
+    let image = render_setup(|handler| handler.camera_view_proj(view_proj), shiny_square);
+
+    assert_eq!(image.pixel(16, 16), WHITE);
+}
+
+#[test]
+#[should_panic]
+fn more_than_max_lights_in_a_frame_panics() {
+    render(|g| {
+        for _ in 0..=MAX_LIGHTS {
+            g.light(head_on());
+        }
+    });
+}
+
+/// Uma câmera ortográfica canhota a 3 unidades da origem, olhando para -Z, com 4 unidades de
+/// altura. Na visão canhota o eixo X aparece espelhado.
+struct LeftHandedOrthoCamera;
+
+impl Camera<f32> for LeftHandedOrthoCamera {
+    fn calc_view_proj(&self) -> Matrix4x4<f32> {
+        self.projection() * self.view()
+    }
+
+    fn view(&self) -> Matrix4x4<f32> {
+        Matrix4x4::look_at_target_lh(
+            Point3::new(0.0, 0.0, 3.0),
+            Point3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        )
+    }
+
+    fn projection(&self) -> Matrix4x4<f32> {
+        orthographic_lh(-2.0, 2.0, -2.0, 2.0, 0.1, 10.0)
+    }
+}
+
+#[test]
+fn left_handed_orthographic_cameras_use_their_axis_as_the_view_direction() {
+    let image = render_with(&mut LeftHandedOrthoCamera, |g| {
+        g.light(head_on());
+        g.material(Material::new(Color::RED, Color::WHITE, 8.0));
+
+        // Como o espelhamento inverte o sentido dos triângulos, o quadrado é desenhado no
+        // sentido horário, com a normal voltada para a câmera
+        g.normal(0.0, 0.0, 1.0);
+        g.color(Color::WHITE);
+        g.begin(Primitive::TriangleList);
+        g.v3d(-1.0, -1.0, 0.0);
+        g.v3d(1.0, 1.0, 0.0);
+        g.v3d(1.0, -1.0, 0.0);
+        g.v3d(-1.0, -1.0, 0.0);
+        g.v3d(-1.0, 1.0, 0.0);
+        g.v3d(1.0, 1.0, 0.0);
+        g.end();
+    });
+
+    assert_eq!(image.pixel(16, 16), WHITE);
+}
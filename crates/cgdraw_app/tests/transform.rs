@@ -0,0 +1,201 @@
+use cgdraw_app::{
+    graphics::{CullingStats, Graphics, GraphicsTransform, Primitive},
+    handler::AppHandler,
+    input::InputState,
+};
+use cgdraw_camera::OrthoCamera2D;
+use cgdraw_core::{color::Color, graphic::RgbaImage, uniforms::UniformsFloat32};
+use cgdraw_math::{angle::Deg, matrix::Matrix4x4, point::Point2, vector::Vec3};
+use cgdraw_render::{Render, RenderState};
+use cgdraw_software::SoftwareRenderer;
+use cgdraw_state::State;
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+/// Desenha o quadro em uma tela de 32 x 32 pixels com uma câmera 2D em que cada unidade é um
+/// pixel e a origem fica no centro da tela.
+fn render<F>(draw: F) -> RgbaImage
+where
+    F: FnOnce(&mut Graphics),
+{
+    let mut state = pollster::block_on(State::new_headless(32, 32, UniformsFloat32::default()));
+    state.clear_color = wgpu::Color::BLACK;
+
+    let input = InputState::default();
+    let mut exit_requested = false;
+    AppHandler::new(
+        &mut state,
+        &input,
+        &mut exit_requested,
+        CullingStats::default(),
+    )
+    .set_camera(&mut OrthoCamera2D::new(Point2::new(0.0, 0.0)));
+
+    let mut render = Render::new(&mut state, RenderState::default());
+    draw(&mut Graphics::new(&mut render.render_state, render.state));
+    render.build();
+
+    state.read_pixels().unwrap()
+}
+
+/// Adiciona os vértices de um quadrado vermelho de 4 x 4 unidades centrado na origem ao passo
+/// atual.
+fn square_vertices(g: &mut Graphics) {
+    g.color(Color::RED);
+    g.v3d(-2.0, -2.0, 0.0);
+    g.v3d(2.0, -2.0, 0.0);
+    g.v3d(2.0, 2.0, 0.0);
+    g.v3d(-2.0, -2.0, 0.0);
+    g.v3d(2.0, 2.0, 0.0);
+    g.v3d(-2.0, 2.0, 0.0);
+}
+
+#[test]
+fn steps_use_the_matrix_from_when_they_began() {
+    let image = render(|g| {
+        g.t.translate(-8.0, 0.0, 0.0);
+
+        g.begin(Primitive::TriangleList);
+        // Alterações feitas depois do `begin` só valem para os próximos passos
+        g.t.translate(16.0, 0.0, 0.0);
+        square_vertices(g);
+        g.end();
+    });
+
+    assert_eq!(image.pixel(8, 16), RED);
+    assert_eq!(image.pixel(16, 16), BLACK);
+    assert_eq!(image.pixel(24, 16), BLACK);
+}
+
+#[test]
+fn each_step_keeps_its_own_matrix() {
+    let image = render(|g| {
+        g.t.translate(-8.0, 0.0, 0.0);
+        g.begin(Primitive::TriangleList);
+        square_vertices(g);
+        g.end();
+
+        g.t.translate(16.0, 0.0, 0.0);
+        g.begin(Primitive::TriangleList);
+        square_vertices(g);
+        g.end();
+    });
+
+    assert_eq!(image.pixel(8, 16), RED);
+    assert_eq!(image.pixel(16, 16), BLACK);
+    assert_eq!(image.pixel(24, 16), RED);
+}
+
+#[test]
+fn software_steps_use_the_matrix_from_when_they_began() {
+    let mut renderer = SoftwareRenderer::new(32, 32);
+    renderer.clear_color = [0.0, 0.0, 0.0, 1.0];
+    renderer.clear();
+
+    // Sem câmera, a tela vai de -1 a 1; com a escala, cada unidade vale 2 pixels
+    let g = &mut Graphics::new_software(&mut renderer);
+    g.t.scale(0.125, 0.125, 1.0);
+    g.t.translate(-4.0, 0.0, 0.0);
+
+    g.begin(Primitive::TriangleList);
+    g.t.translate(8.0, 0.0, 0.0);
+    square_vertices(g);
+    g.end();
+
+    let image = renderer.to_image();
+    assert_eq!(image.pixel(8, 16), RED);
+    assert_eq!(image.pixel(24, 16), BLACK);
+}
+
+#[test]
+fn pop_restores_the_matrix_saved_by_the_matching_push() {
+    let mut t = GraphicsTransform::default();
+    assert_eq!(t.depth(), 0);
+
+    t.translate(1.0, 0.0, 0.0);
+    let outer = t.matrix;
+
+    t.push();
+    t.scale(2.0, 2.0, 2.0);
+    let middle = t.matrix;
+
+    t.push();
+    t.translate(0.0, 5.0, 0.0);
+    assert_eq!(t.depth(), 2);
+
+    t.pop();
+    assert!(t.matrix == middle);
+    assert_eq!(t.depth(), 1);
+
+    t.pop();
+    assert!(t.matrix == outer);
+    assert_eq!(t.depth(), 0);
+}
+
+#[test]
+fn push_saves_a_copy_of_the_matrix() {
+    let mut t = GraphicsTransform::default();
+
+    t.push();
+    t.load(Matrix4x4::from_translate(Vec3::new(3.0, 0.0, 0.0)));
+    t.load_identity();
+    t.pop();
+
+    assert!(t.matrix == Matrix4x4::identity());
+}
+
+#[test]
+#[should_panic]
+fn pop_without_push_panics() {
+    let mut t = GraphicsTransform::default();
+
+    t.push();
+    t.pop();
+    t.pop();
+}
+
+#[test]
+fn with_pushed_restores_the_matrix_after_nested_draws() {
+    let mut renderer = SoftwareRenderer::new(8, 8);
+    let g = &mut Graphics::new_software(&mut renderer);
+
+    g.t.translate(1.0, 0.0, 0.0);
+    let outer = g.t.matrix;
+
+    g.with_pushed(|g| {
+        g.t.rotate_x(Deg(90.0).into());
+        let inner = g.t.matrix;
+        assert_eq!(g.t.depth(), 1);
+
+        g.with_pushed(|g| {
+            g.t.translate(0.0, 1.0, 0.0);
+            assert_eq!(g.t.depth(), 2);
+        });
+
+        assert!(g.t.matrix == inner);
+    });
+
+    assert!(g.t.matrix == outer);
+    assert_eq!(g.t.depth(), 0);
+}
+
+#[test]
+fn transforms_inside_with_pushed_only_move_what_is_drawn_inside_it() {
+    let image = render(|g| {
+        g.with_pushed(|g| {
+            g.t.translate(-8.0, 0.0, 0.0);
+            g.begin(Primitive::TriangleList);
+            square_vertices(g);
+            g.end();
+        });
+
+        g.begin(Primitive::TriangleList);
+        square_vertices(g);
+        g.end();
+    });
+
+    assert_eq!(image.pixel(8, 16), RED);
+    assert_eq!(image.pixel(16, 16), RED);
+    assert_eq!(image.pixel(12, 16), BLACK);
+}
@@ -0,0 +1,177 @@
+use cgdraw_app::{
+    graphics::{CullingStats, Graphics, Primitive},
+    handler::AppHandler,
+    input::InputState,
+};
+use cgdraw_camera::OrthoCamera2D;
+use cgdraw_core::{color::Color, graphic::ScreenRegion, uniforms::UniformsFloat32};
+use cgdraw_math::point::Point2;
+use cgdraw_render::{Render, RenderState};
+use cgdraw_state::State;
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+fn state() -> State {
+    let mut state = pollster::block_on(State::new_headless(64, 32, UniformsFloat32::default()));
+    state.clear_color = wgpu::Color::BLACK;
+    state
+}
+
+/// Adiciona as regiões com o `AppHandler`, como no `AppEvent::Update`.
+fn add_viewports<F>(state: &mut State, add: F)
+where
+    F: FnOnce(&mut AppHandler),
+{
+    let input = InputState::default();
+    let mut exit_requested = false;
+
+    let mut handler = AppHandler::new(state, &input, &mut exit_requested, CullingStats::default());
+    add(&mut handler);
+}
+
+/// Desenha um quadrado vermelho de 20 x 20 unidades centrado na origem.
+fn draw_square(state: &mut State) -> CullingStats {
+    let mut render = Render::new(state, RenderState::default());
+
+    let g = &mut Graphics::new(&mut render.render_state, render.state);
+    g.color(Color::RED);
+    g.begin(Primitive::TriangleList);
+    g.v3d(-10.0, -10.0, 0.0);
+    g.v3d(10.0, -10.0, 0.0);
+    g.v3d(10.0, 10.0, 0.0);
+    g.v3d(-10.0, -10.0, 0.0);
+    g.v3d(10.0, 10.0, 0.0);
+    g.v3d(-10.0, 10.0, 0.0);
+    g.end();
+    let stats = g.culling_stats();
+
+    render.build();
+
+    stats
+}
+
+const LEFT: ScreenRegion = ScreenRegion::new(0.0, 0.0, 0.5, 1.0);
+const RIGHT: ScreenRegion = ScreenRegion::new(0.5, 0.0, 0.5, 1.0);
+
+#[test]
+fn each_viewport_draws_the_frame_with_its_own_camera() {
+    let mut state = state();
+
+    // A câmera da direita olha para longe do quadrado
+    let mut left = OrthoCamera2D::new(Point2::new(0.0, 0.0));
+    let mut right = OrthoCamera2D::new(Point2::new(1000.0, 0.0));
+
+    add_viewports(&mut state, |handler| {
+        handler.add_viewport(&mut left, LEFT, None);
+        handler.add_viewport(&mut right, RIGHT, None);
+    });
+
+    // As câmeras recebem o tamanho da sua região
+    assert_eq!(left.screen_size(), (32.0, 32.0));
+    assert_eq!(right.screen_size(), (32.0, 32.0));
+
+    // O quadrado aparece na região da esquerda, então não é descartado
+    assert_eq!(draw_square(&mut state).drawn, 1);
+
+    let image = state.read_pixels().unwrap();
+
+    // Com o zoom 1, o quadrado ocupa os pixels 6 a 26 no centro da região da esquerda
+    assert_eq!(image.pixel(16, 16), RED);
+    assert_eq!(image.pixel(2, 16), BLACK);
+    assert_eq!(image.pixel(16, 2), BLACK);
+    assert_eq!(image.pixel(48, 16), BLACK);
+}
+
+#[test]
+fn the_same_camera_can_be_drawn_in_several_viewports() {
+    let mut state = state();
+    let mut camera = OrthoCamera2D::new(Point2::new(0.0, 0.0));
+
+    add_viewports(&mut state, |handler| {
+        handler.add_viewport(&mut camera, LEFT, None);
+        handler.add_viewport(&mut camera, RIGHT, None);
+    });
+
+    draw_square(&mut state);
+    let image = state.read_pixels().unwrap();
+
+    assert_eq!(image.pixel(16, 16), RED);
+    assert_eq!(image.pixel(48, 16), RED);
+    assert_eq!(image.pixel(34, 16), BLACK);
+}
+
+#[test]
+fn the_scissor_limits_the_drawn_pixels() {
+    let mut state = state();
+
+    // Com o zoom 4, o quadrado ocupa a tela inteira
+    let mut camera = OrthoCamera2D::new(Point2::new(0.0, 0.0));
+    camera.set_zoom(4.0);
+
+    add_viewports(&mut state, |handler| {
+        handler.add_viewport(&mut camera, ScreenRegion::FULL, Some(LEFT));
+    });
+
+    draw_square(&mut state);
+    let image = state.read_pixels().unwrap();
+
+    assert_eq!(image.pixel(16, 16), RED);
+    assert_eq!(image.pixel(40, 16), BLACK);
+}
+
+#[test]
+fn without_viewports_the_frame_uses_the_full_screen_camera() {
+    let mut state = state();
+    let mut camera = OrthoCamera2D::new(Point2::new(0.0, 0.0));
+
+    add_viewports(&mut state, |handler| handler.set_camera(&mut camera));
+
+    assert!(state.viewports.is_empty());
+
+    draw_square(&mut state);
+    let image = state.read_pixels().unwrap();
+
+    // O quadrado ocupa os pixels 22 a 42 no centro da tela
+    assert_eq!(image.pixel(32, 16), RED);
+    assert_eq!(image.pixel(16, 16), BLACK);
+}
+
+#[test]
+fn viewports_partially_off_screen_are_cropped_instead_of_squashed() {
+    let mut state = state();
+    let mut camera = OrthoCamera2D::new(Point2::new(0.0, 0.0));
+
+    // A região vai dos pixels 48 a 80, mas a tela termina no 64
+    add_viewports(&mut state, |handler| {
+        handler.add_viewport(&mut camera, ScreenRegion::new(0.75, 0.0, 0.5, 1.0), None);
+    });
+
+    // A câmera recebe o tamanho inteiro da região
+    assert_eq!(camera.screen_size(), (32.0, 32.0));
+
+    draw_square(&mut state);
+    let image = state.read_pixels().unwrap();
+
+    // O quadrado fica no centro da região, nos pixels 54 a 74, e só a parte dentro da tela
+    // aparece
+    assert_eq!(image.pixel(60, 16), RED);
+    assert_eq!(image.pixel(50, 16), BLACK);
+    assert_eq!(image.pixel(16, 16), BLACK);
+}
+
+#[test]
+fn viewports_fully_off_screen_are_not_drawn() {
+    let mut state = state();
+    let mut camera = OrthoCamera2D::new(Point2::new(0.0, 0.0));
+
+    add_viewports(&mut state, |handler| {
+        handler.add_viewport(&mut camera, ScreenRegion::new(1.0, 0.0, 0.5, 1.0), None);
+    });
+
+    draw_square(&mut state);
+    let image = state.read_pixels().unwrap();
+
+    assert_eq!(image.pixel(32, 16), BLACK);
+    assert_eq!(image.pixel(63, 16), BLACK);
+}
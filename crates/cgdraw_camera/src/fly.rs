@@ -0,0 +1,248 @@
+use std::time::Duration;
+
+use cgdraw_core::{
+    input::InputState, keyboard::keys::VirtualKeyCode, mouse::buttons::MouseButton, perspective,
+};
+use cgdraw_math::{
+    angle::{Deg, Rad},
+    matrix::Matrix4x4,
+    num::BaseFloat,
+    point::Point3,
+    vector::Vec3,
+};
+use num_traits::cast;
+
+use crate::Camera;
+
+/// Câmera em primeira pessoa que voa livremente pela cena, como nos editores de jogos.
+///
+/// A direção para onde a câmera olha é definida pelos ângulos `yaw` (ao redor do eixo Y,
+/// positivo para a direita) e `pitch` (positivo para cima). Com os dois ângulos em zero, a
+/// câmera olha para o eixo -Z.
+///
+/// ```no_run
+/// # use cgdraw_camera::{Camera, FlyCamera};
+/// # use cgdraw_core::input::InputState;
+/// # use cgdraw_math::point::Point3;
+/// # use std::time::Duration;
+/// let mut camera = FlyCamera::<f32>::new(Point3::new(0.0, 2.0, 10.0));
+/// camera.look_at(Point3::new(0.0, 0.0, 0.0));
+/// camera.resize(800, 600);
+///
+/// // A cada AppEvent::Update
+/// # let (input, delta_time) = (InputState::default(), Duration::ZERO);
+/// camera.update(&input, delta_time);
+/// let view_proj = camera.calc_view_proj();
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct FlyCamera<T> {
+    pub position: Point3<T>,
+    pub yaw: Rad<T>,
+    pub pitch: Rad<T>,
+
+    /// O campo de visão vertical.
+    pub fovy: Rad<T>,
+    pub znear: T,
+    pub zfar: T,
+
+    /// O maior ângulo `pitch`, para cima ou para baixo. Deve ser menor que 90° para que a
+    /// câmera não vire de cabeça para baixo.
+    pub max_pitch: Rad<T>,
+
+    /// A velocidade de movimento, em unidades por segundo.
+    pub move_speed: T,
+
+    /// O multiplicador da velocidade de movimento enquanto `Shift` está pressionado.
+    pub fast_multiplier: T,
+
+    /// A rotação, em radianos, por pixel movido com o mouse.
+    pub look_speed: T,
+
+    /// O botão do mouse que precisa estar pressionado para olhar ao redor. Com `None`, todo
+    /// movimento do mouse gira a câmera.
+    pub look_button: Option<MouseButton>,
+
+    screen_width: T,
+    screen_height: T,
+}
+
+impl<T: BaseFloat> Default for FlyCamera<T> {
+    /// Câmera na origem olhando para o eixo -Z, com 45° de campo de visão.
+    fn default() -> Self {
+        Self::new(Point3::new(T::zero(), T::zero(), T::zero()))
+    }
+}
+
+impl<T: BaseFloat> FlyCamera<T> {
+    /// Cria a câmera em `position`, olhando para o eixo -Z.
+    pub fn new(position: Point3<T>) -> Self {
+        Self {
+            position,
+            yaw: Rad(T::zero()),
+            pitch: Rad(T::zero()),
+            fovy: Deg(cast(45).unwrap()).into(),
+            znear: cast(0.1).unwrap(),
+            zfar: cast(1000).unwrap(),
+            max_pitch: Deg(cast(89).unwrap()).into(),
+            move_speed: cast(5).unwrap(),
+            fast_multiplier: cast(4).unwrap(),
+            look_speed: cast(0.005).unwrap(),
+            look_button: Some(MouseButton::Right),
+            screen_width: T::one(),
+            screen_height: T::one(),
+        }
+    }
+
+    /// Atualiza a proporção da projeção para o novo tamanho da tela, em pixels.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.screen_width = cast(width.max(1)).unwrap();
+        self.screen_height = cast(height.max(1)).unwrap();
+    }
+
+    /// O tamanho da tela em pixels, usado por `ray_from_screen`.
+    pub fn screen_size(&self) -> (T, T) {
+        (self.screen_width, self.screen_height)
+    }
+
+    /// A direção unitária para onde a câmera olha.
+    pub fn forward(&self) -> Vec3<T> {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+
+        Vec3::new(sin_yaw * cos_pitch, sin_pitch, -cos_yaw * cos_pitch)
+    }
+
+    /// A direção unitária para a direita da câmera, sempre no plano XZ.
+    pub fn right(&self) -> Vec3<T> {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+
+        Vec3::new(cos_yaw, T::zero(), sin_yaw)
+    }
+
+    /// Gira a câmera para olhar para `target`. O `pitch` é limitado por `max_pitch`.
+    pub fn look_at(&mut self, target: Point3<T>) {
+        let direction = target - self.position;
+        let horizontal = direction.x.hypot(direction.z);
+
+        self.yaw = Rad(direction.x.atan2(-direction.z));
+        self.pitch = self.clamp_pitch(Rad(direction.y.atan2(horizontal)));
+    }
+
+    /// Gira a câmera a partir do deslocamento do cursor, em pixels. Mover o mouse para a
+    /// direita olha para a direita e movê-lo para baixo olha para baixo.
+    pub fn look(&mut self, dx: T, dy: T) {
+        self.yaw = Rad(self.yaw.0 + dx * self.look_speed);
+        self.pitch = self.clamp_pitch(Rad(self.pitch.0 - dy * self.look_speed));
+    }
+
+    /// Move a câmera no seu próprio espaço: `forward` na direção em que ela olha, `right`
+    /// para a direita e `up` no eixo Y global.
+    pub fn translate(&mut self, forward: T, right: T, up: T) {
+        self.position = self.position
+            + self.forward() * forward
+            + self.right() * right
+            + Vec3::new(T::zero(), up, T::zero());
+    }
+
+    /// Aplica a entrada do quadro:
+    ///
+    /// - `W`/`S` ou as setas para cima e para baixo movem para frente e para trás;
+    /// - `A`/`D` ou as setas para os lados movem para os lados;
+    /// - `E`/`Q` sobem e descem;
+    /// - `Shift` multiplica a velocidade por `fast_multiplier`;
+    /// - o mouse, com `look_button` pressionado, gira a câmera.
+    pub fn update(&mut self, input: &InputState, delta_time: Duration) {
+        let looking = match self.look_button {
+            Some(button) => input.is_mouse_down(button),
+            None => true,
+        };
+
+        if looking {
+            let (dx, dy) = input.cursor_delta();
+            self.look(cast(dx).unwrap(), cast(dy).unwrap());
+        }
+
+        let axis = |positive: &[VirtualKeyCode], negative: &[VirtualKeyCode]| {
+            let down = |keys: &[VirtualKeyCode]| {
+                if keys.iter().any(|&key| input.is_key_down(key)) {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            };
+
+            down(positive) - down(negative)
+        };
+
+        let forward = axis(
+            &[VirtualKeyCode::W, VirtualKeyCode::Up],
+            &[VirtualKeyCode::S, VirtualKeyCode::Down],
+        );
+        let right = axis(
+            &[VirtualKeyCode::D, VirtualKeyCode::Right],
+            &[VirtualKeyCode::A, VirtualKeyCode::Left],
+        );
+        let up = axis(&[VirtualKeyCode::E], &[VirtualKeyCode::Q]);
+
+        let elapsed: T = cast(delta_time.as_secs_f64()).unwrap();
+        let mut speed = self.move_speed * elapsed;
+        if input.modifiers().shift {
+            speed *= self.fast_multiplier;
+        }
+
+        // Na diagonal, a câmera se move na mesma velocidade que em linha reta
+        let length = (forward * forward + right * right + up * up).sqrt();
+        if length > T::zero() {
+            let scale = speed / length;
+            self.translate(forward * scale, right * scale, up * scale);
+        }
+    }
+
+    fn clamp_pitch(&self, pitch: Rad<T>) -> Rad<T> {
+        Rad(pitch.0.max(-self.max_pitch.0).min(self.max_pitch.0))
+    }
+}
+
+/// Matrizes
+impl<T: BaseFloat> FlyCamera<T> {
+    /// A matriz de visão, que leva do espaço global ao espaço da câmera.
+    pub fn view(&self) -> Matrix4x4<T> {
+        Matrix4x4::look_to_rh(
+            self.position,
+            self.forward(),
+            Vec3::new(T::zero(), T::one(), T::zero()),
+        )
+    }
+
+    /// A matriz de projeção em perspectiva.
+    pub fn projection(&self) -> Matrix4x4<T> {
+        perspective(
+            self.screen_width / self.screen_height,
+            self.fovy,
+            self.znear,
+            self.zfar,
+        )
+    }
+}
+
+impl<T: BaseFloat> Camera<T> for FlyCamera<T> {
+    fn calc_view_proj(&self) -> Matrix4x4<T> {
+        self.projection() * self.view()
+    }
+
+    fn view(&self) -> Matrix4x4<T> {
+        FlyCamera::view(self)
+    }
+
+    fn projection(&self) -> Matrix4x4<T> {
+        FlyCamera::projection(self)
+    }
+
+    fn position(&self) -> Point3<T> {
+        self.position
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        FlyCamera::resize(self, width, height);
+    }
+}
@@ -1,8 +1,91 @@
-use cgdraw_math::{matrix::Matrix4x4, num::BaseFloat, point::Point3, vector::Vec3};
+use cgdraw_math::{
+    geometry::Ray3,
+    matrix::Matrix4x4,
+    num::BaseFloat,
+    point::{Point2, Point3},
+    vector::Vec3,
+    viewport::{unproject, Viewport},
+};
+
+mod fly;
+mod orbit;
+mod ortho2d;
+
+pub use fly::*;
+pub use orbit::*;
+pub use ortho2d::*;
 
 pub trait Camera<T: BaseFloat> {
     /// Calcula a matriz 4x4 de visualização da projeção da câmera no espaço global.
     fn calc_view_proj(&self) -> Matrix4x4<T>;
+
+    /// A matriz de visão, que leva do espaço global ao espaço da câmera.
+    ///
+    /// Por padrão é a identidade, considerando que `calc_view_proj` é somente a projeção. As
+    /// câmeras que separam as duas matrizes devem implementar `view` e `projection` de forma
+    /// que `projection() * view()` seja igual a `calc_view_proj()`.
+    fn view(&self) -> Matrix4x4<T> {
+        Matrix4x4::identity()
+    }
+
+    /// A matriz de projeção, que leva do espaço da câmera ao espaço de recorte. Por padrão é a
+    /// própria `calc_view_proj`.
+    fn projection(&self) -> Matrix4x4<T> {
+        self.calc_view_proj()
+    }
+
+    /// A posição da câmera no espaço global, calculada a partir da inversa de `view`.
+    fn position(&self) -> Point3<T> {
+        let world = self
+            .view()
+            .invert()
+            .expect("A matriz de visão da câmera não possui inversa!");
+
+        Point3::from_homogeneous(
+            world * Point3::new(T::zero(), T::zero(), T::zero()).to_homogeneous(),
+        )
+    }
+
+    /// Atualiza a câmera para o novo tamanho da tela, em pixels, normalmente corrigindo a
+    /// proporção da projeção. Por padrão não faz nada.
+    fn resize(&mut self, _width: u32, _height: u32) {}
+
+    /// Cria o raio que sai da câmera e passa pelo pixel `(x, y)` de uma tela de tamanho
+    /// `width` x `height`, usado para selecionar objetos com o mouse. O raio começa no plano
+    /// de perto e está no espaço global. Funciona também com as projeções infinitas e com
+    /// reversed-Z.
+    ///
+    /// # Panics
+    ///
+    /// Caso a matriz de `calc_view_proj` não possua inversa.
+    fn ray_from_screen(&self, x: T, y: T, width: T, height: T) -> Ray3<T> {
+        let inv_view_proj = self
+            .calc_view_proj()
+            .invert()
+            .expect("A matriz de visualização e projeção da câmera não possui inversa!");
+
+        let viewport = Viewport::from_size(width, height);
+        let screen = Point2::new(x, y);
+
+        let half = T::one() / (T::one() + T::one());
+
+        // Os pontos nas profundidades 0.25 e 0.5 existem em todas as projeções, inclusive nas
+        // infinitas. O `w` no espaço de recorte cresce com a distância até a câmera, indicando
+        // se a profundidade 0 está no plano de perto ou, com reversed-Z, no de longe.
+        let view_proj = self.calc_view_proj();
+        let middle = unproject(screen, half, &inv_view_proj, &viewport);
+        let quarter = unproject(screen, half * half, &inv_view_proj, &viewport);
+        let w = |point: Point3<T>| (view_proj * point.to_homogeneous()).w;
+
+        let near_depth = if w(quarter) <= w(middle) {
+            T::zero()
+        } else {
+            T::one()
+        };
+        let near = unproject(screen, near_depth, &inv_view_proj, &viewport);
+
+        Ray3::new(near, middle - near)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -0,0 +1,361 @@
+use std::time::Duration;
+
+use cgdraw_core::{
+    input::InputState, keyboard::keys::VirtualKeyCode, mouse::buttons::MouseButton, perspective,
+};
+use cgdraw_math::{
+    angle::{Deg, Rad},
+    matrix::Matrix4x4,
+    num::BaseFloat,
+    point::Point3,
+    vector::Vec3,
+};
+use num_traits::cast;
+
+use crate::Camera;
+
+/// As linhas de rolagem, por segundo, equivalentes a segurar `PageUp` ou `PageDown`.
+const KEY_ZOOM_LINES_PER_SECOND: f64 = 10.0;
+
+/// Câmera que orbita ao redor de um ponto alvo, como nos visualizadores de modelos 3D.
+///
+/// A posição é definida pelos ângulos `yaw` (ao redor do eixo Y) e `pitch` (acima ou abaixo
+/// do plano XZ) e pela distância até o alvo. Com os dois ângulos em zero, a câmera fica no
+/// eixo +Z do alvo, olhando para o eixo -Z.
+///
+/// Os comandos (`rotate`, `pan`, `zoom`) alteram a posição desejada da câmera, que é
+/// alcançada suavemente ao longo dos quadros por `advance` ou `update`, conforme `damping`.
+///
+/// ```no_run
+/// # use cgdraw_camera::{Camera, OrbitCamera};
+/// # use cgdraw_core::input::InputState;
+/// # use std::time::Duration;
+/// let mut camera = OrbitCamera::<f32>::default();
+/// camera.resize(800, 600);
+///
+/// // A cada AppEvent::Update
+/// # let (input, delta_time) = (InputState::default(), Duration::ZERO);
+/// camera.update(&input, delta_time);
+/// let view_proj = camera.calc_view_proj();
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct OrbitCamera<T> {
+    /// O campo de visão vertical.
+    pub fovy: Rad<T>,
+    pub znear: T,
+    pub zfar: T,
+
+    /// Os limites do ângulo `pitch`. Devem ficar entre -90° e 90° para que a câmera não passe
+    /// por cima do alvo.
+    pub min_pitch: Rad<T>,
+    pub max_pitch: Rad<T>,
+
+    /// Os limites da distância até o alvo.
+    pub min_distance: T,
+    pub max_distance: T,
+
+    /// A rotação, em radianos, por pixel arrastado com o botão esquerdo do mouse.
+    pub rotate_speed: T,
+
+    /// A rotação, em radianos por segundo, pelas setas do teclado.
+    pub key_rotate_speed: T,
+
+    /// A fração da distância aproximada a cada linha rolada pela roda do mouse.
+    pub zoom_speed: T,
+
+    /// O tempo, em segundos, que a câmera leva para percorrer cerca de 63% do caminho até a
+    /// posição desejada. Com zero, os comandos têm efeito imediato; caso contrário, `advance`
+    /// ou `update` devem ser chamados a cada quadro.
+    pub damping: T,
+
+    target: Point3<T>,
+    yaw: Rad<T>,
+    pitch: Rad<T>,
+    distance: T,
+
+    goal_target: Point3<T>,
+    goal_yaw: Rad<T>,
+    goal_pitch: Rad<T>,
+    goal_distance: T,
+
+    screen_width: T,
+    screen_height: T,
+}
+
+impl<T: BaseFloat> Default for OrbitCamera<T> {
+    /// Câmera a 20 unidades da origem, com 45° de campo de visão.
+    fn default() -> Self {
+        Self::new(
+            Point3::new(T::zero(), T::zero(), T::zero()),
+            cast(20).unwrap(),
+        )
+    }
+}
+
+impl<T: BaseFloat> OrbitCamera<T> {
+    /// Cria a câmera olhando para `target` a partir da distância `distance`, no eixo +Z do
+    /// alvo.
+    pub fn new(target: Point3<T>, distance: T) -> Self {
+        let max_pitch: Rad<T> = Deg(cast(89).unwrap()).into();
+
+        Self {
+            fovy: Deg(cast(45).unwrap()).into(),
+            znear: cast(0.1).unwrap(),
+            zfar: cast(1000).unwrap(),
+            min_pitch: Rad(-max_pitch.0),
+            max_pitch,
+            min_distance: cast(0.1).unwrap(),
+            max_distance: cast(1000).unwrap(),
+            rotate_speed: cast(0.01).unwrap(),
+            key_rotate_speed: cast(std::f64::consts::FRAC_PI_2).unwrap(),
+            zoom_speed: cast(0.1).unwrap(),
+            damping: cast(0.05).unwrap(),
+            target,
+            yaw: Rad(T::zero()),
+            pitch: Rad(T::zero()),
+            distance,
+            goal_target: target,
+            goal_yaw: Rad(T::zero()),
+            goal_pitch: Rad(T::zero()),
+            goal_distance: distance,
+            screen_width: T::one(),
+            screen_height: T::one(),
+        }
+    }
+
+    /// Atualiza a proporção da projeção para o novo tamanho da tela, em pixels.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.screen_width = cast(width.max(1)).unwrap();
+        self.screen_height = cast(height.max(1)).unwrap();
+    }
+
+    /// O tamanho da tela em pixels, usado por `ray_from_screen`.
+    pub fn screen_size(&self) -> (T, T) {
+        (self.screen_width, self.screen_height)
+    }
+}
+
+/// Estado
+impl<T: BaseFloat> OrbitCamera<T> {
+    /// O ponto para onde a câmera olha.
+    pub fn target(&self) -> Point3<T> {
+        self.target
+    }
+
+    /// O ângulo ao redor do eixo Y.
+    pub fn yaw(&self) -> Rad<T> {
+        self.yaw
+    }
+
+    /// O ângulo acima (positivo) ou abaixo (negativo) do plano XZ.
+    pub fn pitch(&self) -> Rad<T> {
+        self.pitch
+    }
+
+    /// A distância até o alvo.
+    pub fn distance(&self) -> T {
+        self.distance
+    }
+
+    /// A posição da câmera no espaço global.
+    pub fn position(&self) -> Point3<T> {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+
+        self.target + Vec3::new(sin_yaw * cos_pitch, sin_pitch, cos_yaw * cos_pitch) * self.distance
+    }
+
+    /// Move o alvo imediatamente, sem suavização.
+    pub fn set_target(&mut self, target: Point3<T>) {
+        self.target = target;
+        self.goal_target = target;
+    }
+
+    /// Define os ângulos imediatamente, sem suavização. O `pitch` é limitado por `min_pitch`
+    /// e `max_pitch`.
+    pub fn set_angles(&mut self, yaw: Rad<T>, pitch: Rad<T>) {
+        self.goal_yaw = yaw;
+        self.goal_pitch = self.clamp_pitch(pitch);
+        self.yaw = self.goal_yaw;
+        self.pitch = self.goal_pitch;
+    }
+
+    /// Define a distância até o alvo imediatamente, sem suavização. A distância é limitada
+    /// por `min_distance` e `max_distance`.
+    pub fn set_distance(&mut self, distance: T) {
+        self.goal_distance = self.clamp_distance(distance);
+        self.distance = self.goal_distance;
+    }
+
+    /// Leva a câmera imediatamente à posição desejada, encerrando a suavização.
+    pub fn snap(&mut self) {
+        self.target = self.goal_target;
+        self.yaw = self.goal_yaw;
+        self.pitch = self.goal_pitch;
+        self.distance = self.goal_distance;
+    }
+
+    fn snap_if_undamped(&mut self) {
+        if self.damping <= T::zero() {
+            self.snap();
+        }
+    }
+
+    fn clamp_pitch(&self, pitch: Rad<T>) -> Rad<T> {
+        Rad(pitch.0.max(self.min_pitch.0).min(self.max_pitch.0))
+    }
+
+    fn clamp_distance(&self, distance: T) -> T {
+        distance.max(self.min_distance).min(self.max_distance)
+    }
+}
+
+/// Comandos
+impl<T: BaseFloat> OrbitCamera<T> {
+    /// Gira a câmera ao redor do alvo a partir do deslocamento do cursor, em pixels. Arrastar
+    /// para a direita gira a cena para a direita e arrastar para baixo mostra a cena de cima.
+    pub fn rotate(&mut self, dx: T, dy: T) {
+        self.rotate_by(dx * self.rotate_speed, dy * self.rotate_speed);
+    }
+
+    fn rotate_by(&mut self, yaw: T, pitch: T) {
+        self.goal_yaw = Rad(self.goal_yaw.0 - yaw);
+        self.goal_pitch = self.clamp_pitch(Rad(self.goal_pitch.0 + pitch));
+        self.snap_if_undamped();
+    }
+
+    /// Move o alvo no plano da tela a partir do deslocamento do cursor, em pixels. Os pontos
+    /// na distância do alvo acompanham o cursor.
+    pub fn pan(&mut self, dx: T, dy: T) {
+        let two = T::one() + T::one();
+
+        // O tamanho de um pixel no plano do alvo
+        let pixel = two * self.goal_distance * (self.fovy / two).tan() / self.screen_height;
+
+        let view = self.view();
+        let right = Vec3::new(view[0][0], view[1][0], view[2][0]);
+        let up = Vec3::new(view[0][1], view[1][1], view[2][1]);
+
+        self.goal_target = self.goal_target + (up * dy - right * dx) * pixel;
+        self.snap_if_undamped();
+    }
+
+    /// Aproxima a câmera do alvo, ou a afasta com valores negativos. `amount` é dado em
+    /// linhas da roda do mouse.
+    pub fn zoom(&mut self, amount: T) {
+        let factor = (T::one() - self.zoom_speed).powf(amount);
+
+        self.goal_distance = self.clamp_distance(self.goal_distance * factor);
+        self.snap_if_undamped();
+    }
+
+    /// Aproxima a câmera da posição desejada, de acordo com `damping` e o tempo decorrido.
+    pub fn advance(&mut self, delta_time: Duration) {
+        let t = if self.damping > T::zero() {
+            let elapsed: T = cast(delta_time.as_secs_f64()).unwrap();
+            T::one() - (-elapsed / self.damping).exp()
+        } else {
+            T::one()
+        };
+
+        self.target = self.target + (self.goal_target - self.target) * t;
+        self.yaw = Rad(self.yaw.0 + (self.goal_yaw.0 - self.yaw.0) * t);
+        self.pitch = Rad(self.pitch.0 + (self.goal_pitch.0 - self.pitch.0) * t);
+        self.distance = self.distance + (self.goal_distance - self.distance) * t;
+    }
+
+    /// Aplica a entrada do quadro e avança a suavização:
+    ///
+    /// - arrastar com o botão esquerdo gira a câmera;
+    /// - arrastar com o botão direito ou do meio move o alvo;
+    /// - a roda do mouse aproxima e afasta;
+    /// - as setas giram e `PageUp`/`PageDown` aproximam e afastam.
+    pub fn update(&mut self, input: &InputState, delta_time: Duration) {
+        let (dx, dy) = input.cursor_delta();
+        let (dx, dy): (T, T) = (cast(dx).unwrap(), cast(dy).unwrap());
+
+        if input.is_mouse_down(MouseButton::Left) {
+            self.rotate(dx, dy);
+        }
+
+        if input.is_mouse_down(MouseButton::Right) || input.is_mouse_down(MouseButton::Middle) {
+            self.pan(dx, dy);
+        }
+
+        let wheel: T = cast(input.wheel_delta()).unwrap();
+        if wheel != T::zero() {
+            self.zoom(wheel);
+        }
+
+        let elapsed: T = cast(delta_time.as_secs_f64()).unwrap();
+        let axis = |positive, negative| {
+            let down = |key| {
+                if input.is_key_down(key) {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            };
+            down(positive) - down(negative)
+        };
+
+        let key_rotation = self.key_rotate_speed * elapsed;
+        let horizontal = axis(VirtualKeyCode::Left, VirtualKeyCode::Right);
+        let vertical = axis(VirtualKeyCode::Up, VirtualKeyCode::Down);
+        if horizontal != T::zero() || vertical != T::zero() {
+            self.rotate_by(horizontal * key_rotation, vertical * key_rotation);
+        }
+
+        let zoom = axis(VirtualKeyCode::PageUp, VirtualKeyCode::PageDown);
+        if zoom != T::zero() {
+            let lines: T = cast(KEY_ZOOM_LINES_PER_SECOND).unwrap();
+            self.zoom(zoom * lines * elapsed);
+        }
+
+        self.advance(delta_time);
+    }
+}
+
+/// Matrizes
+impl<T: BaseFloat> OrbitCamera<T> {
+    /// A matriz de visão, que leva do espaço global ao espaço da câmera.
+    pub fn view(&self) -> Matrix4x4<T> {
+        Matrix4x4::look_to_rh(
+            self.position(),
+            self.target - self.position(),
+            Vec3::new(T::zero(), T::one(), T::zero()),
+        )
+    }
+
+    /// A matriz de projeção em perspectiva.
+    pub fn projection(&self) -> Matrix4x4<T> {
+        perspective(
+            self.screen_width / self.screen_height,
+            self.fovy,
+            self.znear,
+            self.zfar,
+        )
+    }
+}
+
+impl<T: BaseFloat> Camera<T> for OrbitCamera<T> {
+    fn calc_view_proj(&self) -> Matrix4x4<T> {
+        self.projection() * self.view()
+    }
+
+    fn view(&self) -> Matrix4x4<T> {
+        OrbitCamera::view(self)
+    }
+
+    fn projection(&self) -> Matrix4x4<T> {
+        OrbitCamera::projection(self)
+    }
+
+    fn position(&self) -> Point3<T> {
+        OrbitCamera::position(self)
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        OrbitCamera::resize(self, width, height);
+    }
+}
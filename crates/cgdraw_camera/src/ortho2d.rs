@@ -0,0 +1,221 @@
+use cgdraw_core::{input::InputState, mouse::buttons::MouseButton, orthographic};
+use cgdraw_math::{
+    matrix::Matrix4x4,
+    num::BaseFloat,
+    point::Point2,
+    vector::{Vec2, Vec3},
+};
+use num_traits::cast;
+
+use crate::Camera;
+
+/// Câmera ortográfica para cenas 2D, que se comporta como um visualizador de mapas: arrastar
+/// move a cena junto com o cursor e a roda do mouse aproxima mantendo fixo o ponto sob o
+/// cursor.
+///
+/// O `zoom` é a quantidade de pixels por unidade do mundo. Com o zoom 1, cada unidade ocupa
+/// exatamente um pixel, independentemente do tamanho da janela: redimensionar a janela
+/// mostra uma área maior ou menor da cena, sem distorcê-la. O eixo Y do mundo aponta para
+/// cima.
+///
+/// ```no_run
+/// # use cgdraw_camera::{Camera, OrthoCamera2D};
+/// # use cgdraw_core::input::InputState;
+/// let mut camera = OrthoCamera2D::<f32>::default();
+/// camera.resize(800, 600);
+///
+/// // A cada AppEvent::Update
+/// # let input = InputState::default();
+/// camera.update(&input);
+/// let view_proj = camera.calc_view_proj();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrthoCamera2D<T> {
+    /// O ponto do mundo que aparece no centro da tela.
+    pub center: Point2<T>,
+
+    /// Os limites do eixo Z visíveis. Com os valores padrão, -1 e 1, os vértices desenhados
+    /// em `z = 0` ficam no meio do intervalo.
+    pub znear: T,
+    pub zfar: T,
+
+    /// Os limites do zoom, em pixels por unidade do mundo.
+    pub min_zoom: T,
+    pub max_zoom: T,
+
+    /// O quanto o zoom aumenta a cada linha rolada pela roda do mouse, em fração do zoom
+    /// atual.
+    pub zoom_speed: T,
+
+    /// O botão do mouse usado para arrastar a cena. Com `None`, `update` não move a câmera.
+    pub pan_button: Option<MouseButton>,
+
+    zoom: T,
+    screen_width: T,
+    screen_height: T,
+}
+
+impl<T: BaseFloat> Default for OrthoCamera2D<T> {
+    /// Câmera centrada na origem, com o zoom 1.
+    fn default() -> Self {
+        Self::new(Point2::new(T::zero(), T::zero()))
+    }
+}
+
+impl<T: BaseFloat> OrthoCamera2D<T> {
+    /// Cria a câmera centrada no ponto `center`, com o zoom 1.
+    pub fn new(center: Point2<T>) -> Self {
+        Self {
+            center,
+            znear: -T::one(),
+            zfar: T::one(),
+            min_zoom: cast(0.01).unwrap(),
+            max_zoom: cast(100).unwrap(),
+            zoom_speed: cast(0.1).unwrap(),
+            pan_button: Some(MouseButton::Left),
+            zoom: T::one(),
+            screen_width: T::one(),
+            screen_height: T::one(),
+        }
+    }
+
+    /// Atualiza o tamanho da tela, em pixels. O centro e o zoom são mantidos, então a área
+    /// visível acompanha a proporção da janela.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.screen_width = cast(width.max(1)).unwrap();
+        self.screen_height = cast(height.max(1)).unwrap();
+    }
+
+    /// O tamanho da tela em pixels, usado por `ray_from_screen`.
+    pub fn screen_size(&self) -> (T, T) {
+        (self.screen_width, self.screen_height)
+    }
+
+    /// O zoom, em pixels por unidade do mundo.
+    pub fn zoom(&self) -> T {
+        self.zoom
+    }
+
+    /// Define o zoom, limitado por `min_zoom` e `max_zoom`, mantendo o centro.
+    pub fn set_zoom(&mut self, zoom: T) {
+        self.zoom = zoom.max(self.min_zoom).min(self.max_zoom);
+    }
+
+    /// Os limites da área visível do mundo: esquerda, direita, baixo e cima.
+    pub fn bounds(&self) -> (T, T, T, T) {
+        let two = T::one() + T::one();
+        let half_width = self.screen_width / (two * self.zoom);
+        let half_height = self.screen_height / (two * self.zoom);
+
+        (
+            self.center.x - half_width,
+            self.center.x + half_width,
+            self.center.y - half_height,
+            self.center.y + half_height,
+        )
+    }
+}
+
+/// Conversões
+impl<T: BaseFloat> OrthoCamera2D<T> {
+    /// Converte um ponto do mundo para a tela, em pixels a partir do canto superior esquerdo.
+    pub fn world_to_screen(&self, world: Point2<T>) -> Point2<T> {
+        let two = T::one() + T::one();
+
+        Point2::new(
+            (world.x - self.center.x) * self.zoom + self.screen_width / two,
+            self.screen_height / two - (world.y - self.center.y) * self.zoom,
+        )
+    }
+
+    /// Converte um ponto da tela, em pixels a partir do canto superior esquerdo, para o mundo.
+    pub fn screen_to_world(&self, screen: Point2<T>) -> Point2<T> {
+        let two = T::one() + T::one();
+
+        Point2::new(
+            self.center.x + (screen.x - self.screen_width / two) / self.zoom,
+            self.center.y - (screen.y - self.screen_height / two) / self.zoom,
+        )
+    }
+}
+
+/// Comandos
+impl<T: BaseFloat> OrthoCamera2D<T> {
+    /// Move a cena junto com o cursor, a partir do seu deslocamento em pixels.
+    pub fn pan(&mut self, dx: T, dy: T) {
+        self.center = self.center + Vec2::new(-dx, dy) / self.zoom;
+    }
+
+    /// Multiplica o zoom por `factor`, mantendo fixo o ponto do mundo que está no pixel
+    /// `(x, y)` da tela, como o cursor do mouse.
+    pub fn zoom_at(&mut self, factor: T, x: T, y: T) {
+        let screen = Point2::new(x, y);
+        let anchor = self.screen_to_world(screen);
+
+        self.set_zoom(self.zoom * factor);
+
+        // Move o centro para que o ponto volte a ficar sob o cursor
+        self.center = self.center + (anchor - self.screen_to_world(screen));
+    }
+
+    /// Aplica a entrada do quadro: arrastar com `pan_button` move a cena e a roda do mouse
+    /// aproxima e afasta ao redor do cursor.
+    pub fn update(&mut self, input: &InputState) {
+        if let Some(button) = self.pan_button {
+            if input.is_mouse_down(button) {
+                let (dx, dy) = input.cursor_delta();
+                self.pan(cast(dx).unwrap(), cast(dy).unwrap());
+            }
+        }
+
+        let wheel: T = cast(input.wheel_delta()).unwrap();
+        if wheel != T::zero() {
+            let (x, y) = input.cursor_position();
+            let factor = (T::one() + self.zoom_speed).powf(wheel);
+
+            self.zoom_at(factor, cast(x).unwrap(), cast(y).unwrap());
+        }
+    }
+}
+
+/// Matrizes
+impl<T: BaseFloat> OrthoCamera2D<T> {
+    /// A matriz de visão, que move o centro da câmera para a origem.
+    pub fn view(&self) -> Matrix4x4<T> {
+        Matrix4x4::from_translate(Vec3::new(-self.center.x, -self.center.y, T::zero()))
+    }
+
+    /// A matriz de projeção ortográfica, com o tamanho da área visível.
+    pub fn projection(&self) -> Matrix4x4<T> {
+        let two = T::one() + T::one();
+        let half_width = self.screen_width / (two * self.zoom);
+        let half_height = self.screen_height / (two * self.zoom);
+
+        orthographic(
+            -half_width,
+            half_width,
+            -half_height,
+            half_height,
+            self.znear,
+            self.zfar,
+        )
+    }
+}
+
+impl<T: BaseFloat> Camera<T> for OrthoCamera2D<T> {
+    fn calc_view_proj(&self) -> Matrix4x4<T> {
+        self.projection() * self.view()
+    }
+
+    fn view(&self) -> Matrix4x4<T> {
+        OrthoCamera2D::view(self)
+    }
+
+    fn projection(&self) -> Matrix4x4<T> {
+        OrthoCamera2D::projection(self)
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        OrthoCamera2D::resize(self, width, height);
+    }
+}
@@ -0,0 +1,69 @@
+use approx::assert_relative_eq;
+use cgdraw_camera::{Camera, FlyCamera, OrbitCamera, OrthoCamera2D};
+use cgdraw_core::perspective;
+use cgdraw_math::{
+    angle::{Deg, Rad},
+    matrix::Matrix4x4,
+    point::{Point2, Point3},
+};
+
+/// Câmera que implementa somente `calc_view_proj`.
+struct ProjectionOnly;
+
+impl Camera<f64> for ProjectionOnly {
+    fn calc_view_proj(&self) -> Matrix4x4<f64> {
+        perspective(1.5, Rad(1.0), 0.1, 100.0)
+    }
+}
+
+/// Verifica que as matrizes separadas e a posição combinam com `calc_view_proj`.
+fn assert_consistent(camera: &impl Camera<f64>, position: Point3<f64>) {
+    assert_relative_eq!(
+        camera.projection() * camera.view(),
+        camera.calc_view_proj(),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        camera.position().to_vec(),
+        position.to_vec(),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn default_view_is_identity_at_the_origin() {
+    assert_relative_eq!(ProjectionOnly.view(), Matrix4x4::identity());
+    assert_consistent(&ProjectionOnly, Point3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn built_in_cameras_split_view_and_projection() {
+    let mut fly = FlyCamera::new(Point3::new(1.0, 2.0, 3.0));
+    fly.look_at(Point3::new(-4.0, 0.0, 1.0));
+    Camera::resize(&mut fly, 640, 480);
+    assert_consistent(&fly, Point3::new(1.0, 2.0, 3.0));
+
+    let mut orbit = OrbitCamera::new(Point3::new(1.0, 0.0, -2.0), 8.0);
+    orbit.set_angles(Deg(40.0).into(), Deg(-25.0).into());
+    Camera::resize(&mut orbit, 640, 480);
+    assert_consistent(&orbit, orbit.position());
+
+    let mut ortho = OrthoCamera2D::new(Point2::new(30.0, -12.0));
+    ortho.set_zoom(4.0);
+    Camera::resize(&mut ortho, 640, 480);
+    assert_consistent(&ortho, Point3::new(30.0, -12.0, 0.0));
+}
+
+#[test]
+fn resize_through_the_trait_updates_the_aspect() {
+    let mut camera = FlyCamera::<f64>::default();
+
+    Camera::resize(&mut camera, 300, 100);
+    assert_eq!(camera.screen_size(), (300.0, 100.0));
+
+    // Um ponto a 45° para a direita fica na borda direita com a proporção 3:1
+    let fovy = camera.fovy;
+    let edge = 3.0 * (fovy.0 / 2.0).tan();
+    let clip = camera.calc_view_proj() * Point3::new(edge, 0.0, -1.0).to_homogeneous();
+    assert_relative_eq!(clip.x / clip.w, 1.0, epsilon = 1e-12);
+}
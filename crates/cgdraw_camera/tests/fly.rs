@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use approx::assert_relative_eq;
+use cgdraw_camera::FlyCamera;
+use cgdraw_core::{
+    input::InputState,
+    keyboard::{keys::VirtualKeyCode, modifiers::Modifiers},
+    mouse::buttons::MouseButton,
+};
+use cgdraw_math::{
+    angle::{Deg, Rad},
+    point::Point3,
+    vector::{Vec3, Vec4},
+};
+
+const ONE_SECOND: Duration = Duration::from_secs(1);
+
+#[test]
+fn camera_looks_down_negative_z_by_default() {
+    let camera = FlyCamera::<f64>::new(Point3::new(1.0, 2.0, 3.0));
+
+    assert_relative_eq!(camera.forward(), Vec3::new(0.0, 0.0, -1.0));
+    assert_relative_eq!(camera.right(), Vec3::new(1.0, 0.0, 0.0));
+
+    // Um ponto na frente da câmera fica no eixo -Z do espaço de visão
+    assert_relative_eq!(
+        camera.view() * Vec4::new(1.0, 2.0, -7.0, 1.0),
+        Vec4::new(0.0, 0.0, -10.0, 1.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn look_at_points_the_camera_to_the_target() {
+    let mut camera = FlyCamera::<f64>::new(Point3::new(3.0, 4.0, 5.0));
+    let target = Point3::new(-1.0, 0.0, 2.0);
+
+    camera.look_at(target);
+
+    let direction = (target - camera.position).normalize();
+    assert_relative_eq!(camera.forward(), direction, epsilon = 1e-12);
+
+    let view = camera.view() * target.to_homogeneous();
+    assert_relative_eq!(view.x, 0.0, epsilon = 1e-12);
+    assert_relative_eq!(view.y, 0.0, epsilon = 1e-12);
+}
+
+#[test]
+fn mouse_looks_around_only_with_the_look_button() {
+    let mut camera = FlyCamera::<f64>::default();
+    let mut input = InputState::default();
+
+    input.mouse_moved(10.0, 10.0, 10.0, 10.0);
+    camera.update(&input, Duration::ZERO);
+    assert_relative_eq!(camera.yaw.0, 0.0);
+    input.end_frame();
+
+    input.mouse_pressed(MouseButton::Right);
+    input.mouse_moved(110.0, 10.0, 100.0, 0.0);
+    camera.update(&input, Duration::ZERO);
+
+    // Mover o mouse para a direita olha para a direita, na direção +X
+    assert_relative_eq!(camera.yaw.0, 100.0 * camera.look_speed);
+    assert!(camera.forward().x > 0.0);
+    input.end_frame();
+
+    // O pitch não passa de `max_pitch`
+    input.mouse_moved(110.0, -1e6, 0.0, -1e6);
+    camera.update(&input, Duration::ZERO);
+    assert_relative_eq!(camera.pitch.0, camera.max_pitch.0);
+    assert!(camera.forward().y > 0.0);
+}
+
+#[test]
+fn keys_move_the_camera_at_move_speed() {
+    let mut camera = FlyCamera::<f64>::default();
+    camera.yaw = Deg(90.0).into();
+    let mut input = InputState::default();
+
+    // Olhando para +X, `W` avança em X
+    input.key_pressed(VirtualKeyCode::W);
+    camera.update(&input, ONE_SECOND);
+    assert_relative_eq!(
+        camera.position.to_vec(),
+        Vec3::new(camera.move_speed, 0.0, 0.0),
+        epsilon = 1e-12
+    );
+
+    // Na diagonal a velocidade é a mesma
+    camera.position = Point3::new(0.0, 0.0, 0.0);
+    input.key_pressed(VirtualKeyCode::Right);
+    camera.update(&input, ONE_SECOND);
+    assert_relative_eq!(
+        camera.position.to_vec().magnitude(),
+        camera.move_speed,
+        epsilon = 1e-12
+    );
+    input.key_released(VirtualKeyCode::W);
+    input.key_released(VirtualKeyCode::Right);
+
+    // `E` sobe no eixo Y global, mais rápido com `Shift`
+    camera.position = Point3::new(0.0, 0.0, 0.0);
+    camera.pitch = Rad(0.5);
+    input.key_pressed(VirtualKeyCode::E);
+    input.modifiers_changed(Modifiers {
+        shift: true,
+        ..Modifiers::default()
+    });
+    camera.update(&input, ONE_SECOND);
+    assert_relative_eq!(
+        camera.position.to_vec(),
+        Vec3::new(0.0, camera.move_speed * camera.fast_multiplier, 0.0),
+        epsilon = 1e-12
+    );
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use approx::assert_relative_eq;
+use cgdraw_camera::{Camera, OrbitCamera};
+use cgdraw_core::{
+    input::InputState,
+    mouse::buttons::{MouseButton, MouseScrollDelta},
+};
+use cgdraw_math::{
+    angle::{Deg, Rad},
+    point::Point3,
+    vector::{Vec3, Vec4},
+    viewport::{project, Viewport},
+};
+
+/// Câmera sem suavização, com a tela de 100x100 pixels.
+fn camera() -> OrbitCamera<f64> {
+    let mut camera = OrbitCamera::new(Point3::new(1.0, 2.0, 3.0), 10.0);
+    camera.damping = 0.0;
+    camera.resize(100, 100);
+    camera
+}
+
+#[test]
+fn camera_looks_at_the_target() {
+    let mut camera = camera();
+    assert_relative_eq!(camera.position().to_vec(), Vec3::new(1.0, 2.0, 13.0));
+
+    camera.set_angles(Deg(90.0).into(), Rad(0.0));
+    assert_relative_eq!(
+        camera.position().to_vec(),
+        Vec3::new(11.0, 2.0, 3.0),
+        epsilon = 1e-12
+    );
+
+    // O alvo fica no centro da tela, na frente da câmera
+    let center = camera.view() * camera.target().to_homogeneous();
+    assert_relative_eq!(center, Vec4::new(0.0, 0.0, -10.0, 1.0), epsilon = 1e-12);
+
+    let screen = project(
+        camera.target(),
+        &camera.calc_view_proj(),
+        &Viewport::from_size(100.0, 100.0),
+    )
+    .unwrap();
+    assert_relative_eq!(screen.x, 50.0, epsilon = 1e-9);
+    assert_relative_eq!(screen.y, 50.0, epsilon = 1e-9);
+}
+
+#[test]
+fn pitch_and_distance_are_clamped() {
+    let mut camera = camera();
+
+    camera.rotate(0.0, 1e6);
+    assert_relative_eq!(camera.pitch().0, camera.max_pitch.0);
+
+    camera.rotate(0.0, -1e6);
+    assert_relative_eq!(camera.pitch().0, camera.min_pitch.0);
+
+    camera.zoom(1.0);
+    assert_relative_eq!(camera.distance(), 9.0);
+
+    camera.zoom(1e3);
+    assert_relative_eq!(camera.distance(), camera.min_distance);
+
+    camera.zoom(-1e3);
+    assert_relative_eq!(camera.distance(), camera.max_distance);
+}
+
+#[test]
+fn panning_drags_the_target_with_the_cursor() {
+    let mut camera = camera();
+    camera.set_angles(Deg(30.0).into(), Deg(20.0).into());
+
+    let point = camera.target();
+    let viewport = Viewport::from_size(100.0, 100.0);
+
+    camera.pan(10.0, -5.0);
+
+    let screen = project(point, &camera.calc_view_proj(), &viewport).unwrap();
+    assert_relative_eq!(screen.x, 60.0, epsilon = 1e-9);
+    assert_relative_eq!(screen.y, 45.0, epsilon = 1e-9);
+}
+
+#[test]
+fn damping_approaches_the_goal_smoothly() {
+    let mut camera = camera();
+    camera.damping = 0.1;
+
+    camera.rotate(-100.0, 0.0);
+    assert_relative_eq!(camera.yaw().0, 0.0);
+
+    // Depois de `damping` segundos, a câmera percorre 1 - 1/e do caminho
+    camera.advance(Duration::from_millis(100));
+    assert_relative_eq!(
+        camera.yaw().0,
+        100.0 * camera.rotate_speed * (1.0 - (-1.0_f64).exp()),
+        epsilon = 1e-9
+    );
+
+    camera.advance(Duration::from_secs(10));
+    assert_relative_eq!(camera.yaw().0, 100.0 * camera.rotate_speed, epsilon = 1e-9);
+}
+
+#[test]
+fn update_applies_mouse_drag_and_wheel() {
+    let mut camera = camera();
+    let mut input = InputState::default();
+
+    // Movimento sem botão pressionado não gira a câmera
+    input.mouse_moved(10.0, 0.0, 10.0, 0.0);
+    camera.update(&input, Duration::from_millis(16));
+    assert_relative_eq!(camera.yaw().0, 0.0);
+    input.end_frame();
+
+    input.mouse_pressed(MouseButton::Left);
+    input.mouse_moved(20.0, 5.0, 10.0, 5.0);
+    input.mouse_wheel(MouseScrollDelta::Lines { x: 0.0, y: 2.0 });
+    camera.update(&input, Duration::from_millis(16));
+
+    assert_relative_eq!(camera.yaw().0, -10.0 * camera.rotate_speed);
+    assert_relative_eq!(camera.pitch().0, 5.0 * camera.rotate_speed);
+    assert_relative_eq!(camera.distance(), 10.0 * 0.9 * 0.9, epsilon = 1e-9);
+}
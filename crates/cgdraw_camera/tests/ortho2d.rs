@@ -0,0 +1,152 @@
+use approx::assert_relative_eq;
+use cgdraw_camera::{Camera, OrthoCamera2D};
+use cgdraw_core::{
+    input::InputState,
+    mouse::buttons::{MouseButton, MouseScrollDelta},
+};
+use cgdraw_math::{
+    point::{Point2, Point3},
+    vector::Vec2,
+    viewport::{project, Viewport},
+};
+
+/// Câmera centrada em `(100, 50)` em uma tela de 800x600 pixels.
+fn camera() -> OrthoCamera2D<f64> {
+    let mut camera = OrthoCamera2D::new(Point2::new(100.0, 50.0));
+    camera.resize(800, 600);
+    camera
+}
+
+fn vec(point: Point2<f64>) -> Vec2<f64> {
+    Vec2::new(point.x, point.y)
+}
+
+#[test]
+fn one_world_unit_is_one_pixel_at_zoom_one() {
+    let camera = camera();
+
+    assert_relative_eq!(
+        vec(camera.world_to_screen(Point2::new(100.0, 50.0))),
+        Vec2::new(400.0, 300.0)
+    );
+
+    // O eixo Y do mundo aponta para cima e o da tela para baixo
+    assert_relative_eq!(
+        vec(camera.world_to_screen(Point2::new(110.0, 70.0))),
+        Vec2::new(410.0, 280.0)
+    );
+
+    assert_eq!(camera.bounds(), (-300.0, 500.0, -250.0, 350.0));
+}
+
+#[test]
+fn view_proj_agrees_with_world_to_screen() {
+    let mut camera = camera();
+    camera.set_zoom(2.5);
+
+    let viewport = Viewport::from_size(800.0, 600.0);
+
+    for (x, y) in [(100.0, 50.0), (-20.0, 130.0), (210.0, -40.0)] {
+        let projected =
+            project(Point3::new(x, y, 0.0), &camera.calc_view_proj(), &viewport).unwrap();
+        let screen = camera.world_to_screen(Point2::new(x, y));
+
+        assert_relative_eq!(projected.x, screen.x, epsilon = 1e-9);
+        assert_relative_eq!(projected.y, screen.y, epsilon = 1e-9);
+        assert_relative_eq!(projected.z, 0.5, epsilon = 1e-9);
+
+        assert_relative_eq!(
+            vec(camera.screen_to_world(screen)),
+            Vec2::new(x, y),
+            epsilon = 1e-9
+        );
+    }
+}
+
+#[test]
+fn resizing_keeps_the_scale_and_center() {
+    let mut camera = camera();
+    camera.set_zoom(2.0);
+
+    camera.resize(400, 600);
+
+    let (left, right, bottom, top) = camera.bounds();
+    assert_relative_eq!(right - left, 200.0);
+    assert_relative_eq!(top - bottom, 300.0);
+    assert_relative_eq!(
+        vec(camera.world_to_screen(Point2::new(100.0, 50.0))),
+        Vec2::new(200.0, 300.0)
+    );
+}
+
+#[test]
+fn zooming_keeps_the_point_under_the_cursor() {
+    let mut camera = camera();
+    let cursor = Point2::new(650.0, 120.0);
+    let anchor = camera.screen_to_world(cursor);
+
+    camera.zoom_at(3.0, cursor.x, cursor.y);
+
+    assert_relative_eq!(camera.zoom(), 3.0);
+    assert_relative_eq!(
+        vec(camera.world_to_screen(anchor)),
+        vec(cursor),
+        epsilon = 1e-9
+    );
+
+    // O zoom é limitado por `max_zoom`
+    camera.zoom_at(1e6, cursor.x, cursor.y);
+    assert_relative_eq!(camera.zoom(), camera.max_zoom);
+}
+
+#[test]
+fn update_drags_the_scene_and_zooms_with_the_wheel() {
+    let mut camera = camera();
+    camera.set_zoom(2.0);
+    let mut input = InputState::default();
+
+    let grabbed = camera.screen_to_world(Point2::new(300.0, 200.0));
+
+    input.mouse_pressed(MouseButton::Left);
+    input.mouse_moved(340.0, 170.0, 40.0, -30.0);
+    camera.update(&input);
+
+    // O ponto agarrado acompanha o cursor
+    assert_relative_eq!(
+        vec(camera.world_to_screen(grabbed)),
+        Vec2::new(340.0, 170.0),
+        epsilon = 1e-9
+    );
+    input.end_frame();
+
+    input.mouse_released(MouseButton::Left);
+    input.mouse_wheel(MouseScrollDelta::Lines { x: 0.0, y: 1.0 });
+    camera.update(&input);
+
+    assert_relative_eq!(camera.zoom(), 2.0 * (1.0 + camera.zoom_speed));
+    assert_relative_eq!(
+        vec(camera.world_to_screen(grabbed)),
+        Vec2::new(340.0, 170.0),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn rays_from_screen_work_at_the_minimum_zoom() {
+    // Com o zoom mínimo em uma tela grande, o determinante de `view_proj` é muito menor que o
+    // epsilon do f32
+    let mut camera = OrthoCamera2D::<f32>::new(Point2::new(30.0, -20.0));
+    camera.resize(1920, 1080);
+    camera.set_zoom(camera.min_zoom);
+
+    for (x, y) in [(0.0, 0.0), (960.0, 540.0), (1500.0, 200.0)] {
+        let ray = camera.ray_from_screen(x, y, 1920.0, 1080.0);
+        let world = camera.screen_to_world(Point2::new(x, y));
+
+        assert_relative_eq!(ray.origin.x, world.x, max_relative = 1e-4);
+        assert_relative_eq!(ray.origin.y, world.y, max_relative = 1e-4);
+
+        // O raio atravessa a tela, na direção -Z
+        assert_relative_eq!(ray.direction.z, -1.0, epsilon = 1e-4);
+    }
+}
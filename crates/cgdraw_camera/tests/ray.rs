@@ -0,0 +1,75 @@
+use approx::assert_relative_eq;
+use cgdraw_camera::Camera;
+use cgdraw_core::{perspective, perspective_infinite, perspective_reversed_z};
+use cgdraw_math::{angle::Rad, geometry::Aabb3, matrix::Matrix4x4, point::Point3, vector::Vec3};
+
+/// Câmera em `z = 10` olhando para a origem.
+struct TestCamera;
+
+impl Camera<f32> for TestCamera {
+    fn calc_view_proj(&self) -> Matrix4x4<f32> {
+        let view = Matrix4x4::from_translate(Vec3::new(0.0, 0.0, -10.0));
+
+        perspective(1.0, Rad(std::f32::consts::FRAC_PI_2), 0.1, 100.0) * view
+    }
+}
+
+#[test]
+fn ray_through_the_center_points_forward() {
+    let ray = TestCamera.ray_from_screen(50.0, 50.0, 100.0, 100.0);
+
+    assert_relative_eq!(ray.direction, Vec3::new(0.0, 0.0, -1.0), epsilon = 1e-5);
+    assert_relative_eq!(
+        ray.origin.to_vec(),
+        Vec3::new(0.0, 0.0, 9.9),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn clicking_selects_the_object_under_the_cursor() {
+    let cube = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+
+    let hit = TestCamera
+        .ray_from_screen(50.0, 50.0, 100.0, 100.0)
+        .intersect_aabb(&cube)
+        .unwrap();
+    assert_relative_eq!(hit.point.to_vec(), Vec3::new(0.0, 0.0, 1.0), epsilon = 1e-4);
+    assert_relative_eq!(hit.normal, Vec3::new(0.0, 0.0, 1.0));
+
+    // Com 90° de campo de visão, o cubo ocupa 2/11 da altura da tela
+    let miss = TestCamera.ray_from_screen(50.0, 5.0, 100.0, 100.0);
+    assert!(miss.intersect_aabb(&cube).is_none());
+
+    // A tela cresce para baixo: um pouco acima do centro atinge o topo da face da frente
+    let above = TestCamera.ray_from_screen(50.0, 46.0, 100.0, 100.0);
+    assert!(above.intersect_aabb(&cube).unwrap().point.y > 0.0);
+}
+
+/// A mesma câmera de `TestCamera`, com a projeção infinita ou com reversed-Z.
+struct ProjectionCamera(Matrix4x4<f32>);
+
+impl Camera<f32> for ProjectionCamera {
+    fn calc_view_proj(&self) -> Matrix4x4<f32> {
+        self.0 * Matrix4x4::from_translate(Vec3::new(0.0, 0.0, -10.0))
+    }
+}
+
+#[test]
+fn ray_works_with_infinite_and_reversed_z_projections() {
+    let fovy = Rad(std::f32::consts::FRAC_PI_2);
+
+    for projection in [
+        perspective_infinite(1.0, fovy, 0.1),
+        perspective_reversed_z(1.0, fovy, 0.1, 100.0),
+    ] {
+        let ray = ProjectionCamera(projection).ray_from_screen(50.0, 50.0, 100.0, 100.0);
+
+        assert_relative_eq!(ray.direction, Vec3::new(0.0, 0.0, -1.0), epsilon = 1e-5);
+        assert_relative_eq!(
+            ray.origin.to_vec(),
+            Vec3::new(0.0, 0.0, 9.9),
+            epsilon = 1e-4
+        );
+    }
+}
@@ -1,7 +1,7 @@
-use self::srgb::SrgbColor;
-
 mod srgb;
 
+pub use self::srgb::SrgbColor;
+
 #[derive(Debug, Copy, Clone)]
 pub enum Color {
     WHITE,
@@ -76,3 +76,9 @@ impl Color {
         [srgb.r, srgb.g, srgb.b]
     }
 }
+
+impl From<Color> for SrgbColor {
+    fn from(color: Color) -> Self {
+        color.get_srgb()
+    }
+}
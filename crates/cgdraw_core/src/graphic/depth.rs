@@ -0,0 +1,40 @@
+/// Define como o teste de profundidade compara os fragmentos.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum DepthMode {
+    /// A profundidade 0 fica no plano de perto e 1 no de longe. Usado pelas projeções
+    /// `perspective`, `orthographic`, `frustum` e `perspective_infinite`.
+    #[default]
+    Standard,
+
+    /// A profundidade 1 fica no plano de perto e 0 no de longe, o que distribui melhor a
+    /// precisão do buffer de profundidade em cenas grandes. Deve ser usado com as projeções
+    /// `perspective_reversed_z`.
+    ReversedZ,
+}
+
+impl DepthMode {
+    /// A comparação usada pelo teste de profundidade: o fragmento é desenhado quando está mais
+    /// perto que o anterior.
+    pub fn to_wgpu_compare_function(&self) -> wgpu::CompareFunction {
+        match self {
+            DepthMode::Standard => wgpu::CompareFunction::Less,
+            DepthMode::ReversedZ => wgpu::CompareFunction::Greater,
+        }
+    }
+
+    /// O valor usado para limpar o buffer de profundidade, que representa a maior distância.
+    pub fn clear_value(&self) -> f32 {
+        match self {
+            DepthMode::Standard => 1.0,
+            DepthMode::ReversedZ => 0.0,
+        }
+    }
+
+    /// Indica se a profundidade `z` está mais perto que a profundidade `current`.
+    pub fn is_closer(&self, z: f32, current: f32) -> bool {
+        match self {
+            DepthMode::Standard => z < current,
+            DepthMode::ReversedZ => z > current,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+/// Imagem RGBA com 8 bits por canal, armazenada linha por linha a partir do canto superior
+/// esquerdo. É o formato usado para ler de volta os quadros renderizados em modo offscreen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    /// A largura da imagem em pixels.
+    pub width: u32,
+
+    /// A altura da imagem em pixels.
+    pub height: u32,
+
+    /// Os pixels da imagem, com 4 bytes (r, g, b, a) por pixel.
+    pub pixels: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// Cria uma nova imagem a partir dos pixels. O tamanho do vetor de pixels deve ser
+    /// `width * height * 4`.
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        assert_eq!(
+            pixels.len(),
+            (width * height * 4) as usize,
+            "O tamanho do vetor de pixels não corresponde às dimensões da imagem!"
+        );
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Retorna o pixel (r, g, b, a) na posição `(x, y)`.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let i = ((y * self.width + x) * 4) as usize;
+
+        [
+            self.pixels[i],
+            self.pixels[i + 1],
+            self.pixels[i + 2],
+            self.pixels[i + 3],
+        ]
+    }
+
+    /// Carrega uma imagem a partir de um arquivo PNG. Imagens em tons de cinza ou sem canal
+    /// alfa são convertidas para RGBA.
+    pub fn load_png<P: AsRef<Path>>(path: P) -> Result<Self, png::DecodingError> {
+        let file = File::open(path)?;
+
+        let mut decoder = png::Decoder::new(BufReader::new(file));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer
+                .chunks(3)
+                .flat_map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => buffer
+                .chunks(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|&p| [p, p, p, 255]).collect(),
+            // `normalize_to_color8` expande as imagens indexadas para RGB(A)
+            png::ColorType::Indexed => unreachable!(),
+        };
+
+        Ok(Self::new(info.width, info.height, pixels))
+    }
+
+    /// Salva a imagem em um arquivo PNG.
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<(), png::EncodingError> {
+        let file = File::create(path)?;
+
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)
+    }
+}
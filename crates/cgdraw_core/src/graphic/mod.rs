@@ -1,5 +1,13 @@
+mod depth;
+mod image;
+mod present;
 mod texture;
 mod vertex;
+mod viewport;
 
+pub use depth::*;
+pub use image::*;
+pub use present::*;
 pub use texture::*;
 pub use vertex::*;
+pub use viewport::*;
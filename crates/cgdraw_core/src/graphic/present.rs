@@ -0,0 +1,35 @@
+/// Define como os quadros renderizados são apresentados na tela.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum PresentMode {
+    /// Sincronização vertical quando disponível, caso contrário sem sincronização.
+    #[default]
+    AutoVsync,
+
+    /// Sem sincronização vertical quando disponível, caso contrário com sincronização.
+    AutoNoVsync,
+
+    /// Sincronização vertical: os quadros esperam a próxima atualização da tela. É o único
+    /// modo suportado por todas as plataformas.
+    Vsync,
+
+    /// Sem sincronização vertical: os quadros são apresentados imediatamente, podendo causar
+    /// "tearing".
+    Immediate,
+
+    /// Os quadros esperam a próxima atualização da tela, mas um quadro mais novo substitui o
+    /// que estava esperando.
+    Mailbox,
+}
+
+impl PresentMode {
+    /// Converte o modo de apresentação para o modo de apresentação do wgpu
+    pub fn to_wgpu_present_mode(&self) -> wgpu::PresentMode {
+        match self {
+            PresentMode::AutoVsync => wgpu::PresentMode::AutoVsync,
+            PresentMode::AutoNoVsync => wgpu::PresentMode::AutoNoVsync,
+            PresentMode::Vsync => wgpu::PresentMode::Fifo,
+            PresentMode::Immediate => wgpu::PresentMode::Immediate,
+            PresentMode::Mailbox => wgpu::PresentMode::Mailbox,
+        }
+    }
+}
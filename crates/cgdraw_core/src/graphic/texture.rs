@@ -13,7 +13,7 @@ impl Texture {
     ) -> Self {
         let descriptor = wgpu::TextureDescriptor {
             label: Some("Depth Texture"),
-            /**
+            /*
              * Especifica a largura, altura e profundidade da textura.
              */
             size: wgpu::Extent3d {
@@ -21,31 +21,31 @@ impl Texture {
                 height: config.height,
                 depth_or_array_layers: 1,
             },
-            /**
+            /*
              * Especifica o número de níveis mipmap da textura.
              * Um nível de mipmap é uma versão reduzida da textura original usada
              * em distâncias maiores ou menores.
              */
             mip_level_count: 1,
-            /**
+            /*
              * Especifica o número de amostras de textura por pixel.
              */
             sample_count: 1,
-            /**
+            /*
              * Especifica a dimensão da textura.
              */
             dimension: wgpu::TextureDimension::D2,
-            /**
+            /*
              * Especifica o formato da textura.
              */
             format: Self::DEPTH_FORMAT,
-            /**
+            /*
              * Especifica o uso da textura.
              * Neste caso, a textura será usada como um anexo de renderização ou
              * como uma textura de ligação.
              */
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
-            /**
+            /*
              * Especifica os formatos de visualização da textura.
              */
             view_formats: &[Self::DEPTH_FORMAT],
@@ -54,45 +54,45 @@ impl Texture {
         let texture = device.create_texture(&descriptor);
         let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            /**
+            /*
              * Especifica o modo de endereçamento da textura
              * no eixo U (horizontal).
              */
             address_mode_u: wgpu::AddressMode::ClampToEdge,
-            /**
+            /*
              * Especifica o modo de endereçamento da textura
              * no eixo V (vertical).
              */
             address_mode_v: wgpu::AddressMode::ClampToEdge,
-            /**
+            /*
              * Especifica o modo de endereçamento da textura
              * no eixo W (profundidade).
              */
             address_mode_w: wgpu::AddressMode::ClampToEdge,
-            /**
+            /*
              * Especifica o modo de filtragem da textura
              * quando a textura é ampliada.
              */
             mag_filter: wgpu::FilterMode::Linear,
-            /**
+            /*
              * Especifica o modo de filtragem da textura
              * quando a textura é reduzida.
              */
             min_filter: wgpu::FilterMode::Linear,
-            /**
+            /*
              * Especifica o modo de filtragem da textura
              * quando a textura é reduzida ou ampliada.
              */
             mipmap_filter: wgpu::FilterMode::Nearest,
-            /**
+            /*
              * Especifica o limite inferior para o nível de detalhe (LOD) da textura.
              */
             compare: Some(wgpu::CompareFunction::LessEqual),
-            /**
+            /*
              * Especifica o limite inferior para o nível de detalhe (LOD) da textura.
              */
             lod_min_clamp: 0.0,
-            /**
+            /*
              * Especifica o limite superior para o nível de detalhe (LOD) da textura.
              */
             lod_max_clamp: 100.0,
@@ -105,4 +105,48 @@ impl Texture {
             sampler,
         }
     }
+
+    /// Cria uma textura de cor que pode ser usada como alvo de renderização no lugar da
+    /// superfície da janela (renderização offscreen) e copiada de volta para a CPU.
+    pub fn create_render_target(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> Self {
+        let descriptor = wgpu::TextureDescriptor {
+            label: Some("Render Target Texture"),
+            size: wgpu::Extent3d {
+                width: config.width,
+                height: config.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: config.format,
+            /*
+             * A textura é usada como anexo de renderização e como origem de cópia,
+             * para que os pixels possam ser lidos de volta para a CPU.
+             */
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        };
+
+        let texture = device.create_texture(&descriptor);
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        Self {
+            texture,
+            view,
+            sampler,
+        }
+    }
 }
@@ -5,6 +5,10 @@ use wgpu::util::DeviceExt;
 pub struct Vertex {
     pub position: [f32; 3],
     pub color: [f32; 3],
+
+    /// A normal usada pela iluminação. Quando é zero, as superfícies iluminadas usam a normal
+    /// de cada triângulo, o que gera um sombreamento facetado.
+    pub normal: [f32; 3],
 }
 
 impl Vertex {
@@ -27,6 +31,12 @@ impl Vertex {
                     offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
                     shader_location: 1,
                 },
+                // Normal
+                wgpu::VertexAttribute {
+                    format: wgpu::VertexFormat::Float32x3,
+                    offset: std::mem::size_of::<[f32; 6]>() as wgpu::BufferAddress,
+                    shader_location: 2,
+                },
             ],
         }
     }
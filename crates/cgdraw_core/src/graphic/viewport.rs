@@ -0,0 +1,101 @@
+use cgdraw_math::viewport::Viewport;
+
+use crate::uniforms::CameraUniformFloat32;
+
+/// Uma região retangular da tela em coordenadas normalizadas: vai de 0 a 1 a partir do canto
+/// superior esquerdo, independentemente do tamanho da tela em pixels. Para regiões em pixels,
+/// use `Viewport`.
+///
+/// A região pode passar das bordas da tela; a parte de fora simplesmente não é desenhada.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScreenRegion {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl ScreenRegion {
+    /// A região que ocupa a tela inteira.
+    pub const FULL: ScreenRegion = ScreenRegion::new(0.0, 0.0, 1.0, 1.0);
+
+    /// Cria a região a partir do canto superior esquerdo e do tamanho, em frações da tela.
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// A região em pixels em uma tela de `width` x `height`, sem limitá-la às bordas.
+    pub fn to_pixels(&self, width: u32, height: u32) -> Viewport<f32> {
+        let (width, height) = (width as f32, height as f32);
+
+        Viewport::new(
+            self.x * width,
+            self.y * height,
+            self.width * width,
+            self.height * height,
+        )
+    }
+}
+
+/// Uma câmera desenhada em uma região da tela. Com várias regiões, os mesmos desenhos do
+/// quadro são renderizados uma vez por câmera, permitindo telas divididas e
+/// picture-in-picture.
+#[derive(Debug, Clone, Copy)]
+pub struct CameraViewport {
+    /// Os dados da câmera enviados ao shader.
+    pub camera: CameraUniformFloat32,
+
+    /// A região da tela onde a câmera é desenhada.
+    pub viewport: ScreenRegion,
+
+    /// Um recorte opcional: somente os pixels dentro dele são desenhados.
+    pub scissor: Option<ScreenRegion>,
+}
+
+impl CameraViewport {
+    /// Cria uma região sem recorte.
+    pub fn new(camera: CameraUniformFloat32, viewport: ScreenRegion) -> Self {
+        Self {
+            camera,
+            viewport,
+            scissor: None,
+        }
+    }
+
+    /// A região em pixels em uma tela de `width` x `height`. Uma região que passa das bordas
+    /// da tela não é achatada: ela mantém o seu tamanho e a parte de fora é cortada pelo
+    /// recorte de `scissor_pixels`. Retorna `None` quando a região fica totalmente fora da
+    /// tela.
+    pub fn viewport_pixels(&self, width: u32, height: u32) -> Option<Viewport<f32>> {
+        let pixels = self.viewport.to_pixels(width, height);
+
+        let visible = pixels.x < width as f32
+            && pixels.y < height as f32
+            && pixels.x + pixels.width > 0.0
+            && pixels.y + pixels.height > 0.0;
+
+        visible.then_some(pixels)
+    }
+
+    /// O retângulo de recorte em pixels inteiros (`x`, `y`, largura e altura) em uma tela de
+    /// `width` x `height`, limitado às bordas da tela. Sem `scissor`, recorta a própria
+    /// região. Retorna `None` quando nenhum pixel fica dentro do recorte.
+    pub fn scissor_pixels(&self, width: u32, height: u32) -> Option<[u32; 4]> {
+        let region = self.scissor.unwrap_or(self.viewport);
+
+        let round =
+            |value: f32, size: u32| (value * size as f32).round().clamp(0.0, size as f32) as u32;
+
+        let left = round(region.x, width);
+        let top = round(region.y, height);
+        let right = round(region.x + region.width, width);
+        let bottom = round(region.y + region.height, height);
+
+        (left < right && top < bottom).then(|| [left, top, right - left, bottom - top])
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use crate::{
+    keyboard::{keys::VirtualKeyCode, modifiers::Modifiers},
+    mouse::buttons::{MouseButton, MouseScrollDelta},
+};
+
+/// Quantidade de pixels considerada equivalente a uma linha de rolagem, usada para converter
+/// a rolagem de touchpads (em pixels) para a de rodas de mouse (em linhas).
+const PIXELS_PER_SCROLL_LINE: f32 = 20.0;
+
+/// Estado da entrada do teclado e do mouse, atualizado a partir dos eventos da janela antes
+/// de cada `AppEvent::Update`.
+///
+/// Os estados "just" (`just_pressed`, `just_released`, ...) e os deslocamentos do cursor e da
+/// roda valem somente para o quadro atual.
+#[derive(Debug, Default, Clone)]
+pub struct InputState {
+    keys_down: HashSet<VirtualKeyCode>,
+    keys_pressed: HashSet<VirtualKeyCode>,
+    keys_released: HashSet<VirtualKeyCode>,
+
+    buttons_down: HashSet<MouseButton>,
+    buttons_pressed: HashSet<MouseButton>,
+    buttons_released: HashSet<MouseButton>,
+
+    cursor_position: (f32, f32),
+    cursor_delta: (f32, f32),
+    wheel_delta: f32,
+
+    modifiers: Modifiers,
+}
+
+/// Consultas
+impl InputState {
+    /// Indica se a tecla está pressionada.
+    pub fn is_key_down(&self, key_code: VirtualKeyCode) -> bool {
+        self.keys_down.contains(&key_code)
+    }
+
+    /// Indica se a tecla começou a ser pressionada neste quadro.
+    pub fn just_pressed(&self, key_code: VirtualKeyCode) -> bool {
+        self.keys_pressed.contains(&key_code)
+    }
+
+    /// Indica se a tecla foi solta neste quadro.
+    pub fn just_released(&self, key_code: VirtualKeyCode) -> bool {
+        self.keys_released.contains(&key_code)
+    }
+
+    /// Indica se o botão do mouse está pressionado.
+    pub fn is_mouse_down(&self, button: MouseButton) -> bool {
+        self.buttons_down.contains(&button)
+    }
+
+    /// Indica se o botão do mouse começou a ser pressionado neste quadro.
+    pub fn mouse_just_pressed(&self, button: MouseButton) -> bool {
+        self.buttons_pressed.contains(&button)
+    }
+
+    /// Indica se o botão do mouse foi solto neste quadro.
+    pub fn mouse_just_released(&self, button: MouseButton) -> bool {
+        self.buttons_released.contains(&button)
+    }
+
+    /// A posição do cursor em pixels, a partir do canto superior esquerdo da janela.
+    pub fn cursor_position(&self) -> (f32, f32) {
+        self.cursor_position
+    }
+
+    /// O deslocamento do cursor, em pixels, acumulado neste quadro.
+    pub fn cursor_delta(&self) -> (f32, f32) {
+        self.cursor_delta
+    }
+
+    /// A rolagem vertical da roda do mouse, em linhas, acumulada neste quadro. É positiva
+    /// quando a roda é rolada para cima.
+    pub fn wheel_delta(&self) -> f32 {
+        self.wheel_delta
+    }
+
+    /// As teclas modificadoras que estão pressionadas.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+}
+
+/// Atualização a partir dos eventos da janela
+impl InputState {
+    pub fn key_pressed(&mut self, key_code: VirtualKeyCode) {
+        // A repetição automática do teclado envia vários eventos enquanto a tecla é segurada
+        if self.keys_down.insert(key_code) {
+            self.keys_pressed.insert(key_code);
+        }
+    }
+
+    pub fn key_released(&mut self, key_code: VirtualKeyCode) {
+        if self.keys_down.remove(&key_code) {
+            self.keys_released.insert(key_code);
+        }
+    }
+
+    pub fn mouse_pressed(&mut self, button: MouseButton) {
+        if self.buttons_down.insert(button) {
+            self.buttons_pressed.insert(button);
+        }
+    }
+
+    pub fn mouse_released(&mut self, button: MouseButton) {
+        if self.buttons_down.remove(&button) {
+            self.buttons_released.insert(button);
+        }
+    }
+
+    pub fn mouse_moved(&mut self, x: f32, y: f32, dx: f32, dy: f32) {
+        self.cursor_position = (x, y);
+        self.cursor_delta.0 += dx;
+        self.cursor_delta.1 += dy;
+    }
+
+    pub fn mouse_wheel(&mut self, delta: MouseScrollDelta) {
+        self.wheel_delta += match delta {
+            MouseScrollDelta::Lines { y, .. } => y,
+            MouseScrollDelta::Pixels { y, .. } => y / PIXELS_PER_SCROLL_LINE,
+        };
+    }
+
+    pub fn modifiers_changed(&mut self, modifiers: Modifiers) {
+        self.modifiers = modifiers;
+    }
+
+    /// Limpa os estados que valem somente para um quadro. Chamado depois de cada quadro.
+    pub fn end_frame(&mut self) {
+        self.keys_pressed.clear();
+        self.keys_released.clear();
+        self.buttons_pressed.clear();
+        self.buttons_released.clear();
+        self.cursor_delta = (0.0, 0.0);
+        self.wheel_delta = 0.0;
+    }
+}
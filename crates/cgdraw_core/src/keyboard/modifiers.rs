@@ -0,0 +1,26 @@
+use winit::event;
+
+/// As teclas modificadoras que estão pressionadas.
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+
+    /// A tecla do sistema (Windows, Command ou Super).
+    pub logo: bool,
+}
+
+impl Modifiers {
+    /**
+     * Converte o estado das teclas modificadoras do winit para o do cgdraw.
+     */
+    pub fn by_winit_modifiers(modifiers: event::ModifiersState) -> Self {
+        Self {
+            shift: modifiers.shift(),
+            ctrl: modifiers.ctrl(),
+            alt: modifiers.alt(),
+            logo: modifiers.logo(),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 pub mod color;
 pub mod graphic;
+pub mod input;
 pub mod keyboard;
+pub mod light;
+pub mod mouse;
 pub mod projection;
 
 pub mod uniforms;
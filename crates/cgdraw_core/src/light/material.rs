@@ -0,0 +1,45 @@
+use crate::color::{Color, SrgbColor};
+
+/// Como uma superfície reflete a luz no modelo de Blinn-Phong.
+#[derive(Debug, Clone, Copy)]
+pub struct Material {
+    /// A cor refletida igualmente em todas as direções. É multiplicada pela cor dos vértices.
+    pub diffuse: SrgbColor,
+
+    /// A cor do brilho refletido na direção da câmera.
+    pub specular: SrgbColor,
+
+    /// O quão concentrado é o brilho especular: valores maiores geram brilhos menores e mais
+    /// intensos.
+    pub shininess: f32,
+}
+
+impl Default for Material {
+    /// Material branco com um brilho especular moderado.
+    fn default() -> Self {
+        Self {
+            diffuse: Color::WHITE.into(),
+            specular: Color::GRAY.into(),
+            shininess: 32.0,
+        }
+    }
+}
+
+impl Material {
+    pub fn new(
+        diffuse: impl Into<SrgbColor>,
+        specular: impl Into<SrgbColor>,
+        shininess: f32,
+    ) -> Self {
+        Self {
+            diffuse: diffuse.into(),
+            specular: specular.into(),
+            shininess,
+        }
+    }
+
+    /// Um material fosco, sem brilho especular.
+    pub fn matte(diffuse: impl Into<SrgbColor>) -> Self {
+        Self::new(diffuse, Color::BLACK, 1.0)
+    }
+}
@@ -0,0 +1,185 @@
+mod material;
+
+pub use material::*;
+
+use cgdraw_math::{
+    angle::{Deg, Rad},
+    point::Point3,
+    vector::Vec3,
+};
+
+use crate::color::SrgbColor;
+
+/// O tipo de uma luz, que define como ela ilumina a cena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LightKind {
+    /// Ilumina todas as superfícies por igual, independentemente da sua posição e normal.
+    Ambient,
+
+    /// Uma luz muito distante, como o sol: todos os raios têm a mesma direção e não perdem
+    /// intensidade com a distância.
+    Directional,
+
+    /// Uma luz em um ponto que ilumina em todas as direções, como uma lâmpada.
+    Point,
+
+    /// Uma luz em um ponto que ilumina somente dentro de um cone, como uma lanterna.
+    Spot,
+}
+
+/// A atenuação de uma luz pontual ou holofote com a distância `d` até a superfície. A
+/// intensidade é dividida por `constant + linear * d + quadratic * d²`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attenuation {
+    pub constant: f32,
+    pub linear: f32,
+    pub quadratic: f32,
+}
+
+impl Default for Attenuation {
+    /// Sem atenuação: a luz tem a mesma intensidade a qualquer distância.
+    fn default() -> Self {
+        Self::new(1.0, 0.0, 0.0)
+    }
+}
+
+impl Attenuation {
+    pub fn new(constant: f32, linear: f32, quadratic: f32) -> Self {
+        Self {
+            constant,
+            linear,
+            quadratic,
+        }
+    }
+
+    /// Uma atenuação que reduz a intensidade para cerca de 1% a `range` unidades da luz.
+    pub fn range(range: f32) -> Self {
+        let range = range.max(f32::EPSILON);
+
+        Self::new(1.0, 4.5 / range, 75.0 / (range * range))
+    }
+
+    /// O fator que multiplica a intensidade da luz a `distance` unidades dela.
+    pub fn factor(&self, distance: f32) -> f32 {
+        1.0 / (self.constant + self.linear * distance + self.quadratic * distance * distance)
+    }
+}
+
+/// Uma luz da cena, iluminada com o modelo de Blinn-Phong.
+///
+/// ```
+/// # use cgdraw_core::{color::Color, light::{Attenuation, Light}};
+/// # use cgdraw_math::{angle::Deg, point::Point3, vector::Vec3};
+/// let sun = Light::directional(Vec3::new(-1.0, -1.0, -1.0), Color::WHITE).intensity(0.8);
+///
+/// let lamp = Light::point(Point3::new(0.0, 3.0, 0.0), Color::ORANGE)
+///     .attenuation(Attenuation::range(10.0));
+///
+/// let flashlight = Light::spot(
+///     Point3::new(0.0, 1.0, 5.0),
+///     Vec3::new(0.0, 0.0, -1.0),
+///     Color::WHITE,
+///     Deg(15.0).into(),
+///     Deg(25.0).into(),
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct Light {
+    pub kind: LightKind,
+    pub color: SrgbColor,
+
+    /// O multiplicador da cor da luz.
+    pub intensity: f32,
+
+    /// A posição da luz no espaço global. Usada pelas luzes pontuais e holofotes.
+    pub position: Point3<f32>,
+
+    /// A direção para onde a luz aponta no espaço global. Usada pelas luzes direcionais e
+    /// holofotes.
+    pub direction: Vec3<f32>,
+
+    /// A atenuação com a distância. Usada pelas luzes pontuais e holofotes.
+    pub attenuation: Attenuation,
+
+    /// O ângulo, a partir do eixo do holofote, até onde a luz tem intensidade total.
+    pub inner_angle: Rad<f32>,
+
+    /// O ângulo, a partir do eixo do holofote, a partir do qual não há luz. Entre os dois
+    /// ângulos, a intensidade diminui suavemente.
+    pub outer_angle: Rad<f32>,
+}
+
+impl Light {
+    fn new(kind: LightKind, color: impl Into<SrgbColor>) -> Self {
+        Self {
+            kind,
+            color: color.into(),
+            intensity: 1.0,
+            position: Point3::new(0.0, 0.0, 0.0),
+            direction: Vec3::new(0.0, -1.0, 0.0),
+            attenuation: Attenuation::default(),
+            inner_angle: Deg(30.0).into(),
+            outer_angle: Deg(30.0).into(),
+        }
+    }
+
+    /// Cria uma luz ambiente. As luzes ambientes de um quadro são somadas.
+    pub fn ambient(color: impl Into<SrgbColor>) -> Self {
+        Self::new(LightKind::Ambient, color)
+    }
+
+    /// Cria uma luz direcional cujos raios seguem `direction`.
+    pub fn directional(direction: Vec3<f32>, color: impl Into<SrgbColor>) -> Self {
+        Self {
+            direction,
+            ..Self::new(LightKind::Directional, color)
+        }
+    }
+
+    /// Cria uma luz pontual em `position`, sem atenuação.
+    pub fn point(position: Point3<f32>, color: impl Into<SrgbColor>) -> Self {
+        Self {
+            position,
+            ..Self::new(LightKind::Point, color)
+        }
+    }
+
+    /// Cria um holofote em `position` apontando para `direction`, sem atenuação. A luz tem
+    /// intensidade total até `inner_angle` do eixo e some a partir de `outer_angle`.
+    pub fn spot(
+        position: Point3<f32>,
+        direction: Vec3<f32>,
+        color: impl Into<SrgbColor>,
+        inner_angle: Rad<f32>,
+        outer_angle: Rad<f32>,
+    ) -> Self {
+        Self {
+            position,
+            direction,
+            inner_angle,
+            outer_angle,
+            ..Self::new(LightKind::Spot, color)
+        }
+    }
+
+    /// Define o multiplicador da cor da luz.
+    pub fn intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// Define a atenuação com a distância, usada pelas luzes pontuais e holofotes.
+    pub fn attenuation(mut self, attenuation: Attenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
+
+    /// A cor da luz multiplicada pela sua intensidade.
+    pub fn radiance(&self) -> [f32; 3] {
+        [
+            self.color.r * self.intensity,
+            self.color.g * self.intensity,
+            self.color.b * self.intensity,
+        ]
+    }
+}
@@ -0,0 +1,48 @@
+use winit::event;
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    Other(u16),
+}
+
+impl MouseButton {
+    /**
+     * Converte um botão do mouse do winit para um botão do mouse do cgdraw.
+     */
+    pub fn by_winit_button(button: event::MouseButton) -> Self {
+        match button {
+            event::MouseButton::Left => MouseButton::Left,
+            event::MouseButton::Right => MouseButton::Right,
+            event::MouseButton::Middle => MouseButton::Middle,
+            event::MouseButton::Other(id) => MouseButton::Other(id),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MouseScrollDelta {
+    /// Quantidade de linhas (ou "cliques" da roda) rolada em cada eixo. O valor de `y` é
+    /// positivo quando a roda é rolada para cima.
+    Lines { x: f32, y: f32 },
+
+    /// Quantidade de pixels rolada em cada eixo, como acontece em touchpads.
+    Pixels { x: f32, y: f32 },
+}
+
+impl MouseScrollDelta {
+    /**
+     * Converte uma rolagem do winit para uma rolagem do cgdraw.
+     */
+    pub fn by_winit_delta(delta: event::MouseScrollDelta) -> Self {
+        match delta {
+            event::MouseScrollDelta::LineDelta(x, y) => MouseScrollDelta::Lines { x, y },
+            event::MouseScrollDelta::PixelDelta(position) => MouseScrollDelta::Pixels {
+                x: position.x as f32,
+                y: position.y as f32,
+            },
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use cgdraw_math::{angle::Rad, matrix::Matrix4x4, num::BaseFloat};
+use cgdraw_math::{angle::Rad, matrix::Matrix4x4, num::BaseFloat, vector::Vec3};
 use num_traits::cast;
 
 fn opengl_to_wgpu_matrix<T: BaseFloat>() -> Matrix4x4<T> {
@@ -15,6 +15,12 @@ fn opengl_to_wgpu_matrix<T: BaseFloat>() -> Matrix4x4<T> {
     matrix
 }
 
+/// Converte uma projeção destra (right-handed), que enxerga o eixo -Z do espaço de visão, na
+/// projeção canhota (left-handed) equivalente, que enxerga o eixo +Z.
+fn left_handed<T: BaseFloat>(projection: Matrix4x4<T>) -> Matrix4x4<T> {
+    projection * Matrix4x4::from_scale(Vec3::new(T::one(), T::one(), -T::one()))
+}
+
 pub fn perspective<T: BaseFloat>(aspect: T, fovy: Rad<T>, znear: T, zfar: T) -> Matrix4x4<T> {
     let two: T = cast(2).unwrap();
     let f = Rad::cot(fovy / two);
@@ -87,3 +93,178 @@ pub fn orthographic<T: BaseFloat>(
 
     opengl_to_wgpu_matrix() * ortho_matrix
 }
+
+/// Projeção em perspectiva com o volume de visão assimétrico (off-axis), definido pelos
+/// limites `left`, `right`, `bottom` e `top` no plano de perto, como o `glFrustum`. Útil para
+/// estéreo, telas de múltiplos monitores e recortes de uma imagem maior.
+pub fn frustum<T: BaseFloat>(
+    left: T,
+    right: T,
+    bottom: T,
+    top: T,
+    znear: T,
+    zfar: T,
+) -> Matrix4x4<T> {
+    let two: T = cast(2).unwrap();
+
+    let c0r0 = (two * znear) / (right - left);
+    let c0r1 = T::zero();
+    let c0r2 = T::zero();
+    let c0r3 = T::zero();
+
+    let c1r0 = T::zero();
+    let c1r1 = (two * znear) / (top - bottom);
+    let c1r2 = T::zero();
+    let c1r3 = T::zero();
+
+    let c2r0 = (right + left) / (right - left);
+    let c2r1 = (top + bottom) / (top - bottom);
+    let c2r2 = -(zfar + znear) / (zfar - znear);
+    let c2r3 = -T::one();
+
+    let c3r0 = T::zero();
+    let c3r1 = T::zero();
+    let c3r2 = -(two * zfar * znear) / (zfar - znear);
+    let c3r3 = T::zero();
+
+    #[rustfmt::skip]
+    let frustum_matrix = Matrix4x4::new(
+        c0r0, c0r1, c0r2, c0r3,
+        c1r0, c1r1, c1r2, c1r3,
+        c2r0, c2r1, c2r2, c2r3,
+        c3r0, c3r1, c3r2, c3r3,
+    );
+
+    opengl_to_wgpu_matrix() * frustum_matrix
+}
+
+/// Projeção em perspectiva sem plano de longe: a profundidade vai de 0, no plano de perto, a 1
+/// no infinito. Útil para céus e cenas muito grandes.
+pub fn perspective_infinite<T: BaseFloat>(aspect: T, fovy: Rad<T>, znear: T) -> Matrix4x4<T> {
+    let two: T = cast(2).unwrap();
+    let f = Rad::cot(fovy / two);
+
+    let c0r0 = f / aspect;
+    let c0r1 = T::zero();
+    let c0r2 = T::zero();
+    let c0r3 = T::zero();
+
+    let c1r0 = T::zero();
+    let c1r1 = f;
+    let c1r2 = T::zero();
+    let c1r3 = T::zero();
+
+    let c2r0 = T::zero();
+    let c2r1 = T::zero();
+    let c2r2 = -T::one();
+    let c2r3 = -T::one();
+
+    let c3r0 = T::zero();
+    let c3r1 = T::zero();
+    let c3r2 = -two * znear;
+    let c3r3 = T::zero();
+
+    #[rustfmt::skip]
+    let perspective_matrix = Matrix4x4::new(
+        c0r0, c0r1, c0r2, c0r3,
+        c1r0, c1r1, c1r2, c1r3,
+        c2r0, c2r1, c2r2, c2r3,
+        c3r0, c3r1, c3r2, c3r3,
+    );
+
+    opengl_to_wgpu_matrix() * perspective_matrix
+}
+
+/// Projeção em perspectiva com a profundidade invertida (reversed-Z): o plano de perto fica na
+/// profundidade 1 e o de longe na 0. Como os números de ponto flutuante têm mais precisão perto
+/// de zero, a precisão fica mais uniforme ao longo da cena, reduzindo o z-fighting em objetos
+/// distantes.
+///
+/// Deve ser usada com `DepthMode::ReversedZ`, que limpa a profundidade com 0 e desenha os
+/// fragmentos de maior profundidade.
+pub fn perspective_reversed_z<T: BaseFloat>(
+    aspect: T,
+    fovy: Rad<T>,
+    znear: T,
+    zfar: T,
+) -> Matrix4x4<T> {
+    let two: T = cast(2).unwrap();
+    let f = Rad::cot(fovy / two);
+
+    let c0r0 = f / aspect;
+    let c0r1 = T::zero();
+    let c0r2 = T::zero();
+    let c0r3 = T::zero();
+
+    let c1r0 = T::zero();
+    let c1r1 = f;
+    let c1r2 = T::zero();
+    let c1r3 = T::zero();
+
+    let c2r0 = T::zero();
+    let c2r1 = T::zero();
+    let c2r2 = znear / (zfar - znear);
+    let c2r3 = -T::one();
+
+    let c3r0 = T::zero();
+    let c3r1 = T::zero();
+    let c3r2 = (zfar * znear) / (zfar - znear);
+    let c3r3 = T::zero();
+
+    // Já está no intervalo de profundidade do wgpu, de 0 a 1
+    #[rustfmt::skip]
+    let perspective_matrix = Matrix4x4::new(
+        c0r0, c0r1, c0r2, c0r3,
+        c1r0, c1r1, c1r2, c1r3,
+        c2r0, c2r1, c2r2, c2r3,
+        c3r0, c3r1, c3r2, c3r3,
+    );
+
+    perspective_matrix
+}
+
+/// Versão canhota (left-handed) de [`perspective`], que enxerga o eixo +Z do espaço de visão.
+pub fn perspective_lh<T: BaseFloat>(aspect: T, fovy: Rad<T>, znear: T, zfar: T) -> Matrix4x4<T> {
+    left_handed(perspective(aspect, fovy, znear, zfar))
+}
+
+/// Versão canhota (left-handed) de [`orthographic`], que enxerga o eixo +Z do espaço de visão.
+pub fn orthographic_lh<T: BaseFloat>(
+    left: T,
+    right: T,
+    bottom: T,
+    top: T,
+    znear: T,
+    zfar: T,
+) -> Matrix4x4<T> {
+    left_handed(orthographic(left, right, bottom, top, znear, zfar))
+}
+
+/// Versão canhota (left-handed) de [`frustum`], que enxerga o eixo +Z do espaço de visão.
+pub fn frustum_lh<T: BaseFloat>(
+    left: T,
+    right: T,
+    bottom: T,
+    top: T,
+    znear: T,
+    zfar: T,
+) -> Matrix4x4<T> {
+    left_handed(frustum(left, right, bottom, top, znear, zfar))
+}
+
+/// Versão canhota (left-handed) de [`perspective_infinite`], que enxerga o eixo +Z do espaço
+/// de visão.
+pub fn perspective_infinite_lh<T: BaseFloat>(aspect: T, fovy: Rad<T>, znear: T) -> Matrix4x4<T> {
+    left_handed(perspective_infinite(aspect, fovy, znear))
+}
+
+/// Versão canhota (left-handed) de [`perspective_reversed_z`], que enxerga o eixo +Z do espaço
+/// de visão.
+pub fn perspective_reversed_z_lh<T: BaseFloat>(
+    aspect: T,
+    fovy: Rad<T>,
+    znear: T,
+    zfar: T,
+) -> Matrix4x4<T> {
+    left_handed(perspective_reversed_z(aspect, fovy, znear, zfar))
+}
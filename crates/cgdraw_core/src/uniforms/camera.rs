@@ -1,17 +1,75 @@
-use cgdraw_math::matrix::Matrix4x4;
+use cgdraw_math::{matrix::Matrix4x4, vector::Vec4};
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct CameraUniformFloat32 {
     pub view_proj: [[f32; 4]; 4],
+
+    /// A posição da câmera no espaço global, com `w = 1`. Nas projeções ortográficas, em que
+    /// todos os raios da câmera são paralelos, é a direção que aponta para a câmera, com
+    /// `w = 0`. Usada pela iluminação para calcular o brilho especular.
     pub view_position: [f32; 4],
+
+    /// A matriz de visão, que leva do espaço global ao espaço da câmera.
+    pub view: [[f32; 4]; 4],
+
+    /// A matriz de projeção, que leva do espaço da câmera ao espaço de recorte.
+    pub proj: [[f32; 4]; 4],
 }
 
 impl Default for CameraUniformFloat32 {
     fn default() -> Self {
         Self {
             view_proj: Matrix4x4::identity().into(),
-            view_position: [0.0; 4],
+            view_position: [0.0, 0.0, 0.0, 1.0],
+            view: Matrix4x4::identity().into(),
+            proj: Matrix4x4::identity().into(),
         }
     }
 }
+
+impl CameraUniformFloat32 {
+    /// Preenche todos os campos a partir das matrizes de visão e de projeção. A posição da
+    /// câmera é calculada pela inversa de `proj * view`, então também funciona com câmeras
+    /// que só informam a matriz combinada. Caso ela não possua inversa, a posição anterior é
+    /// mantida.
+    pub fn set_matrices(&mut self, view: Matrix4x4<f32>, proj: Matrix4x4<f32>) {
+        self.view_proj = (proj * view).into();
+        self.view = view.into();
+        self.proj = proj.into();
+
+        self.set_view_position(proj * view);
+    }
+
+    /// Preenche os campos a partir somente da matriz de visualização e projeção, como quando
+    /// ela não vem de uma câmera. A posição da câmera é calculada pela inversa da matriz; `view`
+    /// vira a identidade e `proj` a própria `view_proj`. Caso a matriz não possua inversa, a
+    /// posição anterior é mantida.
+    pub fn set_view_proj(&mut self, view_proj: Matrix4x4<f32>) {
+        self.view_proj = view_proj.into();
+        self.view = Matrix4x4::identity().into();
+        self.proj = view_proj.into();
+
+        self.set_view_position(view_proj);
+    }
+
+    /// Calcula `view_position` pela inversa de `view_proj`, sem depender da orientação
+    /// (destra ou canhota) da visão nem do tipo de projeção.
+    fn set_view_position(&mut self, view_proj: Matrix4x4<f32>) {
+        let Some(inverse) = view_proj.invert() else {
+            return;
+        };
+
+        // Os pontos do espaço de recorte com `x = y = w = 0` são a posição da câmera: um ponto
+        // nas projeções perspectivas e, nas ortográficas, a direção do eixo Z no infinito
+        let eye = inverse * Vec4::new(0.0, 0.0, 1.0, 0.0);
+        let direction = eye.truncate();
+
+        self.view_position = if eye.w.abs() > direction.magnitude() * f32::EPSILON {
+            (direction / eye.w).extend(1.0).into()
+        } else {
+            // A profundidade cresce para longe da câmera
+            (-direction.normalize()).extend(0.0).into()
+        };
+    }
+}
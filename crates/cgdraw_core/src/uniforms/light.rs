@@ -0,0 +1,164 @@
+use crate::light::{Light, LightKind};
+
+/// O maior número de luzes direcionais, pontuais e holofotes em um quadro. As luzes ambientes
+/// não entram nessa conta, já que são somadas em uma única cor.
+pub const MAX_LIGHTS: usize = 8;
+
+/// Uma luz no formato do shader.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LightUniformFloat32 {
+    /// A posição no espaço global, com `w = 1`.
+    pub position: [f32; 4],
+
+    /// A direção normalizada para onde a luz aponta, com `w = 0`.
+    pub direction: [f32; 4],
+
+    /// A cor multiplicada pela intensidade.
+    pub color: [f32; 4],
+
+    /// Os termos constante, linear e quadrático da atenuação.
+    pub attenuation: [f32; 4],
+
+    /// O tipo da luz: `DIRECTIONAL`, `POINT` ou `SPOT`.
+    pub kind: u32,
+
+    /// Os cossenos dos ângulos interno e externo do cone do holofote.
+    pub inner_cos: f32,
+    pub outer_cos: f32,
+
+    _padding: u32,
+}
+
+impl LightUniformFloat32 {
+    pub const DIRECTIONAL: u32 = 0;
+    pub const POINT: u32 = 1;
+    pub const SPOT: u32 = 2;
+}
+
+/// Todas as luzes de um quadro, no formato do shader.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LightsUniformFloat32 {
+    /// A soma das luzes ambientes.
+    pub ambient: [f32; 4],
+
+    /// Quantas posições de `lights` estão em uso.
+    pub count: u32,
+
+    _padding: [u32; 3],
+
+    pub lights: [LightUniformFloat32; MAX_LIGHTS],
+}
+
+impl Default for LightsUniformFloat32 {
+    /// Nenhuma luz: as superfícies iluminadas ficam pretas.
+    fn default() -> Self {
+        bytemuck::Zeroable::zeroed()
+    }
+}
+
+impl LightsUniformFloat32 {
+    /// O tamanho do objeto em bytes.
+    pub const SIZE: wgpu::BufferAddress = std::mem::size_of::<Self>() as wgpu::BufferAddress;
+
+    /// Adiciona uma luz. As luzes ambientes são somadas em `ambient`.
+    ///
+    /// # Panics
+    ///
+    /// Caso já existam `MAX_LIGHTS` luzes que não são ambientes.
+    pub fn push(&mut self, light: &Light) {
+        let [r, g, b] = light.radiance();
+
+        let kind = match light.kind {
+            LightKind::Ambient => {
+                self.ambient[0] += r;
+                self.ambient[1] += g;
+                self.ambient[2] += b;
+                return;
+            }
+            LightKind::Directional => LightUniformFloat32::DIRECTIONAL,
+            LightKind::Point => LightUniformFloat32::POINT,
+            LightKind::Spot => LightUniformFloat32::SPOT,
+        };
+
+        if self.count as usize >= MAX_LIGHTS {
+            panic!("O limite de {MAX_LIGHTS} luzes por quadro foi atingido!");
+        }
+
+        let direction = if light.direction.magnitude2() > 0.0 {
+            light.direction.normalize()
+        } else {
+            light.direction
+        };
+
+        // O cone externo precisa ser um pouco maior que o interno para a transição suave
+        let inner_cos = light.inner_angle.0.cos();
+        let outer_cos = light.outer_angle.0.cos().min(inner_cos - 1e-4);
+
+        self.lights[self.count as usize] = LightUniformFloat32 {
+            position: light.position.to_homogeneous().into(),
+            direction: direction.extend(0.0).into(),
+            color: [r, g, b, 1.0],
+            attenuation: [
+                light.attenuation.constant,
+                light.attenuation.linear,
+                light.attenuation.quadratic,
+                0.0,
+            ],
+            kind,
+            inner_cos,
+            outer_cos,
+            _padding: 0,
+        };
+        self.count += 1;
+    }
+
+    /// Remove todas as luzes.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Cria o buffer que guarda as luzes do quadro.
+    pub fn create_buffer(device: &wgpu::Device) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Lights Buffer"),
+            size: Self::SIZE,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Cria um `bind_group_layout` para as luzes, usado pelo grupo 1 dos pipelines.
+    pub fn create_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+        device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: wgpu::BufferSize::new(Self::SIZE),
+                },
+                count: None,
+            }],
+            label: Some("lights_bind_group_layout"),
+        })
+    }
+
+    /// Cria um `bind_group` para as luzes.
+    pub fn create_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+            label: Some("lights_bind_group"),
+        })
+    }
+}
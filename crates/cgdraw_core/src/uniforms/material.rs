@@ -0,0 +1,31 @@
+use crate::light::Material;
+
+/// O material de um desenho, no formato do shader.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct MaterialUniformFloat32 {
+    pub diffuse: [f32; 4],
+    pub specular: [f32; 4],
+    pub shininess: f32,
+    _padding: [f32; 3],
+}
+
+impl Default for MaterialUniformFloat32 {
+    fn default() -> Self {
+        Material::default().into()
+    }
+}
+
+impl From<Material> for MaterialUniformFloat32 {
+    fn from(material: Material) -> Self {
+        let diffuse = material.diffuse;
+        let specular = material.specular;
+
+        Self {
+            diffuse: [diffuse.r, diffuse.g, diffuse.b, 1.0],
+            specular: [specular.r, specular.g, specular.b, 1.0],
+            shininess: material.shininess,
+            _padding: [0.0; 3],
+        }
+    }
+}
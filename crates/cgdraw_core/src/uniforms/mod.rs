@@ -1,14 +1,26 @@
-use cgdraw_math::matrix::Matrix4x4;
-
-use self::camera::CameraUniformFloat32;
+use cgdraw_math::matrix::{Matrix3x3, Matrix4x4};
 
 mod camera;
+mod light;
+mod material;
+
+pub use camera::*;
+pub use light::*;
+pub use material::*;
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct UniformsFloat32 {
     pub camera: CameraUniformFloat32,
     pub model: [[f32; 4]; 4],
+
+    /// A matriz que leva as normais para o espaço global mesmo com escalas diferentes em cada
+    /// eixo. É proporcional à inversa transposta de `model`, então o shader normaliza as
+    /// normais depois de transformá-las.
+    pub normal: [[f32; 4]; 4],
+
+    /// O material usado pela iluminação.
+    pub material: MaterialUniformFloat32,
 }
 
 impl Default for UniformsFloat32 {
@@ -16,17 +28,64 @@ impl Default for UniformsFloat32 {
         UniformsFloat32 {
             camera: CameraUniformFloat32::default(),
             model: Matrix4x4::identity().into(),
+            normal: Matrix4x4::identity().into(),
+            material: MaterialUniformFloat32::default(),
         }
     }
 }
 
 impl UniformsFloat32 {
-    pub fn create_buffer(device: &wgpu::Device, uniform: Self) -> wgpu::Buffer {
-        use wgpu::util::DeviceExt;
-        device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+    /// Cria o objeto uniforms de um desenho, calculando a matriz das normais a partir de
+    /// `model`.
+    pub fn new(
+        camera: CameraUniformFloat32,
+        model: Matrix4x4<f32>,
+        material: MaterialUniformFloat32,
+    ) -> Self {
+        // A matriz dos cofatores da parte linear é a inversa transposta multiplicada pelo
+        // determinante. Ela não tem divisões, então funciona com escalas muito pequenas e
+        // até sem inversa; do determinante só importa o sinal, que inverte as normais dos
+        // modelos espelhados
+        let linear = Matrix3x3::from(model);
+        let cofactors = Matrix3x3::from_cols(
+            linear.c1.cross(linear.c2),
+            linear.c2.cross(linear.c0),
+            linear.c0.cross(linear.c1),
+        );
+        let sign = if linear.determinant() < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+        let normal = Matrix4x4::from(cofactors * sign);
+
+        Self {
+            camera,
+            model: model.into(),
+            normal: normal.into(),
+            material,
+        }
+    }
+
+    /// O tamanho do objeto uniforms em bytes.
+    pub const SIZE: wgpu::BufferAddress = std::mem::size_of::<Self>() as wgpu::BufferAddress;
+
+    /// A distância em bytes entre dois objetos uniforms no buffer, respeitando o alinhamento
+    /// mínimo exigido pelo dispositivo para os deslocamentos dinâmicos.
+    pub fn stride(device: &wgpu::Device) -> wgpu::BufferAddress {
+        let align = device.limits().min_uniform_buffer_offset_alignment as wgpu::BufferAddress;
+
+        (Self::SIZE + align - 1) / align * align
+    }
+
+    /// Cria um buffer com espaço para `capacity` objetos uniforms, um para cada desenho do
+    /// quadro. Cada desenho escolhe o seu objeto através de um deslocamento dinâmico.
+    pub fn create_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Uniforms Buffer"),
-            contents: bytemuck::cast_slice(&[uniform]),
+            size: Self::stride(device) * capacity.max(1) as wgpu::BufferAddress,
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
         })
     }
 
@@ -38,8 +97,8 @@ impl UniformsFloat32 {
                 visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
                 ty: wgpu::BindingType::Buffer {
                     ty: wgpu::BufferBindingType::Uniform,
-                    has_dynamic_offset: false,
-                    min_binding_size: None,
+                    has_dynamic_offset: true,
+                    min_binding_size: wgpu::BufferSize::new(Self::SIZE),
                 },
                 count: None,
             }],
@@ -57,7 +116,11 @@ impl UniformsFloat32 {
             layout,
             entries: &[wgpu::BindGroupEntry {
                 binding: 0,
-                resource: buffer.as_entire_binding(),
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer,
+                    offset: 0,
+                    size: wgpu::BufferSize::new(Self::SIZE),
+                }),
             }],
             label: Some("uniforms_bind_group"),
         })
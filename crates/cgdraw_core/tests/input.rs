@@ -0,0 +1,139 @@
+use cgdraw_core::{
+    input::InputState,
+    keyboard::{keys::VirtualKeyCode, modifiers::Modifiers},
+    mouse::buttons::{MouseButton, MouseScrollDelta},
+};
+
+#[test]
+fn keys_are_just_pressed_only_in_the_first_frame() {
+    let mut input = InputState::default();
+
+    input.key_pressed(VirtualKeyCode::W);
+
+    assert!(input.is_key_down(VirtualKeyCode::W));
+    assert!(input.just_pressed(VirtualKeyCode::W));
+    assert!(!input.just_pressed(VirtualKeyCode::S));
+
+    input.end_frame();
+
+    assert!(input.is_key_down(VirtualKeyCode::W));
+    assert!(!input.just_pressed(VirtualKeyCode::W));
+}
+
+#[test]
+fn keys_are_just_released_only_in_the_frame_they_are_released() {
+    let mut input = InputState::default();
+
+    input.key_pressed(VirtualKeyCode::W);
+    input.end_frame();
+    input.key_released(VirtualKeyCode::W);
+
+    assert!(!input.is_key_down(VirtualKeyCode::W));
+    assert!(input.just_released(VirtualKeyCode::W));
+
+    input.end_frame();
+
+    assert!(!input.just_released(VirtualKeyCode::W));
+}
+
+#[test]
+fn releasing_a_key_that_is_not_down_is_ignored() {
+    let mut input = InputState::default();
+
+    input.key_released(VirtualKeyCode::W);
+
+    assert!(!input.just_released(VirtualKeyCode::W));
+}
+
+#[test]
+fn key_auto_repeat_does_not_press_the_key_again() {
+    let mut input = InputState::default();
+
+    input.key_pressed(VirtualKeyCode::Space);
+    input.end_frame();
+
+    // Eventos repetidos enquanto a tecla é segurada
+    input.key_pressed(VirtualKeyCode::Space);
+    input.key_pressed(VirtualKeyCode::Space);
+
+    assert!(input.is_key_down(VirtualKeyCode::Space));
+    assert!(!input.just_pressed(VirtualKeyCode::Space));
+}
+
+#[test]
+fn a_key_pressed_and_released_in_the_same_frame_reports_both() {
+    let mut input = InputState::default();
+
+    input.key_pressed(VirtualKeyCode::E);
+    input.key_released(VirtualKeyCode::E);
+
+    assert!(input.just_pressed(VirtualKeyCode::E));
+    assert!(input.just_released(VirtualKeyCode::E));
+    assert!(!input.is_key_down(VirtualKeyCode::E));
+}
+
+#[test]
+fn mouse_buttons_follow_the_same_frame_rules() {
+    let mut input = InputState::default();
+
+    input.mouse_pressed(MouseButton::Left);
+    input.mouse_pressed(MouseButton::Left);
+
+    assert!(input.is_mouse_down(MouseButton::Left));
+    assert!(input.mouse_just_pressed(MouseButton::Left));
+    assert!(!input.is_mouse_down(MouseButton::Right));
+
+    input.end_frame();
+    input.mouse_released(MouseButton::Left);
+
+    assert!(!input.is_mouse_down(MouseButton::Left));
+    assert!(!input.mouse_just_pressed(MouseButton::Left));
+    assert!(input.mouse_just_released(MouseButton::Left));
+}
+
+#[test]
+fn cursor_deltas_are_summed_until_the_end_of_the_frame() {
+    let mut input = InputState::default();
+
+    input.mouse_moved(10.0, 20.0, 2.0, -1.0);
+    input.mouse_moved(13.0, 24.0, 3.0, 4.0);
+
+    assert_eq!(input.cursor_position(), (13.0, 24.0));
+    assert_eq!(input.cursor_delta(), (5.0, 3.0));
+
+    input.end_frame();
+
+    // A posição continua valendo nos próximos quadros
+    assert_eq!(input.cursor_position(), (13.0, 24.0));
+    assert_eq!(input.cursor_delta(), (0.0, 0.0));
+}
+
+#[test]
+fn wheel_pixels_are_converted_to_lines() {
+    let mut input = InputState::default();
+
+    input.mouse_wheel(MouseScrollDelta::Lines { x: 0.0, y: 1.0 });
+    input.mouse_wheel(MouseScrollDelta::Pixels { x: 5.0, y: -10.0 });
+
+    // 20 pixels equivalem a uma linha
+    assert_eq!(input.wheel_delta(), 0.5);
+
+    input.end_frame();
+
+    assert_eq!(input.wheel_delta(), 0.0);
+}
+
+#[test]
+fn modifiers_are_kept_between_frames() {
+    let mut input = InputState::default();
+
+    let modifiers = Modifiers {
+        shift: true,
+        ..Modifiers::default()
+    };
+
+    input.modifiers_changed(modifiers);
+    input.end_frame();
+
+    assert_eq!(input.modifiers(), modifiers);
+}
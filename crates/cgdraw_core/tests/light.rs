@@ -0,0 +1,185 @@
+use approx::assert_relative_eq;
+use cgdraw_core::{
+    color::Color,
+    light::{Attenuation, Light, Material},
+    uniforms::{
+        CameraUniformFloat32, LightUniformFloat32, LightsUniformFloat32, MaterialUniformFloat32,
+        UniformsFloat32, MAX_LIGHTS,
+    },
+};
+use cgdraw_math::{
+    angle::{Deg, Rad},
+    matrix::Matrix4x4,
+    point::Point3,
+    vector::{Vec3, Vec4},
+};
+
+#[test]
+fn ambient_lights_are_summed_and_do_not_use_a_slot() {
+    let mut lights = LightsUniformFloat32::default();
+
+    lights.push(&Light::ambient(Color::RED).intensity(0.25));
+    lights.push(&Light::ambient(Color::WHITE).intensity(0.5));
+
+    assert_eq!(lights.count, 0);
+    assert_relative_eq!(lights.ambient[0], 0.75);
+    assert_relative_eq!(lights.ambient[1], 0.5);
+    assert_relative_eq!(lights.ambient[2], 0.5);
+}
+
+#[test]
+fn lights_are_packed_in_the_shader_layout() {
+    let mut lights = LightsUniformFloat32::default();
+
+    lights.push(&Light::directional(Vec3::new(0.0, -2.0, 0.0), Color::WHITE).intensity(2.0));
+    lights.push(
+        &Light::point(Point3::new(1.0, 2.0, 3.0), Color::BLUE)
+            .attenuation(Attenuation::new(1.0, 0.5, 0.25)),
+    );
+    lights.push(&Light::spot(
+        Point3::new(0.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, -1.0),
+        Color::WHITE,
+        Deg(30.0).into(),
+        Deg(60.0).into(),
+    ));
+
+    assert_eq!(lights.count, 3);
+
+    let [directional, point, spot] = [lights.lights[0], lights.lights[1], lights.lights[2]];
+
+    // A direção é normalizada e a intensidade multiplica a cor
+    assert_eq!(directional.kind, LightUniformFloat32::DIRECTIONAL);
+    assert_eq!(directional.direction, [0.0, -1.0, 0.0, 0.0]);
+    assert_eq!(directional.color, [2.0, 2.0, 2.0, 1.0]);
+
+    assert_eq!(point.kind, LightUniformFloat32::POINT);
+    assert_eq!(point.position, [1.0, 2.0, 3.0, 1.0]);
+    assert_eq!(point.attenuation, [1.0, 0.5, 0.25, 0.0]);
+
+    assert_eq!(spot.kind, LightUniformFloat32::SPOT);
+    assert_relative_eq!(spot.inner_cos, 3f32.sqrt() / 2.0, epsilon = 1e-6);
+    assert_relative_eq!(spot.outer_cos, 0.5, epsilon = 1e-6);
+
+    lights.clear();
+    assert_eq!(lights.count, 0);
+}
+
+#[test]
+fn the_spot_cone_always_has_a_smooth_edge() {
+    let mut lights = LightsUniformFloat32::default();
+
+    lights.push(&Light::spot(
+        Point3::new(0.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, -1.0),
+        Color::WHITE,
+        Deg(20.0).into(),
+        Deg(20.0).into(),
+    ));
+
+    assert!(lights.lights[0].outer_cos < lights.lights[0].inner_cos);
+}
+
+#[test]
+#[should_panic]
+fn more_than_max_lights_panics() {
+    let mut lights = LightsUniformFloat32::default();
+
+    for _ in 0..=MAX_LIGHTS {
+        lights.push(&Light::point(Point3::new(0.0, 0.0, 0.0), Color::WHITE));
+    }
+}
+
+#[test]
+fn range_attenuation_fades_to_about_one_percent() {
+    let attenuation = Attenuation::range(10.0);
+
+    assert_relative_eq!(attenuation.factor(0.0), 1.0);
+    assert!(attenuation.factor(5.0) > attenuation.factor(10.0));
+    assert_relative_eq!(attenuation.factor(10.0), 0.0125, epsilon = 0.005);
+}
+
+#[test]
+fn material_uniform_keeps_the_colors_and_shininess() {
+    let material: MaterialUniformFloat32 = Material::new(Color::RED, Color::WHITE, 64.0).into();
+
+    assert_eq!(material.diffuse, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(material.specular, [1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(material.shininess, 64.0);
+
+    let matte: MaterialUniformFloat32 = Material::matte(Color::GREEN).into();
+    assert_eq!(matte.specular, [0.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn normal_matrix_keeps_normals_perpendicular_under_non_uniform_scale() {
+    let model = Matrix4x4::from_scale(Vec3::new(2.0, 1.0, 1.0));
+    let uniforms = UniformsFloat32::new(
+        CameraUniformFloat32::default(),
+        model,
+        MaterialUniformFloat32::default(),
+    );
+
+    // A normal do plano x + y = 0 é (1, 1, 0). Depois da escala, o plano vira x / 2 + y = 0,
+    // cuja normal é (1, 2, 0)
+    let normal = Matrix4x4::from(uniforms.normal) * Vec4::new(1.0, 1.0, 0.0, 0.0);
+    let tangent = model * Vec4::new(1.0, -1.0, 0.0, 0.0);
+
+    assert_relative_eq!(normal.dot(tangent), 0.0);
+    assert_relative_eq!(normal.y / normal.x, 2.0);
+}
+
+/// A direção da normal `n` transformada pela matriz das normais de `model`.
+fn transformed_normal(model: Matrix4x4<f32>, n: Vec3<f32>) -> Vec3<f32> {
+    let uniforms = UniformsFloat32::new(
+        CameraUniformFloat32::default(),
+        model,
+        MaterialUniformFloat32::default(),
+    );
+
+    (Matrix4x4::from(uniforms.normal) * n.extend(0.0))
+        .truncate()
+        .normalize()
+}
+
+#[test]
+fn normal_matrix_matches_the_inverse_transpose_of_scaled_and_rotated_models() {
+    let model = Matrix4x4::from_translate(Vec3::new(5.0, -2.0, 1.0))
+        * Matrix4x4::from_rotate_z(Rad(0.7))
+        * Matrix4x4::from_rotate_x(Rad(-1.1))
+        * Matrix4x4::from_scale(Vec3::new(3.0, 0.5, 1.5));
+    let inverse_transpose = model.invert().unwrap().transpose();
+
+    for n in [
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(1.0, 2.0, -3.0),
+    ] {
+        let expected = (inverse_transpose * n.extend(0.0)).truncate().normalize();
+
+        assert_relative_eq!(transformed_normal(model, n), expected, epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn normal_matrix_works_with_tiny_scales() {
+    // O determinante é da ordem de 1e-8, mas as normais continuam perpendiculares
+    let model =
+        Matrix4x4::from_rotate_y(Rad(0.4)) * Matrix4x4::from_scale(Vec3::new(0.004, 0.001, 0.002));
+
+    let normal = transformed_normal(model, Vec3::new(1.0, 1.0, 0.0));
+    let tangent = (model * Vec4::new(1.0, -1.0, 0.0, 0.0)).truncate();
+
+    assert_relative_eq!(normal.dot(tangent.normalize()), 0.0, epsilon = 1e-5);
+}
+
+#[test]
+fn normal_matrix_keeps_mirrored_normals_outside() {
+    // Espelhar em X leva a face +X para -X, e a sua normal deve continuar apontando para fora
+    let model = Matrix4x4::from_scale(Vec3::new(-2.0, 1.0, 1.0));
+
+    assert_relative_eq!(
+        transformed_normal(model, Vec3::new(1.0, 0.0, 0.0)),
+        Vec3::new(-1.0, 0.0, 0.0)
+    );
+}
@@ -0,0 +1,204 @@
+use approx::assert_relative_eq;
+use cgdraw_core::{
+    frustum, frustum_lh, orthographic, orthographic_lh, perspective, perspective_infinite,
+    perspective_infinite_lh, perspective_lh, perspective_reversed_z, perspective_reversed_z_lh,
+};
+use cgdraw_math::{
+    angle::Rad,
+    matrix::Matrix4x4,
+    point::Point3,
+    vector::{Vec3, Vec4},
+};
+
+const FOVY: Rad<f64> = Rad(std::f64::consts::FRAC_PI_2);
+
+/// Projeta o ponto do espaço de visão e faz a divisão de perspectiva.
+fn ndc(projection: &Matrix4x4<f64>, x: f64, y: f64, z: f64) -> Vec3<f64> {
+    Point3::from_homogeneous(*projection * Vec4::new(x, y, z, 1.0)).to_vec()
+}
+
+#[test]
+fn perspective_maps_near_and_far_planes() {
+    let p = perspective(2.0, FOVY, 1.0, 10.0);
+
+    assert_relative_eq!(
+        ndc(&p, 0.0, 0.0, -1.0),
+        Vec3::new(0.0, 0.0, 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        ndc(&p, 0.0, 0.0, -10.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        epsilon = 1e-12
+    );
+
+    // Com 90° de campo de visão, a borda superior está em y = -z e a direita em x = -z * 2
+    assert_relative_eq!(
+        ndc(&p, 4.0, 2.0, -2.0).truncate(),
+        Vec3::new(1.0, 1.0, 0.0).truncate()
+    );
+}
+
+#[test]
+fn off_axis_frustum_maps_its_corners() {
+    let p = frustum(-1.0, 3.0, -2.0, 1.0, 1.0, 10.0);
+
+    assert_relative_eq!(
+        ndc(&p, -1.0, -2.0, -1.0),
+        Vec3::new(-1.0, -1.0, 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        ndc(&p, 3.0, 1.0, -1.0),
+        Vec3::new(1.0, 1.0, 0.0),
+        epsilon = 1e-12
+    );
+
+    // No plano de longe, os limites crescem proporcionalmente à distância
+    assert_relative_eq!(
+        ndc(&p, 30.0, -20.0, -10.0),
+        Vec3::new(1.0, -1.0, 1.0),
+        epsilon = 1e-12
+    );
+
+    // O centro da tela não está no eixo de visão
+    assert_relative_eq!(
+        ndc(&p, 1.0, -0.5, -1.0),
+        Vec3::new(0.0, 0.0, 0.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn symmetric_frustum_equals_perspective() {
+    assert_relative_eq!(
+        frustum(-2.0, 2.0, -1.0, 1.0, 1.0, 10.0),
+        perspective(2.0, FOVY, 1.0, 10.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn infinite_perspective_has_no_far_plane() {
+    let p = perspective_infinite(1.0, FOVY, 0.5);
+
+    assert_relative_eq!(
+        ndc(&p, 0.0, 0.0, -0.5),
+        Vec3::new(0.0, 0.0, 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(ndc(&p, 1.0, 1.0, -1.0).z, 0.5, epsilon = 1e-12);
+    assert_relative_eq!(ndc(&p, 1.0, 1.0, -1.0).x, 1.0, epsilon = 1e-12);
+
+    let mut last = 0.5;
+    for z in [-1e2, -1e4, -1e6] {
+        let depth = ndc(&p, 0.0, 0.0, z).z;
+
+        assert!(last < depth && depth < 1.0);
+        last = depth;
+    }
+    assert_relative_eq!(last, 1.0, epsilon = 1e-6);
+
+    // Projetar um vetor (w = 0) na direção da câmera dá a profundidade 1
+    let direction = p * Vec4::new(0.0, 0.0, -1.0, 0.0);
+    assert_relative_eq!(direction.z / direction.w, 1.0);
+}
+
+#[test]
+fn reversed_z_perspective_swaps_near_and_far() {
+    let p = perspective_reversed_z(2.0, FOVY, 1.0, 10.0);
+    let standard = perspective(2.0, FOVY, 1.0, 10.0);
+
+    assert_relative_eq!(
+        ndc(&p, 0.0, 0.0, -1.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        ndc(&p, 0.0, 0.0, -10.0),
+        Vec3::new(0.0, 0.0, 0.0),
+        epsilon = 1e-12
+    );
+
+    // A profundidade é 1 - a profundidade padrão, com os mesmos x e y
+    for (x, y, z) in [(1.0, 0.5, -2.0), (-3.0, 2.0, -5.0), (0.0, -8.0, -9.0)] {
+        let reversed = ndc(&p, x, y, z);
+        let expected = ndc(&standard, x, y, z);
+
+        assert_relative_eq!(reversed.x, expected.x, epsilon = 1e-12);
+        assert_relative_eq!(reversed.y, expected.y, epsilon = 1e-12);
+        assert_relative_eq!(reversed.z, 1.0 - expected.z, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn orthographic_maps_the_box_corners() {
+    let p = orthographic(-2.0, 2.0, -1.0, 3.0, 1.0, 5.0);
+
+    assert_relative_eq!(
+        ndc(&p, -2.0, -1.0, -1.0),
+        Vec3::new(-1.0, -1.0, 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        ndc(&p, 2.0, 3.0, -5.0),
+        Vec3::new(1.0, 1.0, 1.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn left_handed_projections_look_down_positive_z() {
+    let pairs = [
+        (
+            perspective(2.0, FOVY, 1.0, 10.0),
+            perspective_lh(2.0, FOVY, 1.0, 10.0),
+        ),
+        (
+            orthographic(-2.0, 2.0, -1.0, 3.0, 1.0, 5.0),
+            orthographic_lh(-2.0, 2.0, -1.0, 3.0, 1.0, 5.0),
+        ),
+        (
+            frustum(-1.0, 3.0, -2.0, 1.0, 1.0, 10.0),
+            frustum_lh(-1.0, 3.0, -2.0, 1.0, 1.0, 10.0),
+        ),
+        (
+            perspective_infinite(2.0, FOVY, 1.0),
+            perspective_infinite_lh(2.0, FOVY, 1.0),
+        ),
+        (
+            perspective_reversed_z(2.0, FOVY, 1.0, 10.0),
+            perspective_reversed_z_lh(2.0, FOVY, 1.0, 10.0),
+        ),
+    ];
+
+    for (rh, lh) in pairs {
+        for (x, y, z) in [(0.5, -0.25, -1.0), (1.0, 2.0, -3.0), (-4.0, 1.0, -7.5)] {
+            // A projeção canhota espera o mesmo ponto espelhado no eixo Z
+            assert_relative_eq!(ndc(&lh, x, y, -z), ndc(&rh, x, y, z), epsilon = 1e-12);
+        }
+    }
+
+    // O plano de perto da projeção canhota está em z = +1
+    let p = perspective_lh(2.0, FOVY, 1.0, 10.0);
+    assert_relative_eq!(
+        ndc(&p, 0.0, 0.0, 1.0),
+        Vec3::new(0.0, 0.0, 0.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn orthographic_view_proj_is_invertible() {
+    // Uma tela de 800 x 600 pixels com uma unidade por pixel: o determinante é cerca de 4e-9
+    let projection = orthographic(-400.0_f32, 400.0, -300.0, 300.0, -1000.0, 1000.0);
+    let view = Matrix4x4::from_translate(Vec3::new(-50.0, 20.0, 0.0));
+    let view_proj = projection * view;
+
+    assert!(view_proj.is_invertible());
+
+    // O canto superior direito da tela volta para o mundo
+    let corner = view_proj.invert().unwrap() * Vec4::new(1.0, 1.0, 0.5, 1.0);
+    assert_relative_eq!(corner.x, 450.0, epsilon = 1e-2);
+    assert_relative_eq!(corner.y, 280.0, epsilon = 1e-2);
+}
@@ -0,0 +1,65 @@
+/// A ordem em que as rotações dos ângulos de Euler são aplicadas. As rotações são feitas em
+/// torno dos eixos fixos do mundo (extrínsecas): em `XYZ`, o objeto é rotacionado primeiro em
+/// X, depois em Y e por último em Z, o que equivale à matriz `Rz * Ry * Rx`.
+///
+/// Para rotações em torno dos eixos locais do objeto (intrínsecas), use
+/// [`EulerOrder::intrinsic`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+/// Um dos eixos de rotação.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl EulerOrder {
+    /// Os eixos na ordem em que as rotações são aplicadas.
+    pub fn axes(&self) -> [Axis; 3] {
+        match self {
+            EulerOrder::XYZ => [Axis::X, Axis::Y, Axis::Z],
+            EulerOrder::XZY => [Axis::X, Axis::Z, Axis::Y],
+            EulerOrder::YXZ => [Axis::Y, Axis::X, Axis::Z],
+            EulerOrder::YZX => [Axis::Y, Axis::Z, Axis::X],
+            EulerOrder::ZXY => [Axis::Z, Axis::X, Axis::Y],
+            EulerOrder::ZYX => [Axis::Z, Axis::Y, Axis::X],
+        }
+    }
+
+    /// A ordem extrínseca equivalente às rotações intrínsecas na ordem `self`, ou seja, em
+    /// torno dos eixos locais que giram junto com o objeto. Girar em X, depois no novo Y e
+    /// depois no novo Z (intrínseca `XYZ`) é o mesmo que a extrínseca `ZYX`, `Rx * Ry * Rz`.
+    /// ```text
+    /// Matrix4x4::from_euler(EulerOrder::XYZ.intrinsic(), x, y, z) == Rx * Ry * Rz
+    /// ```
+    pub fn intrinsic(self) -> EulerOrder {
+        match self {
+            EulerOrder::XYZ => EulerOrder::ZYX,
+            EulerOrder::XZY => EulerOrder::YZX,
+            EulerOrder::YXZ => EulerOrder::ZXY,
+            EulerOrder::YZX => EulerOrder::XZY,
+            EulerOrder::ZXY => EulerOrder::YXZ,
+            EulerOrder::ZYX => EulerOrder::XYZ,
+        }
+    }
+}
+
+impl Axis {
+    /// O índice do eixo em um vetor: 0 para X, 1 para Y e 2 para Z.
+    pub fn index(&self) -> usize {
+        match self {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{matrix::Matrix4x4, num::BaseFloat, point::Point3, vector::Vec3};
+
+/// Uma caixa alinhada aos eixos (Axis-Aligned Bounding Box), definida pelos seus cantos
+/// mínimo e máximo.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Aabb3<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+impl<T: BaseFloat> Aabb3<T> {
+    /// Cria a caixa entre dois cantos opostos quaisquer.
+    pub fn new(a: Point3<T>, b: Point3<T>) -> Self {
+        Self {
+            min: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    /// A menor caixa que contém todos os pontos, ou `None` caso não haja nenhum ponto.
+    pub fn from_points<I: IntoIterator<Item = Point3<T>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Self::new(first, first), |aabb, point| aabb.grow(point)))
+    }
+
+    /// A menor caixa que contém esta caixa e o ponto.
+    pub fn grow(&self, point: Point3<T>) -> Self {
+        Self {
+            min: Point3::new(
+                self.min.x.min(point.x),
+                self.min.y.min(point.y),
+                self.min.z.min(point.z),
+            ),
+            max: Point3::new(
+                self.max.x.max(point.x),
+                self.max.y.max(point.y),
+                self.max.z.max(point.z),
+            ),
+        }
+    }
+
+    /// A menor caixa que contém as duas caixas.
+    pub fn union(&self, other: &Aabb3<T>) -> Self {
+        self.grow(other.min).grow(other.max)
+    }
+
+    /// O centro da caixa.
+    pub fn center(&self) -> Point3<T> {
+        let half: T = num_traits::cast(0.5).unwrap();
+
+        self.min + (self.max - self.min) * half
+    }
+
+    /// As dimensões da caixa em cada eixo.
+    pub fn size(&self) -> Vec3<T> {
+        self.max - self.min
+    }
+
+    /// Os oito cantos da caixa.
+    pub fn corners(&self) -> [Point3<T>; 8] {
+        let (min, max) = (self.min, self.max);
+
+        [
+            Point3::new(min.x, min.y, min.z),
+            Point3::new(max.x, min.y, min.z),
+            Point3::new(min.x, max.y, min.z),
+            Point3::new(max.x, max.y, min.z),
+            Point3::new(min.x, min.y, max.z),
+            Point3::new(max.x, min.y, max.z),
+            Point3::new(min.x, max.y, max.z),
+            Point3::new(max.x, max.y, max.z),
+        ]
+    }
+
+    /// Indica se o ponto está dentro ou na borda da caixa.
+    pub fn contains_point(&self, point: Point3<T>) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+            && self.min.z <= point.z
+            && point.z <= self.max.z
+    }
+
+    /// Indica se as duas caixas se tocam ou se sobrepõem.
+    pub fn intersects(&self, other: &Aabb3<T>) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+            && self.min.z <= other.max.z
+            && other.min.z <= self.max.z
+    }
+
+    /// A caixa alinhada aos eixos que contém esta caixa depois de transformada pela matriz
+    /// afim `m`. Caixas rotacionadas ficam maiores que o objeto original.
+    pub fn transform(&self, m: &Matrix4x4<T>) -> Self {
+        let [first, rest @ ..] = self.corners().map(|corner| {
+            let v = *m * corner.to_homogeneous();
+            Point3::new(v.x, v.y, v.z)
+        });
+
+        rest.iter()
+            .fold(Self::new(first, first), |aabb, &p| aabb.grow(p))
+    }
+}
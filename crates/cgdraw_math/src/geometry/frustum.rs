@@ -0,0 +1,109 @@
+use crate::{
+    matrix::Matrix4x4,
+    num::BaseFloat,
+    point::Point3,
+    vector::{Vec3, Vec4},
+};
+
+use super::{Aabb3, Plane, Sphere};
+
+/// O volume de visão de uma câmera, limitado por seis planos cujas normais apontam para
+/// dentro do volume. Um ponto está dentro do frustum quando está do lado positivo de todos
+/// os planos.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Frustum<T> {
+    /// Os planos na ordem: esquerda, direita, baixo, cima, perto e longe.
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T: BaseFloat> Frustum<T> {
+    /// Cria o frustum a partir dos seis planos, com as normais apontando para dentro.
+    pub fn new(planes: [Plane<T>; 6]) -> Self {
+        Self { planes }
+    }
+
+    /// Extrai os planos do frustum da matriz de visão e projeção `view_proj` (método de
+    /// Gribb e Hartmann), usando o intervalo de profundidade do wgpu, de 0 a 1. Os planos
+    /// ficam no espaço em que os vértices estão antes de `view_proj`: com a matriz da câmera,
+    /// no espaço do mundo.
+    ///
+    /// Um plano que não existe, como o plano de longe de uma projeção infinita, aceita todos
+    /// os pontos.
+    pub fn from_view_proj(view_proj: &Matrix4x4<T>) -> Self {
+        let row = |r: usize| {
+            Vec4::new(
+                view_proj.c0[r],
+                view_proj.c1[r],
+                view_proj.c2[r],
+                view_proj.c3[r],
+            )
+        };
+        let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+
+        let plane = |v: Vec4<T>| {
+            if v.truncate().magnitude() <= T::default_epsilon() {
+                Plane {
+                    normal: Vec3::from_value(T::zero()),
+                    distance: T::one(),
+                }
+            } else {
+                Plane::from_vec4(v)
+            }
+        };
+
+        Self::new([
+            plane(w + x),
+            plane(w - x),
+            plane(w + y),
+            plane(w - y),
+            plane(z),
+            plane(w - z),
+        ])
+    }
+
+    /// Indica se o ponto está dentro do frustum.
+    pub fn contains_point(&self, point: Point3<T>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= T::zero())
+    }
+
+    /// Indica se a esfera está, ao menos em parte, dentro do frustum.
+    ///
+    /// O teste é conservador: perto dos cantos do frustum, algumas esferas que estão fora
+    /// podem ser consideradas dentro, mas nenhuma esfera visível é descartada.
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(sphere.center) >= -sphere.radius)
+    }
+
+    /// Indica se a caixa está, ao menos em parte, dentro do frustum.
+    ///
+    /// Assim como `intersects_sphere`, o teste é conservador e nunca descarta uma caixa
+    /// visível.
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        self.planes.iter().all(|plane| {
+            // O canto da caixa mais distante no sentido da normal
+            let corner = Point3::new(
+                if plane.normal.x >= T::zero() {
+                    aabb.max.x
+                } else {
+                    aabb.min.x
+                },
+                if plane.normal.y >= T::zero() {
+                    aabb.max.y
+                } else {
+                    aabb.min.y
+                },
+                if plane.normal.z >= T::zero() {
+                    aabb.max.z
+                } else {
+                    aabb.min.z
+                },
+            );
+
+            plane.signed_distance(corner) >= T::zero()
+        })
+    }
+}
@@ -0,0 +1,16 @@
+//! Primitivas geométricas 3D e testes de interseção entre elas, usados para seleção de
+//! objetos com o mouse (picking), descarte de objetos fora da câmera (culling) e colisões.
+
+mod aabb;
+mod frustum;
+mod plane;
+mod ray;
+mod sphere;
+mod triangle;
+
+pub use self::aabb::Aabb3;
+pub use self::frustum::Frustum;
+pub use self::plane::Plane;
+pub use self::ray::{Ray3, RayHit};
+pub use self::sphere::Sphere;
+pub use self::triangle::Triangle3;
@@ -0,0 +1,61 @@
+use crate::{
+    num::BaseFloat,
+    point::Point3,
+    vector::{Vec3, Vec4},
+};
+
+/// Um plano infinito, formado pelos pontos `p` que satisfazem `normal · p + distance = 0`.
+///
+/// A normal é sempre unitária, então `distance` é a distância com sinal da origem até o
+/// plano, medida no sentido oposto ao da normal.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Plane<T> {
+    pub normal: Vec3<T>,
+    pub distance: T,
+}
+
+impl<T: BaseFloat> Plane<T> {
+    /// Cria o plano `normal · p + distance = 0`. A equação é normalizada, então `normal` não
+    /// precisa ser unitária.
+    pub fn new(normal: Vec3<T>, distance: T) -> Self {
+        let magnitude = normal.magnitude();
+
+        Self {
+            normal: normal / magnitude,
+            distance: distance / magnitude,
+        }
+    }
+
+    /// Cria o plano a partir dos coeficientes `(a, b, c, d)` da equação
+    /// `ax + by + cz + d = 0`.
+    pub fn from_vec4(v: Vec4<T>) -> Self {
+        Self::new(v.truncate(), v.w)
+    }
+
+    /// Cria o plano que passa pelo ponto `point` com a normal `normal`.
+    pub fn from_point_normal(point: Point3<T>, normal: Vec3<T>) -> Self {
+        let normal = normal.normalize();
+
+        Self {
+            normal,
+            distance: -normal.dot(point.to_vec()),
+        }
+    }
+
+    /// Cria o plano que passa pelos três pontos. A normal segue a regra da mão direita: ela
+    /// aponta para o observador quando os pontos estão no sentido anti-horário.
+    pub fn from_points(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> Self {
+        Self::from_point_normal(a, (b - a).cross(c - a))
+    }
+
+    /// A distância com sinal do ponto até o plano: positiva do lado para onde a normal aponta
+    /// e negativa do outro lado.
+    pub fn signed_distance(&self, point: Point3<T>) -> T {
+        self.normal.dot(point.to_vec()) + self.distance
+    }
+
+    /// O ponto do plano mais próximo de `point`.
+    pub fn closest_point(&self, point: Point3<T>) -> Point3<T> {
+        point - self.normal * self.signed_distance(point)
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{matrix::Matrix4x4, num::BaseFloat, point::Point3, vector::Vec3};
+
+use super::{Aabb3, Plane, Sphere, Triangle3};
+
+/// Uma semirreta que parte de `origin` na direção `direction`, usada para seleção de objetos
+/// (picking) e testes de colisão.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ray3<T> {
+    pub origin: Point3<T>,
+
+    /// A direção unitária do raio.
+    pub direction: Vec3<T>,
+}
+
+/// O ponto onde um raio atinge uma superfície.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RayHit<T> {
+    /// A distância da origem do raio até o ponto atingido.
+    pub distance: T,
+    pub point: Point3<T>,
+
+    /// A normal unitária da superfície no ponto atingido.
+    pub normal: Vec3<T>,
+}
+
+impl<T: BaseFloat> Ray3<T> {
+    /// Cria o raio. A direção é normalizada, então as distâncias retornadas pelos testes de
+    /// interseção estão nas mesmas unidades da cena.
+    pub fn new(origin: Point3<T>, direction: Vec3<T>) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// O ponto do raio a uma distância `distance` da origem.
+    pub fn at(&self, distance: T) -> Point3<T> {
+        self.origin + self.direction * distance
+    }
+
+    /// O raio transformado pela matriz afim `m`. Útil para levar um raio do mundo para o
+    /// espaço local de um objeto, usando a inversa da matriz do objeto. As distâncias dos
+    /// testes com o raio transformado são medidas no novo espaço.
+    pub fn transform(&self, m: &Matrix4x4<T>) -> Self {
+        let origin = Point3::from_homogeneous(*m * self.origin.to_homogeneous());
+        let direction = (*m * self.direction.extend(T::zero())).truncate();
+
+        Self::new(origin, direction)
+    }
+
+    fn hit(&self, distance: T, normal: Vec3<T>) -> RayHit<T> {
+        RayHit {
+            distance,
+            point: self.at(distance),
+            normal,
+        }
+    }
+
+    /// Interseção com um plano. A normal retornada é a do plano.
+    ///
+    /// Retorna `None` quando o raio é paralelo ao plano ou se afasta dele.
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<RayHit<T>> {
+        let denominator = plane.normal.dot(self.direction);
+
+        if denominator.abs() <= T::default_epsilon() {
+            return None;
+        }
+
+        let distance = -plane.signed_distance(self.origin) / denominator;
+
+        (distance >= T::zero()).then(|| self.hit(distance, plane.normal))
+    }
+
+    /// Interseção com um triângulo pelo algoritmo de Möller–Trumbore. Os dois lados do
+    /// triângulo são atingidos, e a normal retornada aponta para o lado de onde o raio veio.
+    pub fn intersect_triangle(&self, triangle: &Triangle3<T>) -> Option<RayHit<T>> {
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+
+        let p = self.direction.cross(edge2);
+        let determinant = edge1.dot(p);
+
+        // O raio é paralelo ao plano do triângulo. O determinante cresce com a área do
+        // triângulo e com o tamanho da direção, então a tolerância também
+        let scale = edge1.magnitude() * edge2.magnitude() * self.direction.magnitude();
+        if determinant.abs() <= scale * T::default_epsilon() {
+            return None;
+        }
+
+        let inverse = T::one() / determinant;
+        let s = self.origin - triangle.a;
+
+        // Coordenadas baricêntricas (u, v) do ponto atingido
+        let u = s.dot(p) * inverse;
+        if u < T::zero() || u > T::one() {
+            return None;
+        }
+
+        let q = s.cross(edge1);
+        let v = self.direction.dot(q) * inverse;
+        if v < T::zero() || u + v > T::one() {
+            return None;
+        }
+
+        let distance = edge2.dot(q) * inverse;
+        if distance < T::zero() {
+            return None;
+        }
+
+        let normal = edge1.cross(edge2).normalize();
+        let normal = if normal.dot(self.direction) > T::zero() {
+            -normal
+        } else {
+            normal
+        };
+
+        Some(self.hit(distance, normal))
+    }
+
+    /// Interseção com uma caixa alinhada aos eixos pelo método das placas (slabs). A normal
+    /// retornada é a da face atingida, apontando para fora da caixa.
+    ///
+    /// Quando a origem está dentro da caixa, retorna o ponto por onde o raio sai.
+    pub fn intersect_aabb(&self, aabb: &Aabb3<T>) -> Option<RayHit<T>> {
+        let origin = [self.origin.x, self.origin.y, self.origin.z];
+        let direction = [self.direction.x, self.direction.y, self.direction.z];
+        let min = [aabb.min.x, aabb.min.y, aabb.min.z];
+        let max = [aabb.max.x, aabb.max.y, aabb.max.z];
+
+        let mut near = (T::neg_infinity(), 0, T::zero());
+        let mut far = (T::infinity(), 0, T::zero());
+
+        for axis in 0..3 {
+            if direction[axis] == T::zero() {
+                // Paralelo às placas deste eixo: precisa estar entre elas
+                if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let inverse = T::one() / direction[axis];
+            let mut t0 = (min[axis] - origin[axis]) * inverse;
+            let mut t1 = (max[axis] - origin[axis]) * inverse;
+
+            // O sinal da normal da face por onde o raio entra neste eixo
+            let mut sign = -T::one();
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+                sign = T::one();
+            }
+
+            if t0 > near.0 {
+                near = (t0, axis, sign);
+            }
+            if t1 < far.0 {
+                far = (t1, axis, -sign);
+            }
+
+            if near.0 > far.0 {
+                return None;
+            }
+        }
+
+        let (distance, axis, sign) = if near.0 >= T::zero() {
+            near
+        } else if far.0 >= T::zero() {
+            far
+        } else {
+            return None;
+        };
+
+        let mut normal = [T::zero(); 3];
+        normal[axis] = sign;
+
+        Some(self.hit(distance, Vec3::from(normal)))
+    }
+
+    /// Interseção com uma esfera. A normal retornada aponta para fora da esfera.
+    ///
+    /// Quando a origem está dentro da esfera, retorna o ponto por onde o raio sai.
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<RayHit<T>> {
+        // Resolve |origin + t * direction - center|² = radius², com a direção unitária
+        let offset = self.origin - sphere.center;
+        let b = offset.dot(self.direction);
+        let c = offset.magnitude2() - sphere.radius * sphere.radius;
+
+        let discriminant = b * b - c;
+        if discriminant < T::zero() {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let distance = if -b - root >= T::zero() {
+            -b - root
+        } else if -b + root >= T::zero() {
+            -b + root
+        } else {
+            return None;
+        };
+
+        let point = self.at(distance);
+
+        Some(RayHit {
+            distance,
+            point,
+            normal: (point - sphere.center) / sphere.radius,
+        })
+    }
+}
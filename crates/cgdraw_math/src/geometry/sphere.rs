@@ -0,0 +1,27 @@
+use crate::{num::BaseFloat, point::Point3};
+
+/// Uma esfera sólida, definida pelo centro e pelo raio.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Sphere<T> {
+    pub center: Point3<T>,
+    pub radius: T,
+}
+
+impl<T: BaseFloat> Sphere<T> {
+    /// Cria a esfera a partir do centro e do raio.
+    pub fn new(center: Point3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+
+    /// Indica se o ponto está dentro ou na superfície da esfera.
+    pub fn contains_point(&self, point: Point3<T>) -> bool {
+        (point - self.center).magnitude2() <= self.radius * self.radius
+    }
+
+    /// Indica se as duas esferas se tocam ou se sobrepõem.
+    pub fn intersects(&self, other: &Sphere<T>) -> bool {
+        let radius = self.radius + other.radius;
+
+        (other.center - self.center).magnitude2() <= radius * radius
+    }
+}
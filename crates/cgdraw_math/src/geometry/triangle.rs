@@ -0,0 +1,29 @@
+use crate::{num::BaseFloat, point::Point3, vector::Vec3};
+
+/// Um triângulo no espaço, definido pelos seus três vértices.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Triangle3<T> {
+    pub a: Point3<T>,
+    pub b: Point3<T>,
+    pub c: Point3<T>,
+}
+
+impl<T: BaseFloat> Triangle3<T> {
+    /// Cria o triângulo a partir dos seus vértices.
+    pub fn new(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> Self {
+        Self { a, b, c }
+    }
+
+    /// A normal unitária do triângulo. Ela aponta para o observador quando os vértices estão
+    /// no sentido anti-horário.
+    pub fn normal(&self) -> Vec3<T> {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    /// A área do triângulo.
+    pub fn area(&self) -> T {
+        let half: T = num_traits::cast(0.5).unwrap();
+
+        (self.b - self.a).cross(self.c - self.a).magnitude() * half
+    }
+}
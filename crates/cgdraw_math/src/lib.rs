@@ -1,6 +1,11 @@
 pub mod angle;
+pub mod euler;
+pub mod geometry;
 pub mod macros;
 pub mod matrix;
 pub mod num;
 pub mod point;
+pub mod quaternion;
+pub mod transform;
 pub mod vector;
+pub mod viewport;
@@ -1,14 +1,18 @@
 use crate::{
     angle::Rad,
+    euler::{Axis, EulerOrder},
     num::BaseFloat,
-    point::Point3,
-    vector::{Vec3, Vec4},
+    point::{Point2, Point3},
+    vector::{Vec2, Vec3, Vec4},
 };
 
+use std::fmt;
 use std::ops::*;
 
 use std::mem;
 
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
 /// Matriz 4x4, onde cada coluna é um vetor de 4 elementos.
 #[derive(Copy, Clone, PartialEq)]
 pub struct Matrix4x4<T> {
@@ -55,11 +59,46 @@ impl<T: BaseFloat> Matrix4x4<T> {
         Vec4::new(x, y, z, w)
     }
 
-    /// Cria uma matriz de transformação que mira a partir de um ponto de origem `eye`, para um
-    /// vetor de direção `dir`, usando o vetor `up` como orientação de referência.
+    /// Cria uma matriz de visão destra (right-handed) que mira a partir de um ponto de origem
+    /// `eye`, usando o vetor `up` como orientação de referência. O vetor `direction` é o eixo Z
+    /// da câmera, que aponta para trás: para mirar em um ponto `target`, use `eye - target`.
+    ///
+    /// Apesar do nome, não recebe um ponto alvo. Para isso, use `look_at_target_rh`; para
+    /// informar a direção para onde a câmera aponta, use `look_to_rh`.
     pub fn look_at_rh(eye: Point3<T>, direction: Vec3<T>, up: Vec3<T>) -> Matrix4x4<T> {
+        Self::look_along(eye, direction, up)
+    }
+
+    /// Cria uma matriz de visão destra (right-handed) que olha a partir de `eye` na direção
+    /// `direction`, para onde a câmera aponta. A câmera enxerga o eixo -Z do espaço de visão,
+    /// como esperado pelas projeções destras (`perspective`, `orthographic` e `frustum`).
+    pub fn look_to_rh(eye: Point3<T>, direction: Vec3<T>, up: Vec3<T>) -> Matrix4x4<T> {
+        Self::look_along(eye, -direction, up)
+    }
+
+    /// Cria uma matriz de visão canhota (left-handed) que olha a partir de `eye` na direção
+    /// `direction`. A câmera enxerga o eixo +Z do espaço de visão, como esperado pelas
+    /// projeções `_lh`.
+    pub fn look_to_lh(eye: Point3<T>, direction: Vec3<T>, up: Vec3<T>) -> Matrix4x4<T> {
+        Self::look_along(eye, direction, up)
+    }
+
+    /// Cria uma matriz de visão destra (right-handed) que mira a partir de `eye` no ponto
+    /// `target`. Equivale a `look_to_rh(eye, target - eye, up)`.
+    pub fn look_at_target_rh(eye: Point3<T>, target: Point3<T>, up: Vec3<T>) -> Matrix4x4<T> {
+        Self::look_to_rh(eye, target - eye, up)
+    }
+
+    /// Cria uma matriz de visão canhota (left-handed) que mira a partir de `eye` no ponto
+    /// `target`. Equivale a `look_to_lh(eye, target - eye, up)`.
+    pub fn look_at_target_lh(eye: Point3<T>, target: Point3<T>, up: Vec3<T>) -> Matrix4x4<T> {
+        Self::look_to_lh(eye, target - eye, up)
+    }
+
+    /// A matriz de visão cujo eixo Z da câmera é `z_axis`.
+    fn look_along(eye: Point3<T>, z_axis: Vec3<T>, up: Vec3<T>) -> Matrix4x4<T> {
         // O vetor direção representa o eixo Z da câmera. Para sabermos qual eixo
-        let dir = direction.normalize();
+        let dir = z_axis.normalize();
 
         // O vetor direito representa o eixo horizontal da câmera. Para sabermos
         // qual eixo é o horizontal, precisamos de um vetor indicando qual é o eixo
@@ -126,7 +165,7 @@ impl<T: BaseFloat> Matrix4x4<T> {
     }
 
     /// Cria uma matriz 4x4 de rotação do eixo X a partir de um ângulo em radianos.
-    /// ```rust
+    /// ```text
     /// // Matriz Transposta
     /// [
     ///     [1, 0,   0,    0],
@@ -146,7 +185,11 @@ impl<T: BaseFloat> Matrix4x4<T> {
     }
 
     /// Cria uma matriz 4x4 de rotação do eixo Y a partir de um ângulo em radianos.
-    /// ```rust
+    ///
+    /// Diferente de `from_rotate_x` e `from_rotate_z`, a rotação é no sentido horário quando o
+    /// eixo aponta para o observador: o eixo X vai para o eixo Z. As rotações de Euler e
+    /// `from_axis_angle` usam o sentido anti-horário em todos os eixos.
+    /// ```text
     /// // Matriz Transposta
     /// [
     ///     [cos,  0, sin,  0],
@@ -166,7 +209,7 @@ impl<T: BaseFloat> Matrix4x4<T> {
     }
 
     /// Cria uma matriz 4x4 de rotação do eixo Z a partir de um ângulo em radianos.
-    /// ```rust
+    /// ```text
     /// // Matriz Transposta
     /// [
     ///     [cos,  sin, 0, 0],
@@ -185,54 +228,214 @@ impl<T: BaseFloat> Matrix4x4<T> {
         )
     }
 
-    /// Cria uma matriz 4x4 de rotação de um eixo arbitrário a partir de um ângulo em radianos
-    /// informado pelos eixos x,y e z.
-    /// ```rust
-    /// // Matriz Transposta
-    /// let c0r0 = cosz * cosy;
-    /// let c0r1 = cosy * sinz;
-    /// let c0r2 = siny;
-    ///
-    /// let c1r0 = -sinx * siny * cosz + cosx * sinz;
-    /// let c1r1 = -sinx * siny * sinz - cosx * cosz;
-    /// let c1r2 = sinx * cosy;
-    ///
-    /// let c2r0 = cosx * siny * cosz + sinx * sinz;
-    /// let c2r1 = cosx * siny * sinz - sinx * cosz;
-    /// let c2r2 = -cosx * cosy;
-    ///
+    /// Cria uma matriz 4x4 de rotação a partir dos ângulos de Euler em radianos, aplicados na
+    /// ordem `XYZ`: primeiro em X, depois em Y e por último em Z, todos no sentido
+    /// anti-horário.
+    /// ```text
+    /// Matrix4x4::from_rotate_z(z) * Matrix4x4::from_rotate_y(-y) * Matrix4x4::from_rotate_x(x)
+    /// ```
+    /// Para escolher outra ordem, use [`Matrix4x4::from_euler`].
+    pub fn from_rotate(angle_x: Rad<T>, angle_y: Rad<T>, angle_z: Rad<T>) -> Matrix4x4<T> {
+        Self::from_euler(EulerOrder::XYZ, angle_x, angle_y, angle_z)
+    }
+
+    /// Cria uma matriz 4x4 de rotação anti-horária de um dos eixos a partir de um ângulo em
+    /// radianos, como `Quaternion::from_axis_angle`.
+    fn from_rotate_axis(axis: Axis, angle: Rad<T>) -> Matrix4x4<T> {
+        match axis {
+            Axis::X => Self::from_rotate_x(angle),
+            // `from_rotate_y` gira no sentido horário
+            Axis::Y => Self::from_rotate_y(Rad(-angle.0)),
+            Axis::Z => Self::from_rotate_z(angle),
+        }
+    }
+
+    /// Cria uma matriz 4x4 de rotação a partir dos ângulos de Euler `x`, `y` e `z` em
+    /// radianos, aplicados na ordem `order` no sentido anti-horário, como
+    /// `Quaternion::from_euler`. Na ordem `YXZ`, por exemplo, o resultado é `Rz * Rx * Ry`.
+    pub fn from_euler(order: EulerOrder, x: Rad<T>, y: Rad<T>, z: Rad<T>) -> Matrix4x4<T> {
+        let angles = [x, y, z];
+
+        order.axes().iter().fold(Self::identity(), |matrix, &axis| {
+            // Cada rotação é aplicada depois das anteriores
+            Self::from_rotate_axis(axis, angles[axis.index()]) * matrix
+        })
+    }
+
+    /// Cria uma matriz 4x4 de rotação de `angle` radianos em torno de um eixo arbitrário, no
+    /// sentido anti-horário quando o eixo aponta para o observador. O eixo não precisa estar
+    /// normalizado.
+    /// ```text
+    /// // Fórmula de Rodrigues, com t = 1 - cos
     /// [
-    ///     [c0r0, c0r1, c0r2, 0],
-    ///     [c1r0, c1r1, c1r2, 0],
-    ///     [c2r0, c2r1, c2r2, 0],
-    ///     [0,    0,    0,    1],
+    ///     [t*x*x + cos,   t*x*y + sin*z, t*x*z - sin*y, 0],
+    ///     [t*x*y - sin*z, t*y*y + cos,   t*y*z + sin*x, 0],
+    ///     [t*x*z + sin*y, t*y*z - sin*x, t*z*z + cos,   0],
+    ///     [0,             0,             0,             1],
     /// ]
     /// ```
-    pub fn from_rotate(angle_x: Rad<T>, angle_y: Rad<T>, angle_z: Rad<T>) -> Matrix4x4<T> {
-        let (sin_x, cos_x) = angle_x.0.sin_cos();
-        let (sin_y, cos_y) = angle_y.0.sin_cos();
-        let (sin_z, cos_z) = angle_z.0.sin_cos();
+    pub fn from_axis_angle(axis: Vec3<T>, angle: Rad<T>) -> Matrix4x4<T> {
+        let (sin, cos) = angle.0.sin_cos();
+        let t = T::one() - cos;
+        let Vec3 { x, y, z } = axis.normalize();
 
         Self::from_cols(
-            Vec4::new(cos_y * cos_z, cos_y * sin_z, sin_y, T::zero()),
             Vec4::new(
-                -sin_x * sin_y * cos_z + cos_x + sin_z,
-                -sin_x * sin_y * sin_z - cos_x * cos_z,
-                sin_x * cos_y,
+                t * x * x + cos,
+                t * x * y + sin * z,
+                t * x * z - sin * y,
                 T::zero(),
             ),
             Vec4::new(
-                cos_x * sin_y * cos_z + sin_x * sin_z,
-                cos_x * sin_y * sin_z - sin_x * cos_z,
-                -cos_x * cos_y,
+                t * x * y - sin * z,
+                t * y * y + cos,
+                t * y * z + sin * x,
+                T::zero(),
+            ),
+            Vec4::new(
+                t * x * z + sin * y,
+                t * y * z - sin * x,
+                t * z * z + cos,
                 T::zero(),
             ),
             Vec4::new(T::zero(), T::zero(), T::zero(), T::one()),
         )
     }
 
+    /// Extrai os ângulos de Euler `(x, y, z)` em radianos de uma matriz de rotação, de forma
+    /// que `Matrix4x4::from_euler(order, x, y, z)` reconstrua a mesma rotação.
+    ///
+    /// O ângulo do eixo do meio da ordem fica entre -π/2 e π/2, e os demais entre -π e π.
+    /// Quando o ângulo do meio é ±π/2 (gimbal lock), os outros dois eixos giram em torno da
+    /// mesma direção; nesse caso o ângulo do último eixo é zero.
+    pub fn to_euler(&self, order: EulerOrder) -> (Rad<T>, Rad<T>, Rad<T>) {
+        let [first, middle, last] = order.axes().map(|axis| axis.index());
+
+        // Elemento da linha `r` e coluna `c`
+        let m = |r: usize, c: usize| self[c][r];
+
+        // Sinal da permutação dos eixos: positivo para as ordens cíclicas (XYZ, YZX, ZXY)
+        let sign = if middle == (first + 1) % 3 {
+            T::one()
+        } else {
+            -T::one()
+        };
+
+        let cos_middle = m(first, first).hypot(m(middle, first));
+        let angle_middle = (-sign * m(last, first)).atan2(cos_middle);
+
+        let (angle_first, angle_last) = if cos_middle > T::default_epsilon().sqrt() {
+            (
+                (sign * m(last, middle)).atan2(m(last, last)),
+                (sign * m(middle, first)).atan2(m(first, first)),
+            )
+        } else {
+            // Remove a rotação do eixo do meio e o que sobra é a rotação do primeiro eixo
+            let rest = Self::from_rotate_axis(order.axes()[1], Rad(-angle_middle)) * *self;
+            let (a, b) = ((first + 1) % 3, (first + 2) % 3);
+
+            (rest[a][b].atan2(rest[a][a]), T::zero())
+        };
+
+        let mut angles = [T::zero(); 3];
+        angles[first] = angle_first;
+        angles[middle] = angle_middle;
+        angles[last] = angle_last;
+
+        (Rad(angles[0]), Rad(angles[1]), Rad(angles[2]))
+    }
+
+    /// Retorna a matriz transposta, onde as linhas se tornam colunas.
+    pub fn transpose(&self) -> Matrix4x4<T> {
+        Self::from_cols(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    /// Calcula o determinante da matriz.
+    pub fn determinant(&self) -> T {
+        let (s, c) = self.sub_determinants();
+
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Indica se a matriz possui inversa, ou seja, se o seu determinante não é zero.
+    pub fn is_invertible(&self) -> bool {
+        !self.is_singular(self.determinant())
+    }
+
+    /// Indica se o determinante `det` desta matriz deve ser considerado zero.
+    fn is_singular(&self, det: T) -> bool {
+        is_singular(det, [self[0], self[1], self[2], self[3]].map(|c| c.dot(c)))
+    }
+
+    /// Calcula a matriz inversa pela matriz adjunta. Retorna `None` caso a matriz não seja
+    /// inversível (determinante igual a zero).
+    pub fn invert(&self) -> Option<Matrix4x4<T>> {
+        let m = self;
+        let (s, c) = self.sub_determinants();
+
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+
+        if self.is_singular(det) {
+            return None;
+        }
+
+        let inv_det = T::one() / det;
+
+        #[rustfmt::skip]
+        let inverse = Matrix4x4::new(
+            m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+            -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+            m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+            -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+
+            -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+            m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+            -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+            m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+
+            m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+            -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+            m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+            -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+
+            -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+            m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+            -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+            m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+        );
+
+        Some(inverse * inv_det)
+    }
+
+    /// Os determinantes 2x2 usados pelo determinante e pela inversa: `s` vem das colunas 0 e 1
+    /// e `c` das colunas 2 e 3.
+    #[inline]
+    fn sub_determinants(&self) -> ([T; 6], [T; 6]) {
+        let m = self;
+
+        let s = [
+            m[0][0] * m[1][1] - m[1][0] * m[0][1],
+            m[0][0] * m[1][2] - m[1][0] * m[0][2],
+            m[0][0] * m[1][3] - m[1][0] * m[0][3],
+            m[0][1] * m[1][2] - m[1][1] * m[0][2],
+            m[0][1] * m[1][3] - m[1][1] * m[0][3],
+            m[0][2] * m[1][3] - m[1][2] * m[0][3],
+        ];
+
+        let c = [
+            m[2][0] * m[3][1] - m[3][0] * m[2][1],
+            m[2][0] * m[3][2] - m[3][0] * m[2][2],
+            m[2][0] * m[3][3] - m[3][0] * m[2][3],
+            m[2][1] * m[3][2] - m[3][1] * m[2][2],
+            m[2][1] * m[3][3] - m[3][1] * m[2][3],
+            m[2][2] * m[3][3] - m[3][2] * m[2][3],
+        ];
+
+        (s, c)
+    }
+
     /// Cria uma matriz 4x4 de escala a partir de um vetor de 3 elementos (x, y, z).
-    /// ```rust
+    /// ```text
     /// // Matriz Transposta
     /// [
     ///     [x, 0, 0, 0],
@@ -269,6 +472,346 @@ impl<T: BaseFloat> Mul<Matrix4x4<T>> for Matrix4x4<T> {
     }
 }
 
+/// Matriz 2x2, onde cada coluna é um vetor de 2 elementos. Representa transformações
+/// lineares 2D (rotação, escala e cisalhamento) sem translação.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Matrix2x2<T> {
+    pub c0: Vec2<T>,
+    pub c1: Vec2<T>,
+}
+
+impl<T> Matrix2x2<T> {
+    /// Cria uma matriz 2x2 a partir de 4 elementos.
+    #[inline]
+    #[rustfmt::skip]
+    pub fn new(
+        c0r0: T, c0r1: T,
+        c1r0: T, c1r1: T,
+    ) -> Self {
+        Self::from_cols(Vec2::new(c0r0, c0r1), Vec2::new(c1r0, c1r1))
+    }
+
+    /// Cria uma matriz 2x2 a partir de 2 vetores de 2 elementos.
+    pub fn from_cols(c0: Vec2<T>, c1: Vec2<T>) -> Self {
+        Self { c0, c1 }
+    }
+}
+
+impl<T: BaseFloat> Matrix2x2<T> {
+    /// Retorna a linha `r` da matriz.
+    #[inline]
+    fn row(&self, r: usize) -> Vec2<T> {
+        Vec2::new(self[0][r], self[1][r])
+    }
+
+    /// Cria uma matriz identidade.
+    pub fn identity() -> Matrix2x2<T> {
+        Self::new(T::one(), T::zero(), T::zero(), T::one())
+    }
+
+    /// Cria uma matriz 2x2 de rotação a partir de um ângulo em radianos, no sentido
+    /// anti-horário.
+    /// ```text
+    /// // Matriz Transposta
+    /// [
+    ///     [cos,  sin],
+    ///     [-sin, cos],
+    /// ]
+    /// ```
+    pub fn from_rotate(angle: Rad<T>) -> Matrix2x2<T> {
+        let (sin, cos) = angle.0.sin_cos();
+        Self::new(cos, sin, -sin, cos)
+    }
+
+    /// Cria uma matriz 2x2 de escala a partir de um vetor de 2 elementos (x, y).
+    pub fn from_scale(xy: Vec2<T>) -> Matrix2x2<T> {
+        Self::new(xy.x, T::zero(), T::zero(), xy.y)
+    }
+
+    /// Cria uma matriz 2x2 de cisalhamento, onde `x' = x + shear_x * y` e
+    /// `y' = y + shear_y * x`.
+    /// ```text
+    /// // Matriz Transposta
+    /// [
+    ///     [1,       shear_y],
+    ///     [shear_x, 1      ],
+    /// ]
+    /// ```
+    pub fn from_shear(shear_x: T, shear_y: T) -> Matrix2x2<T> {
+        Self::new(T::one(), shear_y, shear_x, T::one())
+    }
+
+    /// Retorna a matriz transposta, onde as linhas se tornam colunas.
+    pub fn transpose(&self) -> Matrix2x2<T> {
+        Self::from_cols(self.row(0), self.row(1))
+    }
+
+    /// Calcula o determinante da matriz.
+    pub fn determinant(&self) -> T {
+        self.c0.x * self.c1.y - self.c1.x * self.c0.y
+    }
+
+    /// Indica se a matriz possui inversa, ou seja, se o seu determinante não é zero.
+    pub fn is_invertible(&self) -> bool {
+        !self.is_singular(self.determinant())
+    }
+
+    /// Indica se o determinante `det` desta matriz deve ser considerado zero.
+    fn is_singular(&self, det: T) -> bool {
+        is_singular(det, [self.c0, self.c1].map(|c| c.dot(c)))
+    }
+
+    /// Calcula a matriz inversa. Retorna `None` caso a matriz não seja inversível.
+    pub fn invert(&self) -> Option<Matrix2x2<T>> {
+        let det = self.determinant();
+
+        if self.is_singular(det) {
+            return None;
+        }
+
+        let inverse = Self::new(self.c1.y, -self.c0.y, -self.c1.x, self.c0.x);
+
+        Some(inverse * (T::one() / det))
+    }
+}
+
+/// Matriz 3x3, onde cada coluna é um vetor de 3 elementos. Representa transformações
+/// lineares 3D ou transformações afins 2D em coordenadas homogêneas `(x, y, 1)`.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Matrix3x3<T> {
+    pub c0: Vec3<T>,
+    pub c1: Vec3<T>,
+    pub c2: Vec3<T>,
+}
+
+impl<T> Matrix3x3<T> {
+    /// Cria uma matriz 3x3 a partir de 9 elementos.
+    #[inline]
+    #[rustfmt::skip]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        c0r0: T, c0r1: T, c0r2: T,
+        c1r0: T, c1r1: T, c1r2: T,
+        c2r0: T, c2r1: T, c2r2: T,
+    ) -> Self {
+        Self::from_cols(
+            Vec3::new(c0r0, c0r1, c0r2),
+            Vec3::new(c1r0, c1r1, c1r2),
+            Vec3::new(c2r0, c2r1, c2r2),
+        )
+    }
+
+    /// Cria uma matriz 3x3 a partir de 3 vetores de 3 elementos.
+    pub fn from_cols(c0: Vec3<T>, c1: Vec3<T>, c2: Vec3<T>) -> Self {
+        Self { c0, c1, c2 }
+    }
+}
+
+impl<T: BaseFloat> Matrix3x3<T> {
+    /// Retorna a linha `r` da matriz.
+    #[inline]
+    fn row(&self, r: usize) -> Vec3<T> {
+        Vec3::new(self[0][r], self[1][r], self[2][r])
+    }
+
+    /// Cria uma matriz identidade.
+    pub fn identity() -> Matrix3x3<T> {
+        Self::from_cols(
+            Vec3::new(T::one(), T::zero(), T::zero()),
+            Vec3::new(T::zero(), T::one(), T::zero()),
+            Vec3::new(T::zero(), T::zero(), T::one()),
+        )
+    }
+
+    /// Cria uma matriz afim 2D a partir de uma matriz linear 2x2 e de uma translação.
+    pub fn from_linear_2d(linear: Matrix2x2<T>, translation: Vec2<T>) -> Matrix3x3<T> {
+        Self::from_cols(
+            linear.c0.extend(T::zero()),
+            linear.c1.extend(T::zero()),
+            translation.extend(T::one()),
+        )
+    }
+
+    /// Cria uma matriz afim 2D de translação a partir de um vetor de 2 elementos (x, y).
+    /// ```text
+    /// // Matriz Transposta
+    /// [
+    ///     [1, 0, 0],
+    ///     [0, 1, 0],
+    ///     [x, y, 1],
+    /// ]
+    /// ```
+    pub fn from_translate_2d(xy: Vec2<T>) -> Matrix3x3<T> {
+        Self::from_linear_2d(Matrix2x2::identity(), xy)
+    }
+
+    /// Cria uma matriz afim 2D de rotação ao redor da origem a partir de um ângulo em
+    /// radianos, no sentido anti-horário.
+    pub fn from_rotate_2d(angle: Rad<T>) -> Matrix3x3<T> {
+        Self::from_linear_2d(
+            Matrix2x2::from_rotate(angle),
+            Vec2::new(T::zero(), T::zero()),
+        )
+    }
+
+    /// Cria uma matriz afim 2D de escala a partir de um vetor de 2 elementos (x, y).
+    pub fn from_scale_2d(xy: Vec2<T>) -> Matrix3x3<T> {
+        Self::from_linear_2d(Matrix2x2::from_scale(xy), Vec2::new(T::zero(), T::zero()))
+    }
+
+    /// Cria uma matriz afim 2D de cisalhamento, onde `x' = x + shear_x * y` e
+    /// `y' = y + shear_y * x`.
+    pub fn from_shear_2d(shear_x: T, shear_y: T) -> Matrix3x3<T> {
+        Self::from_linear_2d(
+            Matrix2x2::from_shear(shear_x, shear_y),
+            Vec2::new(T::zero(), T::zero()),
+        )
+    }
+
+    /// Transforma um ponto 2D pela matriz afim, aplicando a translação.
+    pub fn transform_point_2d(&self, point: Point2<T>) -> Point2<T> {
+        let v = *self * Vec3::new(point.x, point.y, T::one());
+        Point2::new(v.x, v.y)
+    }
+
+    /// Transforma um vetor (direção) 2D pela matriz afim, ignorando a translação.
+    pub fn transform_vector_2d(&self, vector: Vec2<T>) -> Vec2<T> {
+        (*self * vector.extend(T::zero())).truncate()
+    }
+
+    /// Retorna a matriz transposta, onde as linhas se tornam colunas.
+    pub fn transpose(&self) -> Matrix3x3<T> {
+        Self::from_cols(self.row(0), self.row(1), self.row(2))
+    }
+
+    /// Calcula o determinante da matriz.
+    pub fn determinant(&self) -> T {
+        self.c0.dot(self.c1.cross(self.c2))
+    }
+
+    /// Indica se a matriz possui inversa, ou seja, se o seu determinante não é zero.
+    pub fn is_invertible(&self) -> bool {
+        !self.is_singular(self.determinant())
+    }
+
+    /// Indica se o determinante `det` desta matriz deve ser considerado zero.
+    fn is_singular(&self, det: T) -> bool {
+        is_singular(det, [self.c0, self.c1, self.c2].map(|c| c.dot(c)))
+    }
+
+    /// Calcula a matriz inversa. Retorna `None` caso a matriz não seja inversível.
+    pub fn invert(&self) -> Option<Matrix3x3<T>> {
+        let det = self.determinant();
+
+        if self.is_singular(det) {
+            return None;
+        }
+
+        // As linhas da inversa são os produtos cruzados entre as colunas
+        let inverse = Self::from_cols(
+            self.c1.cross(self.c2),
+            self.c2.cross(self.c0),
+            self.c0.cross(self.c1),
+        )
+        .transpose();
+
+        Some(inverse * (T::one() / det))
+    }
+}
+
+/// Indica se um determinante deve ser considerado zero. O determinante nunca é maior que o
+/// produto das normas das colunas (desigualdade de Hadamard), então ele é comparado com esse
+/// produto em vez de um valor absoluto: uma matriz de escala 0.001 tem determinante muito
+/// pequeno, mas é inversível.
+#[inline]
+fn is_singular<T: BaseFloat, const N: usize>(det: T, squared_column_norms: [T; N]) -> bool {
+    let bound = squared_column_norms
+        .into_iter()
+        .fold(T::one(), |product, norm| product * norm)
+        .sqrt();
+
+    det.abs() <= bound * T::default_epsilon()
+}
+
+// Implementa a multiplicação de matrizes 2x2.
+impl<T: BaseFloat> Mul<Matrix2x2<T>> for Matrix2x2<T> {
+    type Output = Matrix2x2<T>;
+    fn mul(self, rhs: Matrix2x2<T>) -> Self::Output {
+        let a = self[0];
+        let b = self[1];
+
+        Matrix2x2::from_cols(a * rhs[0][0] + b * rhs[0][1], a * rhs[1][0] + b * rhs[1][1])
+    }
+}
+
+// Implementa a multiplicação de matrizes 3x3.
+impl<T: BaseFloat> Mul<Matrix3x3<T>> for Matrix3x3<T> {
+    type Output = Matrix3x3<T>;
+    fn mul(self, rhs: Matrix3x3<T>) -> Self::Output {
+        let a = self[0];
+        let b = self[1];
+        let c = self[2];
+
+        Matrix3x3::from_cols(
+            a * rhs[0][0] + b * rhs[0][1] + c * rhs[0][2],
+            a * rhs[1][0] + b * rhs[1][1] + c * rhs[1][2],
+            a * rhs[2][0] + b * rhs[2][1] + c * rhs[2][2],
+        )
+    }
+}
+
+/// Converte para uma matriz 4x4 que aplica a mesma transformação linear em X e Y.
+impl<T: BaseFloat> From<Matrix2x2<T>> for Matrix4x4<T> {
+    fn from(m: Matrix2x2<T>) -> Matrix4x4<T> {
+        Matrix4x4::from_cols(
+            Vec4::new(m.c0.x, m.c0.y, T::zero(), T::zero()),
+            Vec4::new(m.c1.x, m.c1.y, T::zero(), T::zero()),
+            Vec4::new(T::zero(), T::zero(), T::one(), T::zero()),
+            Vec4::new(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+}
+
+/// Converte para uma matriz 4x4 que aplica a mesma transformação linear 3D.
+impl<T: BaseFloat> From<Matrix3x3<T>> for Matrix4x4<T> {
+    fn from(m: Matrix3x3<T>) -> Matrix4x4<T> {
+        Matrix4x4::from_cols(
+            m.c0.extend(T::zero()),
+            m.c1.extend(T::zero()),
+            m.c2.extend(T::zero()),
+            Vec4::new(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+}
+
+/// Extrai a parte linear de X e Y da matriz 4x4 (canto superior esquerdo).
+impl<T: BaseFloat> From<Matrix4x4<T>> for Matrix2x2<T> {
+    fn from(m: Matrix4x4<T>) -> Matrix2x2<T> {
+        Matrix2x2::new(m.c0.x, m.c0.y, m.c1.x, m.c1.y)
+    }
+}
+
+/// Extrai a parte linear 3D da matriz 4x4 (canto superior esquerdo), descartando a
+/// translação.
+impl<T: BaseFloat> From<Matrix4x4<T>> for Matrix3x3<T> {
+    fn from(m: Matrix4x4<T>) -> Matrix3x3<T> {
+        Matrix3x3::from_cols(m.c0.truncate(), m.c1.truncate(), m.c2.truncate())
+    }
+}
+
+impl<T: BaseFloat> Matrix4x4<T> {
+    /// Cria uma matriz 4x4 a partir de uma matriz afim 2D, que transforma X e Y e preserva Z.
+    /// É a forma de usar uma transformação 2D no `GraphicsTransform`.
+    pub fn from_affine_2d(m: Matrix3x3<T>) -> Matrix4x4<T> {
+        Matrix4x4::from_cols(
+            Vec4::new(m.c0.x, m.c0.y, T::zero(), T::zero()),
+            Vec4::new(m.c1.x, m.c1.y, T::zero(), T::zero()),
+            Vec4::new(T::zero(), T::zero(), T::one(), T::zero()),
+            Vec4::new(m.c2.x, m.c2.y, T::zero(), T::one()),
+        )
+    }
+}
+
 macro_rules! impl_matrix {
     ($MatrixN:ident { $($field:ident),+ }, $n:expr) => {
         impl<T: BaseFloat> Mul<T> for $MatrixN<T> {
@@ -279,6 +822,51 @@ macro_rules! impl_matrix {
                 $MatrixN { $($field: self.$field * rhs),+ }
             }
         }
+
+        impl<T: BaseFloat> AbsDiffEq for $MatrixN<T> {
+            type Epsilon = T;
+
+            #[inline]
+            fn default_epsilon() -> T {
+                T::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+                $(self.$field.abs_diff_eq(&other.$field, epsilon))&&+
+            }
+        }
+
+        impl<T: BaseFloat> RelativeEq for $MatrixN<T> {
+            #[inline]
+            fn default_max_relative() -> T {
+                T::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+                $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+            }
+        }
+
+        impl<T: BaseFloat> UlpsEq for $MatrixN<T> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+            }
+        }
+
+        impl<T: fmt::Debug> fmt::Debug for $MatrixN<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} ", stringify!($MatrixN))?;
+                f.debug_list()$(.entry(&self.$field))+.finish()
+            }
+        }
     };
 }
 
@@ -393,8 +981,13 @@ macro_rules! fixed_array_conversions {
     }
 }
 
+impl_matrix!(Matrix2x2 { c0, c1 }, 2);
+impl_matrix!(Matrix3x3 { c0, c1, c2 }, 3);
 impl_matrix!(Matrix4x4 { c0, c1, c2, c3 }, 4);
 
+impl_matrix_vector_mul!(Matrix2x2, Vec2 { x: 0, y: 1 });
+impl_matrix_vector_mul!(Matrix3x3, Vec3 { x: 0, y: 1, z: 2 });
+
 impl_matrix_vector_mul!(
     Matrix4x4,
     Vec4 {
@@ -405,6 +998,10 @@ impl_matrix_vector_mul!(
     }
 );
 
+fixed_array_conversions!(Matrix2x2<T> { c0: 0, c1: 1 }, 2);
+fixed_array_conversions!(Matrix3x3<T> { c0: 0, c1: 1, c2: 2 }, 3);
 fixed_array_conversions!(Matrix4x4<T> { c0: 0, c1: 1, c2: 2, c3: 3 }, 4);
 
+index_operators!(Matrix2x2<T>, 2, Vec2<T>, usize);
+index_operators!(Matrix3x3<T>, 3, Vec3<T>, usize);
 index_operators!(Matrix4x4<T>, 4, Vec4<T>, usize);
@@ -77,6 +77,24 @@ macro_rules! impl_point {
                 $VectorN::new($(self.$field - rhs.$field),+)
             }
         }
+
+        impl<T: BaseNum> Add<$VectorN<T>> for $P<T> {
+            type Output = $P<T>;
+
+            #[inline]
+            fn add(self, rhs: $VectorN<T>) -> $P<T> {
+                $P::new($(self.$field + rhs.$field),+)
+            }
+        }
+
+        impl<T: BaseNum> Sub<$VectorN<T>> for $P<T> {
+            type Output = $P<T>;
+
+            #[inline]
+            fn sub(self, rhs: $VectorN<T>) -> $P<T> {
+                $P::new($(self.$field - rhs.$field),+)
+            }
+        }
     }
 }
 
@@ -0,0 +1,357 @@
+use std::fmt;
+use std::ops::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num_traits::cast;
+
+use crate::{
+    angle::Rad,
+    euler::{Axis, EulerOrder},
+    matrix::{Matrix3x3, Matrix4x4},
+    num::BaseFloat,
+    vector::{Vec3, Vec4},
+};
+
+/// Quatérnio `w + xi + yj + zk`. Quatérnios unitários representam rotações 3D sem o
+/// travamento de eixos (gimbal lock) dos ângulos de Euler e podem ser interpolados
+/// suavemente.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+pub struct Quaternion<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T> Quaternion<T> {
+    /// Cria um quatérnio a partir dos seus componentes.
+    #[inline]
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+impl<T: BaseFloat> Quaternion<T> {
+    /// Cria um quatérnio a partir da parte vetorial `v` e da parte escalar `w`.
+    #[inline]
+    pub fn from_sv(w: T, v: Vec3<T>) -> Self {
+        Self::new(v.x, v.y, v.z, w)
+    }
+
+    /// O quatérnio identidade, que não aplica nenhuma rotação.
+    pub fn identity() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero(), T::one())
+    }
+
+    /// Cria a rotação de `angle` radianos em torno do eixo `axis`, no sentido anti-horário.
+    /// O eixo não precisa estar normalizado.
+    pub fn from_axis_angle(axis: Vec3<T>, angle: Rad<T>) -> Self {
+        let half: T = cast(0.5).unwrap();
+        let (sin, cos) = (angle.0 * half).sin_cos();
+
+        Self::from_sv(cos, axis.normalize() * sin)
+    }
+
+    /// Cria a rotação dos ângulos de Euler `x`, `y` e `z`, aplicados na ordem `order`.
+    pub fn from_euler(order: EulerOrder, x: Rad<T>, y: Rad<T>, z: Rad<T>) -> Self {
+        order
+            .axes()
+            .iter()
+            .fold(Self::identity(), |rotation, axis| {
+                let step = match axis {
+                    Axis::X => Self::from_axis_angle(Vec3::new(T::one(), T::zero(), T::zero()), x),
+                    Axis::Y => Self::from_axis_angle(Vec3::new(T::zero(), T::one(), T::zero()), y),
+                    Axis::Z => Self::from_axis_angle(Vec3::new(T::zero(), T::zero(), T::one()), z),
+                };
+
+                // Cada rotação é aplicada depois das anteriores
+                step * rotation
+            })
+    }
+
+    /// Cria a rotação que faz o eixo +Z local apontar para `forward` e o eixo +Y local ficar o
+    /// mais próximo possível de `up`.
+    pub fn look_rotation(forward: Vec3<T>, up: Vec3<T>) -> Self {
+        let forward = forward.normalize();
+        let right = up.cross(forward).normalize();
+        let up = forward.cross(right);
+
+        Self::from(Matrix3x3::from_cols(right, up, forward))
+    }
+
+    /// O quatérnio conjugado, que para quatérnios unitários é a rotação inversa.
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// O quatérnio inverso, tal que `q * q.invert()` é a identidade.
+    pub fn invert(self) -> Self {
+        self.conjugate() * (T::one() / self.magnitude2())
+    }
+
+    /// Calcula o produto escalar entre dois quatérnios.
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Calcula a magnitude2 do quatérnio.
+    #[inline]
+    pub fn magnitude2(self) -> T {
+        self.dot(self)
+    }
+
+    /// Calcula a magnitude do quatérnio.
+    #[inline]
+    pub fn magnitude(self) -> T {
+        self.magnitude2().sqrt()
+    }
+
+    /// Normaliza o quatérnio para uma magnitude de 1.
+    pub fn normalize(self) -> Self {
+        self * (T::one() / self.magnitude())
+    }
+
+    /// Rotaciona o vetor `v`.
+    pub fn rotate(self, v: Vec3<T>) -> Vec3<T> {
+        // v' = v + 2w(q x v) + 2q x (q x v), onde q é a parte vetorial
+        let two = T::one() + T::one();
+        let q = Vec3::new(self.x, self.y, self.z);
+        let t = q.cross(v) * two;
+
+        v + t * self.w + q.cross(t)
+    }
+
+    /// Interpolação linear normalizada entre duas rotações, seguindo o caminho mais curto.
+    /// É mais rápida que `slerp`, mas a velocidade angular não é constante.
+    pub fn nlerp(self, other: Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            -other
+        } else {
+            other
+        };
+
+        (self * (T::one() - t) + other * t).normalize()
+    }
+
+    /// Interpolação esférica entre duas rotações, seguindo o caminho mais curto com velocidade
+    /// angular constante.
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = other;
+
+        // `q` e `-q` representam a mesma rotação, então usa o que está mais próximo
+        if cos < T::zero() {
+            other = -other;
+            cos = -cos;
+        }
+
+        // Para rotações muito próximas, o seno do ângulo tende a zero
+        if cos > cast(0.9995).unwrap() {
+            return self.nlerp(other, t);
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+
+        self * (((T::one() - t) * theta).sin() / sin) + other * ((t * theta).sin() / sin)
+    }
+}
+
+impl<T: BaseFloat> Default for Quaternion<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+// Composição de rotações: `a * b` aplica primeiro `b` e depois `a`, como nas matrizes.
+impl<T: BaseFloat> Mul<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl<T: BaseFloat> Mul<Vec3<T>> for Quaternion<T> {
+    type Output = Vec3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vec3<T>) -> Vec3<T> {
+        self.rotate(rhs)
+    }
+}
+
+impl<T: BaseFloat> Mul<T> for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Quaternion<T> {
+        Quaternion::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+impl<T: BaseFloat> Add<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    #[inline]
+    fn add(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+impl<T: BaseFloat> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    #[inline]
+    fn neg(self) -> Quaternion<T> {
+        Quaternion::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+/// Converte um quatérnio unitário para a matriz de rotação equivalente.
+impl<T: BaseFloat> From<Quaternion<T>> for Matrix3x3<T> {
+    fn from(q: Quaternion<T>) -> Matrix3x3<T> {
+        let two = T::one() + T::one();
+
+        let (xx, yy, zz) = (q.x * q.x * two, q.y * q.y * two, q.z * q.z * two);
+        let (xy, xz, yz) = (q.x * q.y * two, q.x * q.z * two, q.y * q.z * two);
+        let (wx, wy, wz) = (q.w * q.x * two, q.w * q.y * two, q.w * q.z * two);
+
+        #[rustfmt::skip]
+        let m = Matrix3x3::new(
+            T::one() - yy - zz, xy + wz, xz - wy,
+            xy - wz, T::one() - xx - zz, yz + wx,
+            xz + wy, yz - wx, T::one() - xx - yy,
+        );
+
+        m
+    }
+}
+
+/// Converte um quatérnio unitário para a matriz de rotação equivalente.
+impl<T: BaseFloat> From<Quaternion<T>> for Matrix4x4<T> {
+    fn from(q: Quaternion<T>) -> Matrix4x4<T> {
+        Matrix4x4::from(Matrix3x3::from(q))
+    }
+}
+
+/// Extrai a rotação de uma matriz de rotação pura (ortonormal).
+impl<T: BaseFloat> From<Matrix3x3<T>> for Quaternion<T> {
+    fn from(m: Matrix3x3<T>) -> Quaternion<T> {
+        let one = T::one();
+        let quarter: T = cast(0.25).unwrap();
+        let two = one + one;
+
+        // m[c][r] é o elemento da coluna `c` e linha `r`
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        // Usa o maior componente como divisor para evitar divisões por valores pequenos
+        if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Quaternion::new(
+                (m[1][2] - m[2][1]) / s,
+                (m[2][0] - m[0][2]) / s,
+                (m[0][1] - m[1][0]) / s,
+                s * quarter,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+            Quaternion::new(
+                s * quarter,
+                (m[1][0] + m[0][1]) / s,
+                (m[2][0] + m[0][2]) / s,
+                (m[1][2] - m[2][1]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+            Quaternion::new(
+                (m[1][0] + m[0][1]) / s,
+                s * quarter,
+                (m[2][1] + m[1][2]) / s,
+                (m[2][0] - m[0][2]) / s,
+            )
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+            Quaternion::new(
+                (m[2][0] + m[0][2]) / s,
+                (m[2][1] + m[1][2]) / s,
+                s * quarter,
+                (m[0][1] - m[1][0]) / s,
+            )
+        }
+    }
+}
+
+/// Extrai a rotação da parte linear de uma matriz 4x4 sem escala.
+impl<T: BaseFloat> From<Matrix4x4<T>> for Quaternion<T> {
+    fn from(m: Matrix4x4<T>) -> Quaternion<T> {
+        Quaternion::from(Matrix3x3::from(m))
+    }
+}
+
+impl<T> From<Quaternion<T>> for Vec4<T> {
+    #[inline]
+    fn from(q: Quaternion<T>) -> Vec4<T> {
+        Vec4::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl<T: BaseFloat> AbsDiffEq for Quaternion<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        Vec4::from(*self).abs_diff_eq(&Vec4::from(*other), epsilon)
+    }
+}
+
+impl<T: BaseFloat> RelativeEq for Quaternion<T> {
+    #[inline]
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        Vec4::from(*self).relative_eq(&Vec4::from(*other), epsilon, max_relative)
+    }
+}
+
+impl<T: BaseFloat> UlpsEq for Quaternion<T> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        Vec4::from(*self).ulps_eq(&Vec4::from(*other), epsilon, max_ulps)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Quaternion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Quaternion [{:?}, {:?}, {:?}, {:?}]",
+            self.x, self.y, self.z, self.w
+        )
+    }
+}
@@ -0,0 +1,237 @@
+use std::ops::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{
+    matrix::{Matrix3x3, Matrix4x4},
+    num::BaseFloat,
+    point::Point3,
+    quaternion::Quaternion,
+    vector::{Vec3, Vec4},
+};
+
+/// Uma transformação formada por escala, rotação e translação (TRS), aplicadas nessa ordem:
+/// o objeto é escalado, depois rotacionado e por último transladado.
+/// ```text
+/// Matrix4x4::from(transform) == T * R * S
+/// ```
+/// Diferente de uma matriz, os componentes ficam separados e podem ser interpolados entre
+/// duas poses com `lerp`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform<T> {
+    pub translation: Vec3<T>,
+    pub rotation: Quaternion<T>,
+    pub scale: Vec3<T>,
+}
+
+impl<T: BaseFloat> Transform<T> {
+    /// Cria a transformação a partir da translação, da rotação e da escala.
+    pub fn new(translation: Vec3<T>, rotation: Quaternion<T>, scale: Vec3<T>) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// A transformação identidade, que não altera os pontos.
+    pub fn identity() -> Self {
+        Self::new(
+            Vec3::from_value(T::zero()),
+            Quaternion::identity(),
+            Vec3::from_value(T::one()),
+        )
+    }
+
+    /// Cria uma transformação somente de translação.
+    pub fn from_translation(translation: Vec3<T>) -> Self {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    /// Cria uma transformação somente de rotação.
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        Self {
+            rotation,
+            ..Self::identity()
+        }
+    }
+
+    /// Cria uma transformação somente de escala.
+    pub fn from_scale(scale: Vec3<T>) -> Self {
+        Self {
+            scale,
+            ..Self::identity()
+        }
+    }
+
+    /// Decompõe uma matriz afim `T * R * S` nos seus componentes de translação, rotação e
+    /// escala. Uma escala negativa (reflexão) é representada no eixo X.
+    ///
+    /// Retorna `None` quando a matriz não pode ser representada, ou seja, quando possui
+    /// projeção (a última linha não é `[0, 0, 0, 1]`) ou alguma escala igual a zero. Matrizes
+    /// com cisalhamento retornam apenas uma aproximação.
+    pub fn from_matrix(m: Matrix4x4<T>) -> Option<Self> {
+        let last_row = Vec4::new(m.c0.w, m.c1.w, m.c2.w, m.c3.w);
+        if last_row.abs_diff_ne(
+            &Vec4::new(T::zero(), T::zero(), T::zero(), T::one()),
+            epsilon(),
+        ) {
+            return None;
+        }
+
+        let linear = Matrix3x3::from(m);
+        let mut scale = Vec3::new(
+            linear.c0.magnitude(),
+            linear.c1.magnitude(),
+            linear.c2.magnitude(),
+        );
+
+        if scale.x <= epsilon() || scale.y <= epsilon() || scale.z <= epsilon() {
+            return None;
+        }
+
+        if linear.determinant() < T::zero() {
+            scale.x = -scale.x;
+        }
+
+        let rotation = Matrix3x3::from_cols(
+            linear.c0 / scale.x,
+            linear.c1 / scale.y,
+            linear.c2 / scale.z,
+        );
+
+        Some(Self::new(
+            m.c3.truncate(),
+            Quaternion::from(rotation).normalize(),
+            scale,
+        ))
+    }
+
+    /// Aplica a transformação a um ponto.
+    pub fn transform_point(&self, point: Point3<T>) -> Point3<T> {
+        Point3::from_vec(self.transform_vector(point.to_vec()) + self.translation)
+    }
+
+    /// Aplica a transformação a um vetor de direção, que não é afetado pela translação.
+    pub fn transform_vector(&self, vector: Vec3<T>) -> Vec3<T> {
+        self.rotation.rotate(vector * self.scale)
+    }
+
+    /// A transformação inversa, que desfaz esta transformação.
+    ///
+    /// A inversa de `T * R * S` é `S⁻¹ * R⁻¹ * T⁻¹`, que só pode ser escrita novamente como
+    /// TRS quando a escala é uniforme. Com escala não uniforme e rotação, o resultado é uma
+    /// aproximação; para a inversa exata, use `Matrix4x4::from(t).invert()`.
+    ///
+    /// # Panics
+    ///
+    /// Caso algum componente da escala seja zero.
+    pub fn inverse(&self) -> Self {
+        if self.scale.x == T::zero() || self.scale.y == T::zero() || self.scale.z == T::zero() {
+            panic!("Não é possível inverter uma transformação com escala zero!");
+        }
+
+        let scale = Vec3::from_value(T::one()) / self.scale;
+        let rotation = self.rotation.invert();
+        let translation = rotation.rotate(-self.translation) * scale;
+
+        Self::new(translation, rotation, scale)
+    }
+
+    /// Interpola entre duas transformações, sendo `t = 0` esta transformação e `t = 1` a
+    /// transformação `other`. A translação e a escala são interpoladas linearmente e a
+    /// rotação com `Quaternion::slerp`.
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        Self::new(
+            self.translation + (other.translation - self.translation) * t,
+            self.rotation.slerp(other.rotation, t),
+            self.scale + (other.scale - self.scale) * t,
+        )
+    }
+}
+
+/// A tolerância usada para decidir se um componente é zero ao decompor uma matriz.
+fn epsilon<T: BaseFloat>() -> T {
+    T::default_epsilon().sqrt()
+}
+
+impl<T: BaseFloat> Default for Transform<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+// Composição de transformações: `a * b` aplica primeiro `b` e depois `a`, como nas matrizes.
+// O resultado é exato quando a escala de `a` é uniforme; caso contrário, o cisalhamento gerado
+// pela rotação de `b` sob a escala de `a` é descartado.
+impl<T: BaseFloat> Mul<Transform<T>> for Transform<T> {
+    type Output = Transform<T>;
+
+    fn mul(self, rhs: Transform<T>) -> Transform<T> {
+        Transform::new(
+            self.transform_point(Point3::from_vec(rhs.translation))
+                .to_vec(),
+            self.rotation * rhs.rotation,
+            self.scale * rhs.scale,
+        )
+    }
+}
+
+impl<T: BaseFloat> From<Transform<T>> for Matrix4x4<T> {
+    fn from(t: Transform<T>) -> Matrix4x4<T> {
+        Matrix4x4::from_translate(t.translation)
+            * Matrix4x4::from(t.rotation)
+            * Matrix4x4::from_scale(t.scale)
+    }
+}
+
+impl<T: BaseFloat> AbsDiffEq for Transform<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.translation.abs_diff_eq(&other.translation, epsilon)
+            && self.rotation.abs_diff_eq(&other.rotation, epsilon)
+            && self.scale.abs_diff_eq(&other.scale, epsilon)
+    }
+}
+
+impl<T: BaseFloat> RelativeEq for Transform<T> {
+    #[inline]
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.translation
+            .relative_eq(&other.translation, epsilon, max_relative)
+            && self
+                .rotation
+                .relative_eq(&other.rotation, epsilon, max_relative)
+            && self.scale.relative_eq(&other.scale, epsilon, max_relative)
+    }
+}
+
+impl<T: BaseFloat> UlpsEq for Transform<T> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.translation
+            .ulps_eq(&other.translation, epsilon, max_ulps)
+            && self.rotation.ulps_eq(&other.rotation, epsilon, max_ulps)
+            && self.scale.ulps_eq(&other.scale, epsilon, max_ulps)
+    }
+}
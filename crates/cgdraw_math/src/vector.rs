@@ -2,6 +2,7 @@ use std::fmt;
 use std::mem;
 use std::ops::*;
 
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use num_traits::NumCast;
 use num_traits::{Float, Zero};
 
@@ -37,6 +38,20 @@ pub struct Vec4<T> {
     pub w: T,
 }
 
+impl<T: BaseNum> Vec2<T> {
+    /// Cria um `Vec3` com os valores do vetor bidimensional e o valor `z`.
+    #[inline]
+    pub fn extend(self, z: T) -> Vec3<T> {
+        Vec3::new(self.x, self.y, z)
+    }
+
+    /// Calcula o produto escalar entre dois vetores.
+    #[inline]
+    pub fn dot(self, other: Vec2<T>) -> T {
+        Vec2::mul(self, other).sum()
+    }
+}
+
 impl<T: BaseNum> Vec3<T> {
     /// Faz o produto cruzado entre dois vetores.
     #[inline]
@@ -264,11 +279,58 @@ macro_rules! impl_vector {
     }
 }
 
+macro_rules! impl_approx {
+    ($V:ident { $($field:ident),+ }) => {
+        impl<T: AbsDiffEq> AbsDiffEq for $V<T> where T::Epsilon: Copy {
+            type Epsilon = T::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> T::Epsilon {
+                T::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                $(self.$field.abs_diff_eq(&other.$field, epsilon))&&+
+            }
+        }
+
+        impl<T: RelativeEq> RelativeEq for $V<T> where T::Epsilon: Copy {
+            #[inline]
+            fn default_max_relative() -> T::Epsilon {
+                T::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+                $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+            }
+        }
+
+        impl<T: UlpsEq> UlpsEq for $V<T> where T::Epsilon: Copy {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+            }
+        }
+    }
+}
+
 impl_vector!(Vec1 { x }, 1);
 impl_vector!(Vec2 { x, y }, 2);
 impl_vector!(Vec3 { x, y, z }, 3);
 impl_vector!(Vec4 { x, y, z, w }, 4);
 
+impl_approx!(Vec1 { x });
+impl_approx!(Vec2 { x, y });
+impl_approx!(Vec3 { x, y, z });
+impl_approx!(Vec4 { x, y, z, w });
+
 impl_fixed_array_conversions!(Vec1<S> { x: 0 }, 1);
 impl_fixed_array_conversions!(Vec2<S> { x: 0, y: 1 }, 2);
 impl_fixed_array_conversions!(Vec3<S> { x: 0, y: 1, z: 2 }, 3);
@@ -0,0 +1,89 @@
+use crate::{
+    matrix::Matrix4x4,
+    num::BaseFloat,
+    point::{Point2, Point3},
+    vector::Vec4,
+};
+
+/// A região da tela, em pixels, onde a cena é desenhada. A origem fica no canto superior
+/// esquerdo da janela e o eixo Y cresce para baixo, como nas posições do mouse.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Viewport<T> {
+    pub x: T,
+    pub y: T,
+    pub width: T,
+    pub height: T,
+}
+
+impl<T: BaseFloat> Viewport<T> {
+    /// Cria a região a partir do canto superior esquerdo e do tamanho.
+    pub fn new(x: T, y: T, width: T, height: T) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Cria a região que ocupa toda a janela de tamanho `width` x `height`.
+    pub fn from_size(width: T, height: T) -> Self {
+        Self::new(T::zero(), T::zero(), width, height)
+    }
+
+    /// A proporção entre a largura e a altura da região.
+    pub fn aspect(&self) -> T {
+        self.width / self.height
+    }
+
+    /// Indica se o ponto da tela está dentro da região.
+    pub fn contains(&self, x: T, y: T) -> bool {
+        self.x <= x && x < self.x + self.width && self.y <= y && y < self.y + self.height
+    }
+}
+
+/// Projeta um ponto do mundo na tela. O resultado possui as coordenadas `x` e `y` em pixels
+/// e, em `z`, a profundidade de 0 (plano de perto) a 1 (plano de longe).
+///
+/// Retorna `None` quando o ponto está atrás da câmera, onde a projeção não existe.
+pub fn project<T: BaseFloat>(
+    point: Point3<T>,
+    view_proj: &Matrix4x4<T>,
+    viewport: &Viewport<T>,
+) -> Option<Point3<T>> {
+    let clip = *view_proj * point.to_homogeneous();
+
+    if clip.w <= T::zero() {
+        return None;
+    }
+
+    let ndc = Point3::from_homogeneous(clip);
+    let half: T = num_traits::cast(0.5).unwrap();
+
+    Some(Point3::new(
+        viewport.x + (ndc.x + T::one()) * half * viewport.width,
+        viewport.y + (T::one() - ndc.y) * half * viewport.height,
+        ndc.z,
+    ))
+}
+
+/// Desfaz a projeção de um ponto da tela, em pixels, com a profundidade `depth` de 0 a 1,
+/// retornando o ponto correspondente no mundo. `inv_view_proj` é a inversa da matriz de
+/// visão e projeção usada para desenhar.
+pub fn unproject<T: BaseFloat>(
+    screen: Point2<T>,
+    depth: T,
+    inv_view_proj: &Matrix4x4<T>,
+    viewport: &Viewport<T>,
+) -> Point3<T> {
+    let two = T::one() + T::one();
+
+    let ndc = Vec4::new(
+        (screen.x - viewport.x) / viewport.width * two - T::one(),
+        T::one() - (screen.y - viewport.y) / viewport.height * two,
+        depth,
+        T::one(),
+    );
+
+    Point3::from_homogeneous(*inv_view_proj * ndc)
+}
@@ -0,0 +1,212 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use approx::assert_relative_eq;
+use cgdraw_math::{
+    angle::Rad,
+    euler::{Axis, EulerOrder},
+    matrix::Matrix4x4,
+    quaternion::Quaternion,
+    vector::Vec3,
+};
+
+const ORDERS: [EulerOrder; 6] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+];
+
+/// Gerador pseudoaleatório simples (LCG), para que os testes sejam reproduzíveis.
+struct Angles(u64);
+
+impl Angles {
+    /// Um ângulo entre -π e π.
+    fn next(&mut self) -> Rad<f64> {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+
+        Rad(((self.0 >> 11) as f64 / (1u64 << 53) as f64) * 2.0 * PI - PI)
+    }
+}
+
+/// A rotação anti-horária em Y. `from_rotate_y` gira no sentido horário.
+fn rotate_y(angle: Rad<f64>) -> Matrix4x4<f64> {
+    Matrix4x4::from_rotate_y(Rad(-angle.0))
+}
+
+fn single_axis(axis: Axis, x: Rad<f64>, y: Rad<f64>, z: Rad<f64>) -> Matrix4x4<f64> {
+    match axis {
+        Axis::X => Matrix4x4::from_rotate_x(x),
+        Axis::Y => rotate_y(y),
+        Axis::Z => Matrix4x4::from_rotate_z(z),
+    }
+}
+
+#[test]
+fn from_euler_matches_product_of_single_axis_rotations() {
+    let mut angles = Angles(7);
+
+    for _ in 0..200 {
+        let (x, y, z) = (angles.next(), angles.next(), angles.next());
+
+        for order in ORDERS {
+            let [a, b, c] = order.axes();
+
+            // Extrínseca: a primeira rotação fica mais à direita
+            let extrinsic =
+                single_axis(c, x, y, z) * single_axis(b, x, y, z) * single_axis(a, x, y, z);
+            assert_relative_eq!(
+                Matrix4x4::from_euler(order, x, y, z),
+                extrinsic,
+                epsilon = 1e-12
+            );
+
+            // Intrínseca: a primeira rotação fica mais à esquerda
+            let intrinsic =
+                single_axis(a, x, y, z) * single_axis(b, x, y, z) * single_axis(c, x, y, z);
+            assert_relative_eq!(
+                Matrix4x4::from_euler(order.intrinsic(), x, y, z),
+                intrinsic,
+                epsilon = 1e-12
+            );
+
+            assert_relative_eq!(
+                Matrix4x4::from(Quaternion::from_euler(order, x, y, z)),
+                extrinsic,
+                epsilon = 1e-12
+            );
+        }
+    }
+}
+
+#[test]
+fn from_rotate_is_xyz_order() {
+    let (x, y, z) = (Rad(0.4), Rad(-1.2), Rad(0.9));
+
+    assert_relative_eq!(
+        Matrix4x4::from_rotate(x, y, z),
+        Matrix4x4::from_rotate_z(z) * rotate_y(y) * Matrix4x4::from_rotate_x(x),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn single_axis_rotations_are_counterclockwise() {
+    let rotate = |m: Matrix4x4<f64>, v: Vec3<f64>| (m * v.extend(0.0)).truncate();
+    let quarter = Rad(FRAC_PI_2);
+
+    let (x, y, z) = (
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+    );
+
+    assert_relative_eq!(
+        rotate(Matrix4x4::from_rotate_x(quarter), y),
+        z,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        rotate(
+            Matrix4x4::from_euler(EulerOrder::XYZ, Rad(0.0), quarter, Rad(0.0)),
+            z
+        ),
+        x,
+        epsilon = 1e-12
+    );
+
+    // Exceto `from_rotate_y`, que mantém o sentido horário
+    assert_relative_eq!(
+        rotate(Matrix4x4::from_rotate_y(quarter), x),
+        z,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        rotate(Matrix4x4::from_rotate_z(quarter), x),
+        y,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn from_axis_angle_matches_single_axis_and_quaternion() {
+    let mut angles = Angles(42);
+
+    for _ in 0..200 {
+        let angle = angles.next();
+
+        assert_relative_eq!(
+            Matrix4x4::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), angle),
+            Matrix4x4::from_rotate_x(angle),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Matrix4x4::from_axis_angle(Vec3::new(0.0, 2.0, 0.0), angle),
+            rotate_y(angle),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Matrix4x4::from_axis_angle(Vec3::new(0.0, 0.0, -1.0), angle),
+            Matrix4x4::from_rotate_z(Rad(-angle.0)),
+            epsilon = 1e-12
+        );
+
+        let axis = Vec3::new(angles.next().0, angles.next().0, angles.next().0);
+        assert_relative_eq!(
+            Matrix4x4::from_axis_angle(axis, angle),
+            Matrix4x4::from(Quaternion::from_axis_angle(axis, angle)),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn to_euler_round_trip() {
+    let mut angles = Angles(1234);
+
+    for _ in 0..200 {
+        let (x, y, z) = (angles.next(), angles.next(), angles.next());
+
+        for order in ORDERS {
+            let m = Matrix4x4::from_euler(order, x, y, z);
+            let (ex, ey, ez) = m.to_euler(order);
+
+            assert_relative_eq!(Matrix4x4::from_euler(order, ex, ey, ez), m, epsilon = 1e-9);
+        }
+    }
+}
+
+#[test]
+fn to_euler_recovers_angles_in_range() {
+    let (x, y, z) = (Rad(0.3), Rad(-0.7), Rad(1.1));
+
+    for order in ORDERS {
+        let (ex, ey, ez) = Matrix4x4::from_euler(order, x, y, z).to_euler(order);
+
+        assert_relative_eq!(ex.0, x.0, epsilon = 1e-12);
+        assert_relative_eq!(ey.0, y.0, epsilon = 1e-12);
+        assert_relative_eq!(ez.0, z.0, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn to_euler_handles_gimbal_lock() {
+    for order in ORDERS {
+        let [_, middle, _] = order.axes();
+
+        for middle_angle in [FRAC_PI_2, -FRAC_PI_2] {
+            let mut angles = [Rad(0.5), Rad(-0.8), Rad(1.3)];
+            angles[middle.index()] = Rad(middle_angle);
+            let [x, y, z] = angles;
+
+            let m = Matrix4x4::from_euler(order, x, y, z);
+            let (ex, ey, ez) = m.to_euler(order);
+
+            assert_relative_eq!(Matrix4x4::from_euler(order, ex, ey, ez), m, epsilon = 1e-9);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use approx::assert_relative_eq;
+use cgdraw_math::{
+    angle::Rad,
+    geometry::{Aabb3, Frustum, Plane, Ray3, Sphere, Triangle3},
+    matrix::Matrix4x4,
+    point::Point3,
+    vector::Vec3,
+};
+
+fn unit_box() -> Aabb3<f64> {
+    Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0))
+}
+
+/// Um frustum em forma de caixa, de -10 a 10 em cada eixo.
+fn box_frustum() -> Frustum<f64> {
+    Frustum::new([
+        Plane::new(Vec3::new(1.0, 0.0, 0.0), 10.0),
+        Plane::new(Vec3::new(-1.0, 0.0, 0.0), 10.0),
+        Plane::new(Vec3::new(0.0, 1.0, 0.0), 10.0),
+        Plane::new(Vec3::new(0.0, -1.0, 0.0), 10.0),
+        Plane::new(Vec3::new(0.0, 0.0, 1.0), 10.0),
+        Plane::new(Vec3::new(0.0, 0.0, -1.0), 10.0),
+    ])
+}
+
+#[test]
+fn ray_hits_triangle() {
+    let triangle = Triangle3::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+
+    let ray = Ray3::new(Point3::new(0.25, 0.25, 5.0), Vec3::new(0.0, 0.0, -2.0));
+    let hit = ray.intersect_triangle(&triangle).unwrap();
+
+    assert_relative_eq!(hit.distance, 5.0);
+    assert_relative_eq!(hit.point.to_vec(), Vec3::new(0.25, 0.25, 0.0));
+    assert_relative_eq!(hit.normal, Vec3::new(0.0, 0.0, 1.0));
+
+    // Atingido por trás, a normal aponta para o lado de onde o raio veio
+    let ray = Ray3::new(Point3::new(0.25, 0.25, -5.0), Vec3::new(0.0, 0.0, 1.0));
+    assert_relative_eq!(
+        ray.intersect_triangle(&triangle).unwrap().normal,
+        Vec3::new(0.0, 0.0, -1.0)
+    );
+
+    // Fora do triângulo, atrás da origem e paralelo
+    let miss = Ray3::new(Point3::new(0.75, 0.75, 5.0), Vec3::new(0.0, 0.0, -1.0));
+    assert!(miss.intersect_triangle(&triangle).is_none());
+
+    let behind = Ray3::new(Point3::new(0.25, 0.25, 5.0), Vec3::new(0.0, 0.0, 1.0));
+    assert!(behind.intersect_triangle(&triangle).is_none());
+
+    let parallel = Ray3::new(Point3::new(0.0, 0.0, 1.0), Vec3::new(1.0, 0.0, 0.0));
+    assert!(parallel.intersect_triangle(&triangle).is_none());
+
+    assert_relative_eq!(triangle.area(), 0.5);
+}
+
+#[test]
+fn ray_hits_sub_millimetre_triangles() {
+    // Com arestas de 0.3 mm em uma cena em metros, o determinante em f32 é 9e-8, abaixo do
+    // épsilon de 1.2e-7
+    let triangle = Triangle3::new(
+        Point3::new(0.0f32, 0.0, 0.0),
+        Point3::new(0.0003, 0.0, 0.0),
+        Point3::new(0.0, 0.0003, 0.0),
+    );
+
+    let ray = Ray3::new(Point3::new(0.0001, 0.0001, 1.0), Vec3::new(0.0, 0.0, -1.0));
+    let hit = ray.intersect_triangle(&triangle).unwrap();
+
+    assert_relative_eq!(hit.distance, 1.0);
+    assert_relative_eq!(hit.normal, Vec3::new(0.0, 0.0, 1.0));
+
+    // Continua paralelo quando a direção está no plano do triângulo
+    let parallel = Ray3::new(Point3::new(0.0, 0.0, 0.0001), Vec3::new(1.0, 1.0, 0.0));
+    assert!(parallel.intersect_triangle(&triangle).is_none());
+}
+
+#[test]
+fn ray_hits_aabb() {
+    let ray = Ray3::new(Point3::new(-5.0, 0.5, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    let hit = ray.intersect_aabb(&unit_box()).unwrap();
+
+    assert_relative_eq!(hit.distance, 4.0);
+    assert_relative_eq!(hit.point.to_vec(), Vec3::new(-1.0, 0.5, 0.0));
+    assert_relative_eq!(hit.normal, Vec3::new(-1.0, 0.0, 0.0));
+
+    let ray = Ray3::new(Point3::new(0.0, 3.0, 0.0), Vec3::new(0.0, -1.0, 0.0));
+    let hit = ray.intersect_aabb(&unit_box()).unwrap();
+    assert_relative_eq!(hit.distance, 2.0);
+    assert_relative_eq!(hit.normal, Vec3::new(0.0, 1.0, 0.0));
+
+    // De dentro da caixa, o raio atinge a face de saída
+    let inside = Ray3::new(Point3::origin(), Vec3::new(0.0, 0.0, 1.0));
+    let hit = inside.intersect_aabb(&unit_box()).unwrap();
+    assert_relative_eq!(hit.distance, 1.0);
+    assert_relative_eq!(hit.normal, Vec3::new(0.0, 0.0, 1.0));
+
+    let miss = Ray3::new(Point3::new(-5.0, 2.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    assert!(miss.intersect_aabb(&unit_box()).is_none());
+
+    let away = Ray3::new(Point3::new(-5.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0));
+    assert!(away.intersect_aabb(&unit_box()).is_none());
+
+    let diagonal = Ray3::new(Point3::new(3.0, 3.0, 3.0), Vec3::new(-1.0, -1.0, -1.0));
+    let hit = diagonal.intersect_aabb(&unit_box()).unwrap();
+    assert_relative_eq!(hit.distance, 2.0 * 3.0_f64.sqrt(), epsilon = 1e-12);
+}
+
+#[test]
+fn ray_hits_sphere() {
+    let sphere = Sphere::new(Point3::new(0.0, 0.0, -10.0), 2.0);
+
+    let ray = Ray3::new(Point3::origin(), Vec3::new(0.0, 0.0, -1.0));
+    let hit = ray.intersect_sphere(&sphere).unwrap();
+
+    assert_relative_eq!(hit.distance, 8.0);
+    assert_relative_eq!(hit.point.to_vec(), Vec3::new(0.0, 0.0, -8.0));
+    assert_relative_eq!(hit.normal, Vec3::new(0.0, 0.0, 1.0));
+
+    let inside = Ray3::new(sphere.center, Vec3::new(1.0, 0.0, 0.0));
+    let hit = inside.intersect_sphere(&sphere).unwrap();
+    assert_relative_eq!(hit.distance, 2.0);
+    assert_relative_eq!(hit.normal, Vec3::new(1.0, 0.0, 0.0));
+
+    let miss = Ray3::new(Point3::new(0.0, 3.0, 0.0), Vec3::new(0.0, 0.0, -1.0));
+    assert!(miss.intersect_sphere(&sphere).is_none());
+
+    let away = Ray3::new(Point3::origin(), Vec3::new(0.0, 0.0, 1.0));
+    assert!(away.intersect_sphere(&sphere).is_none());
+}
+
+#[test]
+fn ray_hits_plane() {
+    let ground = Plane::from_point_normal(Point3::new(0.0, -2.0, 0.0), Vec3::new(0.0, 3.0, 0.0));
+
+    let ray = Ray3::new(Point3::new(1.0, 4.0, 0.0), Vec3::new(0.0, -1.0, 0.0));
+    let hit = ray.intersect_plane(&ground).unwrap();
+
+    assert_relative_eq!(hit.distance, 6.0);
+    assert_relative_eq!(hit.point.to_vec(), Vec3::new(1.0, -2.0, 0.0));
+    assert_relative_eq!(hit.normal, Vec3::new(0.0, 1.0, 0.0));
+
+    let parallel = Ray3::new(Point3::origin(), Vec3::new(1.0, 0.0, 0.0));
+    assert!(parallel.intersect_plane(&ground).is_none());
+
+    let away = Ray3::new(Point3::origin(), Vec3::new(0.0, 1.0, 0.0));
+    assert!(away.intersect_plane(&ground).is_none());
+}
+
+#[test]
+fn plane_from_points_and_distances() {
+    let plane = Plane::from_points(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+
+    assert_relative_eq!(plane.normal, Vec3::new(0.0, 0.0, 1.0));
+    assert_relative_eq!(plane.signed_distance(Point3::new(3.0, 4.0, 5.0)), 4.0);
+    assert_relative_eq!(plane.signed_distance(Point3::origin()), -1.0);
+    assert_relative_eq!(
+        plane.closest_point(Point3::new(3.0, 4.0, 5.0)).to_vec(),
+        Vec3::new(3.0, 4.0, 1.0)
+    );
+
+    // A equação é normalizada
+    let plane = Plane::new(Vec3::new(0.0, 2.0, 0.0), 4.0);
+    assert_relative_eq!(plane.distance, 2.0);
+}
+
+#[test]
+fn aabb_overlap_and_transform() {
+    let a = unit_box();
+    let touching = Aabb3::new(Point3::new(1.0, 0.0, 0.0), Point3::new(2.0, 1.0, 1.0));
+    let apart = Aabb3::new(Point3::new(1.5, 0.0, 0.0), Point3::new(2.0, 1.0, 1.0));
+
+    assert!(a.intersects(&touching));
+    assert!(touching.intersects(&a));
+    assert!(!a.intersects(&apart));
+
+    let points = [
+        Point3::new(1.0, -2.0, 0.5),
+        Point3::new(-3.0, 4.0, 0.0),
+        Point3::new(0.0, 0.0, 2.0),
+    ];
+    let bounds = Aabb3::from_points(points).unwrap();
+    assert_eq!(bounds.min, Point3::new(-3.0, -2.0, 0.0));
+    assert_eq!(bounds.max, Point3::new(1.0, 4.0, 2.0));
+    assert!(points.iter().all(|&p| bounds.contains_point(p)));
+    assert!(Aabb3::<f64>::from_points([]).is_none());
+
+    let moved = a.transform(&Matrix4x4::from_translate(Vec3::new(5.0, 0.0, 0.0)));
+    assert_eq!(moved.center(), Point3::new(5.0, 0.0, 0.0));
+
+    // Girar 45° em Z aumenta a caixa para conter os cantos
+    let rotated = a.transform(&Matrix4x4::from_rotate_z(Rad(std::f64::consts::FRAC_PI_4)));
+    assert_relative_eq!(rotated.max.x, 2.0_f64.sqrt(), epsilon = 1e-12);
+    assert_relative_eq!(rotated.size().z, 2.0);
+}
+
+#[test]
+fn frustum_culls_spheres_and_boxes() {
+    let frustum = box_frustum();
+
+    assert!(frustum.contains_point(Point3::origin()));
+    assert!(!frustum.contains_point(Point3::new(0.0, 11.0, 0.0)));
+
+    assert!(frustum.intersects_sphere(&Sphere::new(Point3::origin(), 1.0)));
+    assert!(frustum.intersects_sphere(&Sphere::new(Point3::new(11.0, 0.0, 0.0), 2.0)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(Point3::new(13.0, 0.0, 0.0), 2.0)));
+
+    let inside = unit_box();
+    let crossing = Aabb3::new(Point3::new(9.0, 9.0, 9.0), Point3::new(12.0, 12.0, 12.0));
+    let outside = Aabb3::new(Point3::new(0.0, 0.0, -20.0), Point3::new(1.0, 1.0, -11.0));
+
+    assert!(frustum.intersects_aabb(&inside));
+    assert!(frustum.intersects_aabb(&crossing));
+    assert!(!frustum.intersects_aabb(&outside));
+}
+
+#[test]
+fn frustum_from_view_proj() {
+    // Com a identidade, o frustum é o volume de recorte do wgpu: X e Y de -1 a 1 e Z de 0 a 1
+    let frustum = Frustum::from_view_proj(&Matrix4x4::<f64>::identity());
+
+    assert!(frustum.contains_point(Point3::new(0.0, 0.0, 0.5)));
+    assert!(frustum.contains_point(Point3::new(1.0, -1.0, 1.0)));
+    assert!(!frustum.contains_point(Point3::new(0.0, 0.0, -0.1)));
+    assert!(!frustum.contains_point(Point3::new(0.0, 1.1, 0.5)));
+
+    // Transladar o mundo move o frustum no sentido oposto
+    let view_proj = Matrix4x4::from_translate(Vec3::new(-10.0, 0.0, 0.0));
+    let frustum = Frustum::from_view_proj(&view_proj);
+
+    assert!(frustum.contains_point(Point3::new(10.5, 0.0, 0.5)));
+    assert!(!frustum.contains_point(Point3::new(0.0, 0.0, 0.5)));
+    assert!(frustum.intersects_aabb(&Aabb3::new(
+        Point3::new(5.0, 0.0, 0.0),
+        Point3::new(9.5, 1.0, 1.0)
+    )));
+    assert!(!frustum.intersects_aabb(&unit_box()));
+}
@@ -0,0 +1,302 @@
+use approx::assert_relative_eq;
+use cgdraw_math::{
+    angle::Rad,
+    matrix::{Matrix2x2, Matrix3x3, Matrix4x4},
+    point::{Point2, Point3},
+    vector::{Vec2, Vec3, Vec4},
+};
+
+/// Uma matriz sem nenhuma simetria, com determinante conhecido.
+#[rustfmt::skip]
+fn known_matrix() -> Matrix4x4<f64> {
+    Matrix4x4::new(
+        2.0, 0.0, 1.0, 3.0,
+        1.0, 1.0, 0.0, 2.0,
+        0.0, 3.0, 1.0, 1.0,
+        4.0, 1.0, 2.0, 1.0,
+    )
+}
+
+#[test]
+fn transpose_swaps_rows_and_columns() {
+    #[rustfmt::skip]
+    let expected = Matrix4x4::new(
+        2.0, 1.0, 0.0, 4.0,
+        0.0, 1.0, 3.0, 1.0,
+        1.0, 0.0, 1.0, 2.0,
+        3.0, 2.0, 1.0, 1.0,
+    );
+
+    assert_eq!(known_matrix().transpose(), expected);
+    assert_eq!(known_matrix().transpose().transpose(), known_matrix());
+}
+
+#[test]
+fn determinant_of_known_matrices() {
+    assert_relative_eq!(Matrix4x4::<f64>::identity().determinant(), 1.0);
+    assert_relative_eq!(known_matrix().determinant(), -27.0);
+    assert_relative_eq!(
+        Matrix4x4::from_scale(Vec3::new(2.0, 3.0, 4.0)).determinant(),
+        24.0
+    );
+    assert_relative_eq!(
+        Matrix4x4::from_rotate_y(Rad(0.7_f64)).determinant(),
+        1.0,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        known_matrix().transpose().determinant(),
+        known_matrix().determinant()
+    );
+}
+
+#[test]
+fn invert_known_matrices() {
+    assert_eq!(
+        Matrix4x4::<f64>::identity().invert(),
+        Some(Matrix4x4::identity())
+    );
+
+    let translate = Matrix4x4::from_translate(Vec3::new(1.0, -2.0, 3.0));
+    assert_relative_eq!(
+        translate.invert().unwrap(),
+        Matrix4x4::from_translate(Vec3::new(-1.0, 2.0, -3.0))
+    );
+
+    let scale = Matrix4x4::from_scale(Vec3::new(2.0, 4.0, 0.5));
+    assert_relative_eq!(
+        scale.invert().unwrap(),
+        Matrix4x4::from_scale(Vec3::new(0.5, 0.25, 2.0))
+    );
+
+    // A inversa de uma rotação é a sua transposta
+    let rotate = Matrix4x4::from_rotate_x(Rad(0.3_f64)) * Matrix4x4::from_rotate_z(Rad(1.2));
+    assert_relative_eq!(
+        rotate.invert().unwrap(),
+        rotate.transpose(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn matrix_times_inverse_is_identity() {
+    let m = known_matrix();
+    let inverse = m.invert().unwrap();
+
+    assert_relative_eq!(m * inverse, Matrix4x4::identity(), epsilon = 1e-12);
+    assert_relative_eq!(inverse * m, Matrix4x4::identity(), epsilon = 1e-12);
+    assert_relative_eq!(inverse.determinant(), -1.0 / 27.0, epsilon = 1e-12);
+
+    let view = Matrix4x4::look_at_rh(
+        Point3::new(3.0_f32, 2.0, -5.0),
+        Vec3::new(-3.0, -2.0, 5.0),
+        Vec3::new(0.0, 1.0, 0.0),
+    );
+    let world = view.invert().unwrap();
+
+    assert_relative_eq!(view * world, Matrix4x4::identity(), epsilon = 1e-5);
+
+    // A inversa da matriz de visualização leva a origem da câmera para a sua posição
+    assert_relative_eq!(
+        world * Vec4::new(0.0, 0.0, 0.0, 1.0),
+        Vec4::new(3.0, 2.0, -5.0, 1.0),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn singular_matrices_are_not_invertible() {
+    let flatten = Matrix4x4::from_scale(Vec3::new(1.0_f64, 0.0, 1.0));
+
+    assert!(!flatten.is_invertible());
+    assert_eq!(flatten.invert(), None);
+
+    #[rustfmt::skip]
+    let repeated_column = Matrix4x4::new(
+        1.0, 2.0, 3.0, 4.0,
+        1.0, 2.0, 3.0, 4.0,
+        0.0, 1.0, 0.0, 1.0,
+        5.0, 0.0, 2.0, 1.0,
+    );
+
+    assert_relative_eq!(repeated_column.determinant(), 0.0);
+    assert!(!repeated_column.is_invertible());
+    assert!(repeated_column.invert().is_none());
+
+    assert!(known_matrix().is_invertible());
+}
+
+#[test]
+fn small_scales_are_invertible() {
+    // O determinante é 0.004³ = 6.4e-8, menor que o epsilon do f32
+    let scale = Matrix4x4::from_scale(Vec3::new(0.004_f32, 0.004, 0.004));
+
+    assert!(scale.is_invertible());
+    assert_relative_eq!(
+        scale.invert().unwrap(),
+        Matrix4x4::from_scale(Vec3::new(250.0, 250.0, 250.0)),
+        max_relative = 1e-5
+    );
+
+    let small = Matrix3x3::new(0.004_f32, 0.0, 0.0, 0.0, 0.004, 0.0, 0.0, 0.0, 0.004);
+    assert!(small.invert().is_some());
+
+    let small = Matrix2x2::from_scale(Vec2::new(0.0001_f32, 0.0001));
+    assert!(small.invert().is_some());
+}
+
+#[test]
+fn scaled_singular_matrices_are_not_invertible() {
+    // Colunas quase paralelas continuam singulares, qualquer que seja a escala
+    for scale in [1e-3_f32, 1.0, 1e3] {
+        let m = Matrix2x2::new(1.0, 2.0, 1.0 + 1e-9, 2.0) * scale;
+        assert!(!m.is_invertible(), "{scale}");
+    }
+}
+
+#[test]
+fn matrix2x2_rotates_and_inverts() {
+    let rotate = Matrix2x2::from_rotate(Rad(std::f64::consts::FRAC_PI_2));
+
+    assert_relative_eq!(
+        rotate * Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(rotate.determinant(), 1.0);
+    assert_relative_eq!(rotate.invert().unwrap(), rotate.transpose());
+
+    let m = Matrix2x2::new(4.0, 2.0, 7.0, 6.0);
+    assert_relative_eq!(m.determinant(), 10.0);
+    assert_relative_eq!(m * m.invert().unwrap(), Matrix2x2::identity());
+
+    assert!(Matrix2x2::new(1.0, 2.0, 2.0, 4.0).invert().is_none());
+}
+
+#[test]
+fn matrix3x3_inverse_and_transpose() {
+    #[rustfmt::skip]
+    let m = Matrix3x3::new(
+        2.0, 0.0, 1.0,
+        1.0, 3.0, 2.0,
+        1.0, 1.0, 2.0,
+    );
+
+    assert_relative_eq!(m.determinant(), 6.0);
+    assert_relative_eq!(m.transpose().determinant(), m.determinant());
+    assert_relative_eq!(
+        m * m.invert().unwrap(),
+        Matrix3x3::identity(),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        m.invert().unwrap() * m,
+        Matrix3x3::identity(),
+        epsilon = 1e-12
+    );
+
+    #[rustfmt::skip]
+    let singular = Matrix3x3::new(
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        0.0, 1.0, 1.0,
+    );
+
+    assert!(!singular.is_invertible());
+    assert!(singular.invert().is_none());
+}
+
+#[test]
+fn affine_2d_transforms() {
+    let translate = Matrix3x3::from_translate_2d(Vec2::new(3.0, -1.0));
+    let rotate = Matrix3x3::from_rotate_2d(Rad(std::f64::consts::FRAC_PI_2));
+    let scale = Matrix3x3::from_scale_2d(Vec2::new(2.0, 3.0));
+    let shear = Matrix3x3::from_shear_2d(0.5, 0.0);
+
+    let p = Point2::new(1.0, 2.0);
+
+    assert_relative_eq!(translate.transform_point_2d(p).x, 4.0);
+    assert_relative_eq!(translate.transform_point_2d(p).y, 1.0);
+
+    // A translação não afeta vetores de direção
+    assert_relative_eq!(
+        translate.transform_vector_2d(Vec2::new(1.0, 2.0)),
+        Vec2::new(1.0, 2.0)
+    );
+
+    assert_relative_eq!(
+        (rotate * scale) * Vec3::new(1.0, 1.0, 1.0),
+        Vec3::new(-3.0, 2.0, 1.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(shear * Vec3::new(1.0, 2.0, 1.0), Vec3::new(2.0, 2.0, 1.0));
+
+    // Transladar e depois desfazer com a inversa volta ao ponto original
+    let m = translate * rotate * scale;
+    let back = m
+        .invert()
+        .unwrap()
+        .transform_point_2d(m.transform_point_2d(p));
+    assert_relative_eq!(back.x, p.x, epsilon = 1e-12);
+    assert_relative_eq!(back.y, p.y, epsilon = 1e-12);
+}
+
+#[test]
+fn conversions_between_matrix_sizes() {
+    let linear = Matrix3x3::from(Matrix4x4::from_rotate_y(Rad(0.4_f64)));
+    assert_relative_eq!(
+        Matrix4x4::from(linear),
+        Matrix4x4::from_rotate_y(Rad(0.4)),
+        epsilon = 1e-12
+    );
+
+    // A translação é descartada ao converter para 3x3
+    let translate = Matrix4x4::from_translate(Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(Matrix3x3::from(translate), Matrix3x3::identity());
+
+    let scale = Matrix2x2::from_scale(Vec2::new(2.0, 3.0));
+    assert_eq!(
+        Matrix4x4::from(scale),
+        Matrix4x4::from_scale(Vec3::new(2.0, 3.0, 1.0))
+    );
+    assert_eq!(Matrix2x2::from(Matrix4x4::from(scale)), scale);
+
+    let affine = Matrix3x3::from_translate_2d(Vec2::new(5.0, -2.0));
+    assert_eq!(
+        Matrix4x4::from_affine_2d(affine),
+        Matrix4x4::from_translate(Vec3::new(5.0, -2.0, 0.0))
+    );
+}
+
+#[test]
+fn look_functions_place_the_target_in_front_of_the_camera() {
+    let eye = Point3::new(3.0_f64, 2.0, -5.0);
+    let target = Point3::new(1.0, 4.0, 2.0);
+    let up = Vec3::new(0.0, 1.0, 0.0);
+    let distance = (target - eye).magnitude();
+
+    // Destra: o alvo fica no eixo -Z do espaço de visão
+    let rh = Matrix4x4::look_to_rh(eye, target - eye, up);
+    assert_relative_eq!(
+        rh * target.to_homogeneous(),
+        Vec4::new(0.0, 0.0, -distance, 1.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(rh, Matrix4x4::look_at_target_rh(eye, target, up));
+
+    // `look_at_rh` recebe o eixo Z da câmera, que aponta para trás, e não o alvo
+    assert_relative_eq!(rh, Matrix4x4::look_at_rh(eye, eye - target, up));
+
+    // Canhota: o alvo fica no eixo +Z do espaço de visão
+    let lh = Matrix4x4::look_at_target_lh(eye, target, up);
+    assert_relative_eq!(
+        lh * target.to_homogeneous(),
+        Vec4::new(0.0, 0.0, distance, 1.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(lh, Matrix4x4::look_to_lh(eye, target - eye, up));
+
+    // O vetor up continua apontando para cima na tela nas duas convenções
+    assert!((rh * Vec4::new(0.0, 1.0, 0.0, 0.0)).y > 0.0);
+    assert!((lh * Vec4::new(0.0, 1.0, 0.0, 0.0)).y > 0.0);
+}
@@ -0,0 +1,146 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use approx::assert_relative_eq;
+use cgdraw_math::{
+    angle::Rad,
+    euler::EulerOrder,
+    matrix::Matrix4x4,
+    quaternion::Quaternion,
+    vector::{Vec3, Vec4},
+};
+
+fn x_axis() -> Vec3<f64> {
+    Vec3::new(1.0, 0.0, 0.0)
+}
+
+fn y_axis() -> Vec3<f64> {
+    Vec3::new(0.0, 1.0, 0.0)
+}
+
+fn z_axis() -> Vec3<f64> {
+    Vec3::new(0.0, 0.0, 1.0)
+}
+
+#[test]
+fn axis_angle_matches_single_axis_matrices() {
+    let angle = Rad(0.8);
+
+    let cases = [
+        (x_axis(), Matrix4x4::from_rotate_x(angle)),
+        (z_axis(), Matrix4x4::from_rotate_z(angle)),
+    ];
+
+    for (axis, matrix) in cases {
+        let q = Quaternion::from_axis_angle(axis, angle);
+
+        assert_relative_eq!(Matrix4x4::from(q), matrix, epsilon = 1e-12);
+    }
+
+    // Rotação anti-horária em Y: o eixo Z vai para o eixo X
+    let q = Quaternion::from_axis_angle(y_axis(), Rad(FRAC_PI_2));
+    assert_relative_eq!(q.rotate(z_axis()), x_axis(), epsilon = 1e-12);
+}
+
+#[test]
+fn rotate_vector() {
+    let q = Quaternion::from_axis_angle(z_axis(), Rad(FRAC_PI_2));
+
+    assert_relative_eq!(q.rotate(x_axis()), y_axis(), epsilon = 1e-12);
+    assert_relative_eq!(q * y_axis(), -x_axis(), epsilon = 1e-12);
+
+    // Girar meia volta em torno de um eixo qualquer não altera o próprio eixo
+    let axis = Vec3::new(1.0, 2.0, 3.0);
+    let q = Quaternion::from_axis_angle(axis, Rad(PI));
+    assert_relative_eq!(q.rotate(axis), axis, epsilon = 1e-12);
+}
+
+#[test]
+fn multiplication_composes_rotations() {
+    let a = Quaternion::from_axis_angle(x_axis(), Rad(0.3));
+    let b = Quaternion::from_axis_angle(Vec3::new(1.0, 1.0, 0.0), Rad(1.1));
+    let v = Vec3::new(0.5, -2.0, 1.5);
+
+    assert_relative_eq!((a * b).rotate(v), a.rotate(b.rotate(v)), epsilon = 1e-12);
+    assert_relative_eq!(
+        Matrix4x4::from(a * b),
+        Matrix4x4::from(a) * Matrix4x4::from(b),
+        epsilon = 1e-12
+    );
+
+    assert_relative_eq!(a * a.invert(), Quaternion::identity(), epsilon = 1e-12);
+}
+
+#[test]
+fn euler_order_is_explicit() {
+    let (x, y, z) = (Rad(0.4), Rad(-1.2), Rad(0.9));
+
+    let rx = Matrix4x4::from_rotate_x(x);
+    let ry = Matrix4x4::from(Quaternion::from_axis_angle(y_axis(), y));
+    let rz = Matrix4x4::from_rotate_z(z);
+
+    let xyz = Quaternion::from_euler(EulerOrder::XYZ, x, y, z);
+    assert_relative_eq!(Matrix4x4::from(xyz), rz * ry * rx, epsilon = 1e-12);
+
+    let yxz = Quaternion::from_euler(EulerOrder::YXZ, x, y, z);
+    assert_relative_eq!(Matrix4x4::from(yxz), rz * rx * ry, epsilon = 1e-12);
+
+    let zyx = Quaternion::from_euler(EulerOrder::ZYX, x, y, z);
+    assert_relative_eq!(Matrix4x4::from(zyx), rx * ry * rz, epsilon = 1e-12);
+}
+
+#[test]
+fn matrix_round_trip() {
+    let rotations = [
+        Quaternion::identity(),
+        Quaternion::from_axis_angle(x_axis(), Rad(PI)),
+        Quaternion::from_axis_angle(y_axis(), Rad(3.0)),
+        Quaternion::from_axis_angle(Vec3::new(-1.0, 0.2, 0.7), Rad(2.5)),
+        Quaternion::from_euler(EulerOrder::ZXY, Rad(1.0), Rad(2.0), Rad(-0.5)),
+    ];
+
+    for q in rotations {
+        let back = Quaternion::from(Matrix4x4::from(q));
+
+        // `q` e `-q` representam a mesma rotação
+        let back = if back.dot(q) < 0.0 { -back } else { back };
+
+        assert_relative_eq!(back, q, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn look_rotation_points_z_to_forward() {
+    let forward = Vec3::new(1.0, 0.0, -1.0);
+    let q = Quaternion::look_rotation(forward, y_axis());
+
+    assert_relative_eq!(q.rotate(z_axis()), forward.normalize(), epsilon = 1e-12);
+    assert_relative_eq!(q.rotate(y_axis()), y_axis(), epsilon = 1e-12);
+}
+
+#[test]
+fn normalize_and_interpolation() {
+    let q = Quaternion::new(1.0, 2.0, 3.0, 4.0).normalize();
+    assert_relative_eq!(q.magnitude(), 1.0);
+
+    let a = Quaternion::identity();
+    let b = Quaternion::from_axis_angle(z_axis(), Rad(FRAC_PI_2));
+
+    assert_relative_eq!(a.slerp(b, 0.0), a, epsilon = 1e-12);
+    assert_relative_eq!(a.slerp(b, 1.0), b, epsilon = 1e-12);
+    assert_relative_eq!(
+        a.slerp(b, 0.5),
+        Quaternion::from_axis_angle(z_axis(), Rad(FRAC_PI_2 / 2.0)),
+        epsilon = 1e-12
+    );
+
+    // A interpolação segue o caminho mais curto mesmo com o sinal oposto
+    assert_relative_eq!(a.slerp(-b, 0.5).rotate(x_axis()).y, (PI / 4.0).sin());
+
+    let halfway = a.nlerp(b, 0.5);
+    assert_relative_eq!(halfway.magnitude(), 1.0);
+    assert_relative_eq!(
+        Vec4::from(halfway),
+        Vec4::from(a.slerp(b, 0.5)),
+        epsilon = 1e-12
+    );
+}
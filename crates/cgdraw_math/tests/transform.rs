@@ -0,0 +1,130 @@
+use approx::assert_relative_eq;
+use cgdraw_math::{
+    angle::Rad, euler::EulerOrder, matrix::Matrix4x4, point::Point3, quaternion::Quaternion,
+    transform::Transform, vector::Vec3,
+};
+
+fn pose() -> Transform<f64> {
+    Transform::new(
+        Vec3::new(1.0, -2.0, 3.0),
+        Quaternion::from_euler(EulerOrder::XYZ, Rad(0.3), Rad(-0.9), Rad(1.4)),
+        Vec3::new(2.0, 0.5, 1.5),
+    )
+}
+
+fn assert_same_rotation(a: Quaternion<f64>, b: Quaternion<f64>) {
+    // `q` e `-q` representam a mesma rotação
+    let b = if a.dot(b) < 0.0 { -b } else { b };
+    assert_relative_eq!(a, b, epsilon = 1e-12);
+}
+
+#[test]
+fn matrix_is_translate_rotate_scale() {
+    let t = pose();
+
+    assert_relative_eq!(
+        Matrix4x4::from(t),
+        Matrix4x4::from_translate(t.translation)
+            * Matrix4x4::from(t.rotation)
+            * Matrix4x4::from_scale(t.scale),
+        epsilon = 1e-12
+    );
+
+    let p = Point3::new(0.5, 1.0, -2.0);
+    let expected = Point3::from_homogeneous(Matrix4x4::from(t) * p.to_homogeneous());
+    let actual = t.transform_point(p);
+    assert_relative_eq!(actual.to_vec(), expected.to_vec(), epsilon = 1e-12);
+
+    assert_eq!(
+        Matrix4x4::from(Transform::<f64>::identity()),
+        Matrix4x4::identity()
+    );
+}
+
+#[test]
+fn decompose_round_trip() {
+    let t = pose();
+    let back = Transform::from_matrix(Matrix4x4::from(t)).unwrap();
+
+    assert_relative_eq!(back.translation, t.translation, epsilon = 1e-12);
+    assert_relative_eq!(back.scale, t.scale, epsilon = 1e-12);
+    assert_same_rotation(back.rotation, t.rotation);
+
+    // Reflexões são representadas com a escala negativa em X
+    let mirrored = Matrix4x4::from_scale(Vec3::new(1.0, -1.0, 1.0));
+    let back = Transform::from_matrix(mirrored).unwrap();
+    assert_relative_eq!(Matrix4x4::from(back), mirrored, epsilon = 1e-12);
+}
+
+#[test]
+fn decompose_rejects_singular_and_projective_matrices() {
+    let flatten = Matrix4x4::from_scale(Vec3::new(1.0_f64, 0.0, 1.0));
+    assert!(Transform::from_matrix(flatten).is_none());
+
+    let mut projective = Matrix4x4::<f64>::identity();
+    projective.c2.w = -1.0;
+    assert!(Transform::from_matrix(projective).is_none());
+}
+
+#[test]
+fn composition_matches_matrix_product() {
+    let a = Transform::new(
+        Vec3::new(-1.0, 4.0, 0.5),
+        Quaternion::from_axis_angle(Vec3::new(0.0, 1.0, 1.0), Rad(0.7)),
+        Vec3::from_value(3.0),
+    );
+    let b = pose();
+
+    assert_relative_eq!(
+        Matrix4x4::from(a * b),
+        Matrix4x4::from(a) * Matrix4x4::from(b),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn inverse_undoes_the_transform() {
+    let t = Transform::new(
+        Vec3::new(1.0, -2.0, 3.0),
+        Quaternion::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), Rad(2.1)),
+        Vec3::from_value(0.25),
+    );
+
+    assert_relative_eq!(t * t.inverse(), Transform::identity(), epsilon = 1e-12);
+    assert_relative_eq!(
+        Matrix4x4::from(t.inverse()),
+        Matrix4x4::from(t).invert().unwrap(),
+        epsilon = 1e-12
+    );
+
+    // Com escala não uniforme, os pontos ainda voltam ao lugar quando não há rotação
+    let t = Transform::new(
+        Vec3::new(5.0, 0.0, -1.0),
+        Quaternion::identity(),
+        Vec3::new(2.0, 4.0, 0.5),
+    );
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let back = t.inverse().transform_point(t.transform_point(p));
+    assert_relative_eq!(back.to_vec(), p.to_vec(), epsilon = 1e-12);
+}
+
+#[test]
+fn lerp_between_poses() {
+    let a = Transform::identity();
+    let b = Transform::new(
+        Vec3::new(2.0, 4.0, -6.0),
+        Quaternion::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), Rad(std::f64::consts::FRAC_PI_2)),
+        Vec3::new(3.0, 1.0, 5.0),
+    );
+
+    assert_relative_eq!(a.lerp(&b, 0.0), a, epsilon = 1e-12);
+    assert_relative_eq!(a.lerp(&b, 1.0), b, epsilon = 1e-12);
+
+    let halfway = a.lerp(&b, 0.5);
+    assert_relative_eq!(halfway.translation, Vec3::new(1.0, 2.0, -3.0));
+    assert_relative_eq!(halfway.scale, Vec3::new(2.0, 1.0, 3.0));
+    assert_same_rotation(
+        halfway.rotation,
+        Quaternion::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), Rad(std::f64::consts::FRAC_PI_4)),
+    );
+}
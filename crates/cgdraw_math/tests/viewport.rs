@@ -0,0 +1,73 @@
+use approx::assert_relative_eq;
+use cgdraw_math::{
+    matrix::Matrix4x4,
+    point::{Point2, Point3},
+    vector::Vec3,
+    viewport::{project, unproject, Viewport},
+};
+
+/// Uma projeção em perspectiva simples no intervalo de profundidade do wgpu, com 90° de
+/// campo de visão, plano de perto em 1 e de longe em 10.
+#[rustfmt::skip]
+fn view_proj() -> Matrix4x4<f64> {
+    let (near, far) = (1.0, 10.0);
+
+    let proj = Matrix4x4::new(
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, far / (near - far), -1.0,
+        0.0, 0.0, near * far / (near - far), 0.0,
+    );
+
+    proj * Matrix4x4::from_translate(Vec3::new(0.0, 0.0, -5.0))
+}
+
+#[test]
+fn project_to_pixels() {
+    let viewport = Viewport::from_size(800.0, 600.0);
+
+    // O centro da tela, no plano de perto (a câmera está em z = 5)
+    let center = project(Point3::new(0.0, 0.0, 4.0), &view_proj(), &viewport).unwrap();
+    assert_relative_eq!(
+        center.to_vec(),
+        Vec3::new(400.0, 300.0, 0.0),
+        epsilon = 1e-12
+    );
+
+    // Com 90° de campo de visão, a 1 unidade de distância a tela vai de -1 a 1
+    let corner = project(Point3::new(-1.0, 1.0, 4.0), &view_proj(), &viewport).unwrap();
+    assert_relative_eq!(corner.x, 0.0, epsilon = 1e-12);
+    assert_relative_eq!(corner.y, 0.0, epsilon = 1e-12);
+
+    let far = project(Point3::new(0.0, 0.0, -5.0), &view_proj(), &viewport).unwrap();
+    assert_relative_eq!(far.z, 1.0, epsilon = 1e-12);
+
+    // Pontos atrás da câmera não possuem projeção
+    assert!(project(Point3::new(0.0, 0.0, 6.0), &view_proj(), &viewport).is_none());
+}
+
+#[test]
+fn unproject_is_the_inverse_of_project() {
+    let viewport = Viewport::new(100.0, 50.0, 640.0, 480.0);
+    let inverse = view_proj().invert().unwrap();
+
+    for point in [
+        Point3::new(0.3, -0.2, 2.0),
+        Point3::new(-3.0, 2.0, -4.0),
+        Point3::new(1.0, 1.0, 0.0),
+    ] {
+        let screen = project(point, &view_proj(), &viewport).unwrap();
+        let back = unproject(
+            Point2::new(screen.x, screen.y),
+            screen.z,
+            &inverse,
+            &viewport,
+        );
+
+        assert_relative_eq!(back.to_vec(), point.to_vec(), epsilon = 1e-9);
+    }
+
+    assert!(viewport.contains(100.0, 50.0));
+    assert!(!viewport.contains(740.0, 100.0));
+    assert_relative_eq!(viewport.aspect(), 4.0 / 3.0);
+}
@@ -29,11 +29,21 @@ impl<'a> Render<'a> {
         if let Some(default_view) = self.default_view {
             // UNIFORMS ===================
 
-            self.state.queue.write_buffer(
-                &self.state.uniforms_buffer,
-                0,
-                bytemuck::cast_slice(&[self.state.uniforms]),
-            );
+            let draws: Vec<_> = self
+                .render_state
+                .buffers
+                .vertices
+                .iter()
+                .map(|vb| (vb.model.into(), vb.material.unwrap_or_default()))
+                .collect();
+
+            // Cada câmera desenha todos os buffers, então há um objeto uniforms por par de
+            // câmera e buffer
+            let viewports = self.state.frame_viewports();
+            let cameras: Vec<_> = viewports.iter().map(|viewport| viewport.camera).collect();
+
+            let stride = self.state.write_draw_uniforms(&cameras, &draws);
+            self.state.write_lights(&self.render_state.lights);
 
             // ===========================
 
@@ -44,26 +54,33 @@ impl<'a> Render<'a> {
                         label: Some("Command Encoder"),
                     });
 
-            {
+            let width = self.state.surface_config.width;
+            let height = self.state.surface_config.height;
+
+            for (v, viewport) in viewports.iter().enumerate() {
+                // A primeira região limpa a tela inteira; as demais desenham por cima dela
+                let color_load = if v == 0 {
+                    wgpu::LoadOp::Clear(self.state.clear_color)
+                } else {
+                    wgpu::LoadOp::Load
+                };
+
                 let desc = wgpu::RenderPassDescriptor {
                     label: Some("Render Pass"),
                     color_attachments: &[Some(wgpu::RenderPassColorAttachment {
                         view: &default_view,
                         resolve_target: None,
                         ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color {
-                                r: 0.1,
-                                g: 0.2,
-                                b: 0.3,
-                                a: 1.0,
-                            }),
+                            load: color_load,
                             store: true,
                         },
                     })],
+                    // Cada câmera começa com a profundidade limpa, para que uma região não
+                    // esconda os desenhos de outra
                     depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
                         view: &self.state.depth_view,
                         depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0),
+                            load: wgpu::LoadOp::Clear(self.state.depth_mode().clear_value()),
                             store: true,
                         }),
                         stencil_ops: None,
@@ -72,8 +89,25 @@ impl<'a> Render<'a> {
 
                 let mut pass = encoder.begin_render_pass(&desc);
 
-                for vb in self.render_state.buffers.vertices.iter() {
+                let (Some(pixels), Some(scissor)) = (
+                    viewport.viewport_pixels(width, height),
+                    viewport.scissor_pixels(width, height),
+                ) else {
+                    // A região está fora da tela, mas o passo ainda limpa a cor se for o primeiro
+                    continue;
+                };
+
+                pass.set_viewport(pixels.x, pixels.y, pixels.width, pixels.height, 0.0, 1.0);
+                pass.set_scissor_rect(scissor[0], scissor[1], scissor[2], scissor[3]);
+
+                // As luzes são as mesmas em todos os desenhos do quadro
+                pass.set_bind_group(1, &self.state.lights_bind_group, &[]);
+
+                for (i, vb) in self.render_state.buffers.vertices.iter().enumerate() {
                     match vb.primitive_topology {
+                        PrimitiveTopology::TriangleList if vb.material.is_some() => {
+                            pass.set_pipeline(&self.state.lit_triangle_pipeline);
+                        }
                         PrimitiveTopology::TriangleList => {
                             pass.set_pipeline(&self.state.triangle_pipeline);
                         }
@@ -86,7 +120,11 @@ impl<'a> Render<'a> {
                         _ => {}
                     }
 
-                    pass.set_bind_group(0, &self.state.uniforms_bind_group, &[]);
+                    // Cada desenho usa o objeto uniforms com a sua câmera, matriz `model` e
+                    // material
+                    let slot = v * draws.len() + i;
+                    let offset = (slot as wgpu::BufferAddress * stride) as wgpu::DynamicOffset;
+                    pass.set_bind_group(0, &self.state.uniforms_bind_group, &[offset]);
                     pass.draw_vertices(vb);
                 }
             }
@@ -98,14 +136,30 @@ impl<'a> Render<'a> {
 
 impl<'a> Render<'a> {
     pub fn build(mut self) {
-        let frame = match self.state.surface.get_current_texture() {
+        // Sem superfície (modo headless), renderiza na textura offscreen
+        let Some(surface) = self.state.surface.as_ref() else {
+            let target = self
+                .state
+                .render_target
+                .as_ref()
+                .expect("O estado não possui uma superfície nem uma textura offscreen!");
+
+            self.default_view = Some(
+                target
+                    .texture
+                    .create_view(&wgpu::TextureViewDescriptor::default()),
+            );
+
+            self.render_pass();
+
+            return;
+        };
+
+        let frame = match surface.get_current_texture() {
             Ok(frame) => frame,
             Err(_) => {
-                self.state
-                    .surface
-                    .configure(&self.state.device, &self.state.surface_config);
-                self.state
-                    .surface
+                surface.configure(&self.state.device, &self.state.surface_config);
+                surface
                     .get_current_texture()
                     .expect("Failed to acquire next surface texture!")
             }
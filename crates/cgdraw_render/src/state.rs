@@ -1,8 +1,20 @@
+use std::sync::Arc;
+
+use cgdraw_core::uniforms::{LightsUniformFloat32, MaterialUniformFloat32};
+
 pub struct VertexBufferState {
-    pub vertex_buffer: wgpu::Buffer,
-    pub index_buffer: wgpu::Buffer,
+    /// Os buffers podem ser compartilhados entre quadros por uma malha (mesh) retida.
+    pub vertex_buffer: Arc<wgpu::Buffer>,
+    pub index_buffer: Arc<wgpu::Buffer>,
     pub num_elements: u32,
     pub primitive_topology: wgpu::PrimitiveTopology,
+
+    /// A matriz `model` usada pelo shader para transformar os vértices deste desenho.
+    pub model: [[f32; 4]; 4],
+
+    /// O material dos triângulos iluminados. Com `None`, o desenho não é iluminado e recebe
+    /// apenas a cor dos vértices. Linhas e pontos nunca são iluminados.
+    pub material: Option<MaterialUniformFloat32>,
 }
 
 pub struct BuffersState {
@@ -17,6 +29,9 @@ pub struct RenderState {
      * Os buffers que serão renderizados
      */
     pub buffers: BuffersState,
+
+    /// As luzes que iluminam os desenhos com material neste quadro.
+    pub lights: LightsUniformFloat32,
 }
 
 impl Default for RenderState {
@@ -25,7 +40,10 @@ impl Default for RenderState {
             vertices: Vec::new(),
         };
 
-        Self { buffers }
+        Self {
+            buffers,
+            lights: LightsUniformFloat32::default(),
+        }
     }
 }
 
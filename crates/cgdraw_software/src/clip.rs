@@ -0,0 +1,93 @@
+use cgdraw_math::vector::{Vec3, Vec4};
+
+/// Um vértice já transformado pelo vertex shader, no espaço de recorte (clip space).
+#[derive(Debug, Clone, Copy)]
+pub struct ClipVertex {
+    /// A posição homogênea `view_proj * model * position`.
+    pub position: Vec4<f32>,
+
+    /// A cor do vértice, que será interpolada ao longo da primitiva.
+    pub color: Vec3<f32>,
+}
+
+impl ClipVertex {
+    /// Interpola linearmente dois vértices no espaço de recorte.
+    fn lerp(self, other: ClipVertex, t: f32) -> ClipVertex {
+        ClipVertex {
+            position: self.position + (other.position - self.position) * t,
+            color: self.color + (other.color - self.color) * t,
+        }
+    }
+
+    /// Indica se o vértice está dentro do volume de visualização.
+    pub fn is_inside(&self) -> bool {
+        let p = self.position;
+
+        p.w > 0.0 && p.x.abs() <= p.w && p.y.abs() <= p.w && p.z >= 0.0 && p.z <= p.w
+    }
+}
+
+/// Os planos de profundidade do volume de visualização do wgpu (`0 <= z <= w`). Os planos
+/// laterais não precisam ser recortados, já que a rasterização limita os pixels ao tamanho da
+/// imagem.
+const DEPTH_PLANES: [fn(Vec4<f32>) -> f32; 2] = [
+    // Plano próximo: z >= 0
+    |p| p.z,
+    // Plano distante: z <= w
+    |p| p.w - p.z,
+];
+
+/// Recorta um polígono convexo contra os planos de profundidade usando o algoritmo de
+/// Sutherland-Hodgman. Retorna um polígono vazio caso ele esteja totalmente fora.
+pub fn clip_polygon(polygon: &[ClipVertex]) -> Vec<ClipVertex> {
+    let mut output = polygon.to_vec();
+
+    for distance in DEPTH_PLANES {
+        let input = std::mem::take(&mut output);
+
+        for (i, &current) in input.iter().enumerate() {
+            let next = input[(i + 1) % input.len()];
+
+            let d_current = distance(current.position);
+            let d_next = distance(next.position);
+
+            if d_current >= 0.0 {
+                output.push(current);
+            }
+
+            // A aresta cruza o plano, então adiciona o ponto de interseção
+            if (d_current >= 0.0) != (d_next >= 0.0) {
+                output.push(current.lerp(next, d_current / (d_current - d_next)));
+            }
+        }
+    }
+
+    output
+}
+
+/// Recorta um segmento de reta contra os planos de profundidade. Retorna `None` caso ele
+/// esteja totalmente fora.
+pub fn clip_line(a: ClipVertex, b: ClipVertex) -> Option<(ClipVertex, ClipVertex)> {
+    let (mut t0, mut t1) = (0.0_f32, 1.0_f32);
+
+    for distance in DEPTH_PLANES {
+        let d_a = distance(a.position);
+        let d_b = distance(b.position);
+
+        if d_a < 0.0 && d_b < 0.0 {
+            return None;
+        }
+
+        if d_a < 0.0 {
+            t0 = t0.max(d_a / (d_a - d_b));
+        } else if d_b < 0.0 {
+            t1 = t1.min(d_a / (d_a - d_b));
+        }
+    }
+
+    if t0 > t1 {
+        return None;
+    }
+
+    Some((a.lerp(b, t0), a.lerp(b, t1)))
+}
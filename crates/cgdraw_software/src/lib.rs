@@ -0,0 +1,10 @@
+//! Rasterizador por software que implementa na CPU o mesmo pipeline que o
+//! `assets/shaders/shader.wgsl` e o `MainPipeline` implementam na GPU. Não precisa de um
+//! adaptador do wgpu, e cada etapa do pipeline está separada para servir como referência de
+//! estudo.
+
+mod clip;
+mod raster;
+mod renderer;
+
+pub use renderer::*;
@@ -0,0 +1,224 @@
+use cgdraw_core::graphic::DepthMode;
+use cgdraw_math::vector::Vec3;
+
+use crate::clip::ClipVertex;
+
+/// Os buffers de cor e profundidade onde as primitivas são rasterizadas.
+pub struct Framebuffer {
+    pub width: u32,
+    pub height: u32,
+
+    /// Os pixels da imagem em RGBA8, já codificados em sRGB como na textura da superfície.
+    pub color: Vec<u8>,
+
+    /// A profundidade de cada pixel, no intervalo `[0, 1]`.
+    pub depth: Vec<f32>,
+
+    /// O modo do teste de profundidade, como o `depth_compare` do pipeline.
+    pub depth_mode: DepthMode,
+}
+
+/// Um vértice no espaço de tela, pronto para ser rasterizado.
+#[derive(Debug, Clone, Copy)]
+struct ScreenVertex {
+    /// Posição em pixels, com a origem no canto superior esquerdo.
+    x: f32,
+    y: f32,
+
+    /// Profundidade no espaço normalizado (NDC), entre 0 e 1.
+    z: f32,
+
+    /// O inverso de `w`, usado para a interpolação com correção de perspectiva.
+    inv_w: f32,
+
+    /// A cor dividida por `w`.
+    color_over_w: Vec3<f32>,
+}
+
+impl Framebuffer {
+    pub fn new(width: u32, height: u32) -> Self {
+        let len = (width * height) as usize;
+
+        Self {
+            width,
+            height,
+            color: vec![0; len * 4],
+            depth: vec![DepthMode::default().clear_value(); len],
+            depth_mode: DepthMode::default(),
+        }
+    }
+
+    /// Preenche o buffer de cor com `color` e o buffer de profundidade com o valor de limpeza
+    /// de `depth_mode`, assim como o `LoadOp::Clear` do render pass.
+    pub fn clear(&mut self, color: [f32; 4]) {
+        let encoded = encode_color(Vec3::new(color[0], color[1], color[2]), color[3]);
+
+        for pixel in self.color.chunks_mut(4) {
+            pixel.copy_from_slice(&encoded);
+        }
+
+        self.depth.fill(self.depth_mode.clear_value());
+    }
+
+    /// Divisão de perspectiva e transformação de viewport: converte do espaço de recorte para
+    /// o espaço de tela.
+    fn to_screen(&self, v: ClipVertex) -> ScreenVertex {
+        let inv_w = 1.0 / v.position.w;
+
+        let ndc_x = v.position.x * inv_w;
+        let ndc_y = v.position.y * inv_w;
+
+        ScreenVertex {
+            x: (ndc_x + 1.0) * 0.5 * self.width as f32,
+            // No NDC o eixo Y aponta para cima, na imagem ele aponta para baixo
+            y: (1.0 - ndc_y) * 0.5 * self.height as f32,
+            z: v.position.z * inv_w,
+            inv_w,
+            color_over_w: v.color * inv_w,
+        }
+    }
+
+    /// Teste de profundidade (`CompareFunction::Less`, ou `Greater` com `DepthMode::ReversedZ`)
+    /// seguido da escrita do fragmento.
+    fn write_fragment(&mut self, x: u32, y: u32, z: f32, color: Vec3<f32>) {
+        if !(0.0..=1.0).contains(&z) {
+            return;
+        }
+
+        let i = (y * self.width + x) as usize;
+
+        if self.depth_mode.is_closer(z, self.depth[i]) {
+            self.depth[i] = z;
+            self.color[i * 4..i * 4 + 4].copy_from_slice(&encode_color(color, 1.0));
+        }
+    }
+
+    /// Rasteriza um triângulo já recortado. Triângulos com a face traseira voltada para a
+    /// câmera são descartados, como o `cull_mode: Some(Face::Back)` com `FrontFace::Ccw`.
+    pub fn triangle(&mut self, a: ClipVertex, b: ClipVertex, c: ClipVertex) {
+        let (v0, mut v1, mut v2) = (self.to_screen(a), self.to_screen(b), self.to_screen(c));
+
+        // Como o eixo Y foi invertido, triângulos anti-horários no NDC possuem área negativa
+        let mut area = edge(&v0, &v1, v2.x, v2.y);
+
+        if area >= 0.0 {
+            return;
+        }
+
+        // Troca a ordem dos vértices para trabalhar sempre com área positiva
+        std::mem::swap(&mut v1, &mut v2);
+        area = -area;
+
+        // A caixa delimitadora do triângulo, limitada ao tamanho da imagem
+        let min_x = v0.x.min(v1.x).min(v2.x).floor().max(0.0) as u32;
+        let min_y = v0.y.min(v1.y).min(v2.y).floor().max(0.0) as u32;
+        let max_x = v0.x.max(v1.x).max(v2.x).ceil().min(self.width as f32) as u32;
+        let max_y = v0.y.max(v1.y).max(v2.y).ceil().min(self.height as f32) as u32;
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                // Amostra no centro do pixel
+                let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+
+                let w0 = edge(&v1, &v2, px, py);
+                let w1 = edge(&v2, &v0, px, py);
+                let w2 = edge(&v0, &v1, px, py);
+
+                if !covers(w0, &v1, &v2) || !covers(w1, &v2, &v0) || !covers(w2, &v0, &v1) {
+                    continue;
+                }
+
+                // Coordenadas baricêntricas
+                let (l0, l1, l2) = (w0 / area, w1 / area, w2 / area);
+
+                let z = l0 * v0.z + l1 * v1.z + l2 * v2.z;
+
+                let inv_w = l0 * v0.inv_w + l1 * v1.inv_w + l2 * v2.inv_w;
+                let color =
+                    (v0.color_over_w * l0 + v1.color_over_w * l1 + v2.color_over_w * l2) / inv_w;
+
+                self.write_fragment(x, y, z, color);
+            }
+        }
+    }
+
+    /// Rasteriza um segmento de reta já recortado, amostrando um pixel por passo ao longo do
+    /// maior eixo.
+    pub fn line(&mut self, a: ClipVertex, b: ClipVertex) {
+        let (v0, v1) = (self.to_screen(a), self.to_screen(b));
+
+        let steps = (v1.x - v0.x).abs().max((v1.y - v0.y).abs()).ceil().max(1.0) as u32;
+
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+
+            // O pixel cujo centro está mais próximo do ponto, com empates indo para o pixel
+            // de cima ou da esquerda
+            let x = (v0.x + (v1.x - v0.x) * t - 1.0).ceil();
+            let y = (v0.y + (v1.y - v0.y) * t - 1.0).ceil();
+
+            if x < 0.0 || y < 0.0 || x >= self.width as f32 || y >= self.height as f32 {
+                continue;
+            }
+
+            let z = v0.z + (v1.z - v0.z) * t;
+            let inv_w = v0.inv_w + (v1.inv_w - v0.inv_w) * t;
+            let color = (v0.color_over_w + (v1.color_over_w - v0.color_over_w) * t) / inv_w;
+
+            self.write_fragment(x as u32, y as u32, z, color);
+        }
+    }
+
+    /// Rasteriza um ponto como um único pixel.
+    pub fn point(&mut self, v: ClipVertex) {
+        if !v.is_inside() {
+            return;
+        }
+
+        let s = self.to_screen(v);
+
+        let x = (s.x as u32).min(self.width - 1);
+        let y = (s.y as u32).min(self.height - 1);
+
+        self.write_fragment(x, y, s.z, v.color);
+    }
+}
+
+/// A função de aresta: o dobro da área com sinal do triângulo `(a, b, p)`.
+fn edge(a: &ScreenVertex, b: &ScreenVertex, px: f32, py: f32) -> f32 {
+    (b.x - a.x) * (py - a.y) - (b.y - a.y) * (px - a.x)
+}
+
+/// Regra top-left: um pixel exatamente sobre uma aresta só pertence ao triângulo se a aresta
+/// for superior ou esquerda, evitando que triângulos vizinhos desenhem o mesmo pixel duas
+/// vezes.
+fn covers(w: f32, a: &ScreenVertex, b: &ScreenVertex) -> bool {
+    if w != 0.0 {
+        return w > 0.0;
+    }
+
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+
+    (dy == 0.0 && dx > 0.0) || dy < 0.0
+}
+
+/// Converte uma cor linear para sRGB com 8 bits por canal, como faz uma textura
+/// `*UnormSrgb` ao receber a saída do fragment shader.
+fn encode_color(color: Vec3<f32>, alpha: f32) -> [u8; 4] {
+    fn linear_to_srgb(c: f32) -> f32 {
+        let c = c.clamp(0.0, 1.0);
+
+        if c <= 0.003_130_8 {
+            c * 12.92
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    [
+        (linear_to_srgb(color.x) * 255.0).round() as u8,
+        (linear_to_srgb(color.y) * 255.0).round() as u8,
+        (linear_to_srgb(color.z) * 255.0).round() as u8,
+        (alpha.clamp(0.0, 1.0) * 255.0).round() as u8,
+    ]
+}
@@ -0,0 +1,182 @@
+use cgdraw_core::{
+    graphic::{DepthMode, RgbaImage, Vertex},
+    uniforms::UniformsFloat32,
+};
+use cgdraw_math::{matrix::Matrix4x4, vector::Vec3};
+
+use crate::{
+    clip::{clip_line, clip_polygon, ClipVertex},
+    raster::Framebuffer,
+};
+
+/// Renderizador que rasteriza as primitivas na CPU, gerando os mesmos pixels que o pipeline
+/// do wgpu gera na GPU.
+pub struct SoftwareRenderer {
+    /// Os uniforms usados pelo vertex shader (`view_proj` da câmera e matriz `model`).
+    pub uniforms: UniformsFloat32,
+
+    /// A cor usada para limpar o buffer de cor.
+    pub clear_color: [f32; 4],
+
+    framebuffer: Framebuffer,
+}
+
+impl SoftwareRenderer {
+    /// Cria um renderizador com buffers de cor e profundidade de `width` x `height` pixels.
+    ///
+    /// # Panics
+    ///
+    /// Caso `width` ou `height` seja zero, assim como uma superfície do wgpu não pode ser
+    /// configurada com tamanho zero.
+    pub fn new(width: u32, height: u32) -> Self {
+        if width == 0 || height == 0 {
+            panic!("O renderizador precisa ter ao menos um pixel! Tamanho: {width} x {height}");
+        }
+
+        let mut renderer = Self {
+            uniforms: UniformsFloat32::default(),
+            clear_color: [0.1, 0.2, 0.3, 1.0],
+            framebuffer: Framebuffer::new(width, height),
+        };
+
+        renderer.clear();
+
+        renderer
+    }
+
+    /// A largura da imagem em pixels.
+    pub fn width(&self) -> u32 {
+        self.framebuffer.width
+    }
+
+    /// A altura da imagem em pixels.
+    pub fn height(&self) -> u32 {
+        self.framebuffer.height
+    }
+
+    /// O buffer de profundidade, linha por linha a partir do canto superior esquerdo.
+    pub fn depth(&self) -> &[f32] {
+        &self.framebuffer.depth
+    }
+
+    /// O modo do teste de profundidade.
+    pub fn depth_mode(&self) -> DepthMode {
+        self.framebuffer.depth_mode
+    }
+
+    /// Define o modo do teste de profundidade e limpa os buffers. Use `DepthMode::ReversedZ`
+    /// com as projeções `perspective_reversed_z`.
+    pub fn set_depth_mode(&mut self, depth_mode: DepthMode) {
+        self.framebuffer.depth_mode = depth_mode;
+        self.clear();
+    }
+
+    /// Limpa o buffer de cor com `clear_color` e o buffer de profundidade com o valor de
+    /// limpeza do modo de profundidade (1.0 no modo padrão).
+    pub fn clear(&mut self) {
+        self.framebuffer.clear(self.clear_color);
+    }
+
+    /// Copia o buffer de cor para uma imagem RGBA8.
+    pub fn to_image(&self) -> RgbaImage {
+        RgbaImage::new(
+            self.framebuffer.width,
+            self.framebuffer.height,
+            self.framebuffer.color.clone(),
+        )
+    }
+
+    /// Desenha os vértices indexados com a topologia informada, assim como o
+    /// `draw_indexed` de um render pass. Todas as topologias do wgpu são suportadas; nas
+    /// tiras (`LineStrip` e `TriangleStrip`), cada índice forma uma primitiva com os
+    /// anteriores.
+    ///
+    /// Primitivas com algum índice fora de `vertices` são descartadas, sem interromper o
+    /// desenho das demais.
+    pub fn draw(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u16],
+        primitive_topology: wgpu::PrimitiveTopology,
+    ) {
+        // Vertex shader: transforma cada vértice para o espaço de recorte
+        let view_proj = Matrix4x4::from(self.uniforms.camera.view_proj);
+        let model = Matrix4x4::from(self.uniforms.model);
+        let transform = view_proj * model;
+
+        let clip_vertices: Vec<ClipVertex> = vertices
+            .iter()
+            .map(|v| ClipVertex {
+                position: transform * Vec3::from(v.position).extend(1.0),
+                color: Vec3::from(v.color),
+            })
+            .collect();
+
+        // Busca os vértices de uma primitiva, ou `None` se algum índice for inválido
+        let fetch = |primitive: &[u16]| -> Option<Vec<ClipVertex>> {
+            primitive
+                .iter()
+                .map(|i| clip_vertices.get(*i as usize).copied())
+                .collect()
+        };
+
+        // Montagem das primitivas, recorte e rasterização
+        match primitive_topology {
+            wgpu::PrimitiveTopology::TriangleList => {
+                for triangle in indices.chunks_exact(3).filter_map(fetch) {
+                    self.triangle(&triangle);
+                }
+            }
+
+            wgpu::PrimitiveTopology::TriangleStrip => {
+                for (i, triangle) in indices.windows(3).enumerate() {
+                    if let Some(mut triangle) = fetch(triangle) {
+                        // Os triângulos ímpares da tira têm a ordem invertida; a troca mantém
+                        // o mesmo sentido de todos para o descarte das faces traseiras
+                        if i % 2 == 1 {
+                            triangle.swap(0, 1);
+                        }
+
+                        self.triangle(&triangle);
+                    }
+                }
+            }
+
+            wgpu::PrimitiveTopology::LineList => {
+                for line in indices.chunks_exact(2).filter_map(fetch) {
+                    self.line(&line);
+                }
+            }
+
+            wgpu::PrimitiveTopology::LineStrip => {
+                for line in indices.windows(2).filter_map(fetch) {
+                    self.line(&line);
+                }
+            }
+
+            wgpu::PrimitiveTopology::PointList => {
+                for point in indices.chunks_exact(1).filter_map(fetch) {
+                    self.framebuffer.point(point[0]);
+                }
+            }
+        }
+    }
+
+    /// Recorta e rasteriza um triângulo.
+    fn triangle(&mut self, triangle: &[ClipVertex]) {
+        let polygon = clip_polygon(triangle);
+
+        // Divide o polígono recortado em um leque de triângulos
+        for i in 1..polygon.len().saturating_sub(1) {
+            self.framebuffer
+                .triangle(polygon[0], polygon[i], polygon[i + 1]);
+        }
+    }
+
+    /// Recorta e rasteriza uma linha.
+    fn line(&mut self, line: &[ClipVertex]) {
+        if let Some((a, b)) = clip_line(line[0], line[1]) {
+            self.framebuffer.line(a, b);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use cgdraw_core::graphic::Vertex;
+use cgdraw_software::SoftwareRenderer;
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+/// Um renderizador de 8 x 8 pixels limpo com preto. Sem câmera, as posições dos vértices já
+/// estão no espaço normalizado (NDC).
+fn renderer() -> SoftwareRenderer {
+    let mut renderer = SoftwareRenderer::new(8, 8);
+    renderer.clear_color = [0.0, 0.0, 0.0, 1.0];
+    renderer.clear();
+    renderer
+}
+
+fn vertex(x: f32, y: f32, z: f32) -> Vertex {
+    Vertex {
+        position: [x, y, z],
+        color: [1.0, 0.0, 0.0],
+        normal: [0.0, 0.0, 0.0],
+    }
+}
+
+/// Desenha um retângulo com dois triângulos anti-horários.
+fn rectangle(renderer: &mut SoftwareRenderer, min: (f32, f32), max: (f32, f32), z: [f32; 2]) {
+    let vertices = [
+        vertex(min.0, min.1, z[0]),
+        vertex(max.0, min.1, z[1]),
+        vertex(max.0, max.1, z[1]),
+        vertex(min.0, max.1, z[0]),
+    ];
+
+    renderer.draw(
+        &vertices,
+        &[0, 1, 2, 0, 2, 3],
+        wgpu::PrimitiveTopology::TriangleList,
+    );
+}
+
+/// As colunas da linha `y` que foram desenhadas.
+fn drawn_columns(renderer: &SoftwareRenderer, y: u32) -> Vec<u32> {
+    let image = renderer.to_image();
+
+    (0..image.width)
+        .filter(|&x| image.pixel(x, y) != BLACK)
+        .collect()
+}
+
+#[test]
+#[should_panic]
+fn an_empty_renderer_panics() {
+    SoftwareRenderer::new(0, 8);
+}
+
+#[test]
+fn a_one_pixel_renderer_draws_points() {
+    let mut renderer = SoftwareRenderer::new(1, 1);
+
+    renderer.draw(
+        &[vertex(1.0, -1.0, 0.5)],
+        &[0],
+        wgpu::PrimitiveTopology::PointList,
+    );
+
+    assert_eq!(renderer.to_image().pixel(0, 0), RED);
+}
+
+#[test]
+fn the_top_left_edges_own_the_pixels_on_them() {
+    let mut renderer = renderer();
+
+    // As arestas passam pelos centros dos pixels 2 e 5, e a diagonal compartilhada pelos
+    // dois triângulos passa pelos centros dos pixels 3 e 4
+    rectangle(&mut renderer, (-0.375, -0.375), (0.375, 0.375), [0.5, 0.5]);
+
+    // As arestas esquerda e superior pertencem ao retângulo, a direita e a inferior não
+    for y in 2..5 {
+        assert_eq!(drawn_columns(&renderer, y), [2, 3, 4], "linha {y}");
+    }
+
+    assert!(drawn_columns(&renderer, 1).is_empty());
+    assert!(drawn_columns(&renderer, 5).is_empty());
+}
+
+#[test]
+fn neighbouring_rectangles_do_not_leave_gaps() {
+    let mut renderer = renderer();
+
+    // Dois retângulos lado a lado, com a aresta compartilhada sobre o centro do pixel 4
+    rectangle(&mut renderer, (-1.0, -1.0), (0.125, 1.0), [0.5, 0.5]);
+    rectangle(&mut renderer, (0.125, -1.0), (1.0, 1.0), [0.5, 0.5]);
+
+    assert_eq!(drawn_columns(&renderer, 4), (0..8).collect::<Vec<_>>());
+}
+
+#[test]
+fn triangles_are_clipped_against_the_near_plane() {
+    let mut renderer = renderer();
+
+    // A profundidade vai de -0.5 na esquerda a 0.5 na direita, então a metade esquerda fica
+    // atrás do plano próximo
+    rectangle(&mut renderer, (-1.0, -1.0), (1.0, 1.0), [-0.5, 0.5]);
+
+    assert_eq!(drawn_columns(&renderer, 4), [4, 5, 6, 7]);
+
+    // Os pixels desenhados recebem a profundidade interpolada da parte recortada
+    let depth = renderer.depth()[4 * 8 + 4];
+    assert!(depth > 0.0 && depth < 0.125, "{depth}");
+}
+
+#[test]
+fn triangles_beyond_the_far_plane_are_discarded() {
+    let mut renderer = renderer();
+
+    rectangle(&mut renderer, (-1.0, -1.0), (1.0, 1.0), [1.5, 2.0]);
+
+    for y in 0..8 {
+        assert!(drawn_columns(&renderer, y).is_empty(), "linha {y}");
+    }
+}
+
+#[test]
+fn lines_are_clipped_against_the_depth_planes() {
+    let mut renderer = renderer();
+
+    // Uma linha horizontal no centro da linha 4 que sai do plano distante na metade
+    renderer.draw(
+        &[vertex(-1.0, -0.125, 0.5), vertex(1.0, -0.125, 1.5)],
+        &[0, 1],
+        wgpu::PrimitiveTopology::LineList,
+    );
+
+    // O último pixel fica exatamente sobre o plano distante, então pode ou não ser desenhado
+    let columns = drawn_columns(&renderer, 4);
+    assert!(columns.starts_with(&[0, 1, 2]), "{columns:?}");
+    assert!(columns.iter().all(|&x| x < 4), "{columns:?}");
+}
+
+#[test]
+fn triangle_strips_draw_the_same_pixels_as_triangle_lists() {
+    let mut list = renderer();
+    rectangle(&mut list, (-0.5, -0.5), (0.5, 0.5), [0.5, 0.5]);
+
+    // A tira desenha (0, 1, 2) e (1, 3, 2), ambos anti-horários
+    let mut strip = renderer();
+    strip.draw(
+        &[
+            vertex(-0.5, -0.5, 0.5),
+            vertex(0.5, -0.5, 0.5),
+            vertex(-0.5, 0.5, 0.5),
+            vertex(0.5, 0.5, 0.5),
+        ],
+        &[0, 1, 2, 3],
+        wgpu::PrimitiveTopology::TriangleStrip,
+    );
+
+    assert_eq!(strip.to_image(), list.to_image());
+    assert_eq!(drawn_columns(&strip, 4), [2, 3, 4, 5]);
+}
+
+#[test]
+fn line_strips_connect_every_index() {
+    let mut renderer = renderer();
+
+    // Uma linha horizontal seguida de uma vertical, passando pelos centros dos pixels
+    renderer.draw(
+        &[
+            vertex(-0.875, -0.125, 0.5),
+            vertex(0.375, -0.125, 0.5),
+            vertex(0.375, 0.875, 0.5),
+        ],
+        &[0, 1, 2],
+        wgpu::PrimitiveTopology::LineStrip,
+    );
+
+    let image = renderer.to_image();
+    assert_eq!(image.pixel(0, 4), RED);
+    assert_eq!(image.pixel(5, 0), RED);
+    assert_eq!(image.pixel(5, 3), RED);
+}
+
+#[test]
+fn primitives_with_invalid_indices_are_skipped() {
+    let mut renderer = renderer();
+    let vertices = [
+        vertex(-1.0, -1.0, 0.5),
+        vertex(1.0, -1.0, 0.5),
+        vertex(1.0, 1.0, 0.5),
+    ];
+
+    // O primeiro triângulo usa um vértice que não existe; o segundo ainda é desenhado
+    renderer.draw(
+        &vertices,
+        &[0, 1, 7, 0, 1, 2],
+        wgpu::PrimitiveTopology::TriangleList,
+    );
+    renderer.draw(&vertices, &[3, 9], wgpu::PrimitiveTopology::PointList);
+
+    assert_eq!(renderer.to_image().pixel(6, 6), RED);
+    assert_eq!(renderer.to_image().pixel(1, 1), BLACK);
+}
@@ -1,4 +1,12 @@
-use cgdraw_core::{graphic::Texture, uniforms::UniformsFloat32};
+use std::sync::mpsc;
+
+use cgdraw_core::{
+    graphic::{CameraViewport, DepthMode, PresentMode, RgbaImage, ScreenRegion, Texture},
+    uniforms::{
+        CameraUniformFloat32, LightsUniformFloat32, MaterialUniformFloat32, UniformsFloat32,
+    },
+};
+use cgdraw_math::matrix::Matrix4x4;
 
 use pipelines::main::MainPipeline;
 use wgpu::RenderPipeline;
@@ -13,27 +21,53 @@ pub struct State {
     /// É a fila de comandos que permite enviar comandos para o dispositivo.
     pub queue: wgpu::Queue,
 
-    /// É a superfície que permite renderizar os gráficos.
-    pub surface: wgpu::Surface,
+    /// É a superfície que permite renderizar os gráficos. É `None` quando o estado foi criado
+    /// em modo headless, sem uma janela.
+    pub surface: Option<wgpu::Surface>,
 
-    /// É a configuração da superfície.
+    /// É a configuração da superfície. No modo headless, descreve o tamanho e o formato da
+    /// textura de renderização offscreen.
     pub surface_config: wgpu::SurfaceConfiguration,
 
+    /// A textura usada como alvo de renderização quando não há uma superfície (modo headless).
+    pub render_target: Option<Texture>,
+
     /// O uniform da câmera que será usado para enviar os dados da câmera para o shader.
     pub uniforms: UniformsFloat32,
 
-    /// O buffer da câmera que será usado para enviar os dados da câmera para o shader.
+    /// As câmeras desenhadas em regiões da tela. Quando vazia, o quadro é desenhado na tela
+    /// inteira com a câmera de `uniforms`.
+    pub viewports: Vec<CameraViewport>,
+
+    /// O buffer da câmera que será usado para enviar os dados da câmera para o shader. Possui
+    /// um objeto uniforms para cada desenho do quadro, com a matriz `model` do desenho.
     pub uniforms_buffer: wgpu::Buffer,
 
+    /// Quantos objetos uniforms cabem no buffer de uniforms.
+    pub uniforms_capacity: usize,
+
     /// O layout do grupo de ligação do objeto uniforms que será usado para criar o grupo de ligação do objeto uniforms.
     pub uniforms_bind_group_layout: wgpu::BindGroupLayout,
 
     /// O grupo de ligação do objeto uniforms que será usado para enviar os dados do uniforms para o shader.
     pub uniforms_bind_group: wgpu::BindGroup,
 
+    /// O buffer com as luzes do quadro, escrito com `write_lights`.
+    pub lights_buffer: wgpu::Buffer,
+
+    /// O layout do grupo de ligação das luzes, o grupo 1 dos pipelines.
+    pub lights_bind_group_layout: wgpu::BindGroupLayout,
+
+    /// O grupo de ligação das luzes.
+    pub lights_bind_group: wgpu::BindGroup,
+
     /// O pipeline que será usado para renderizar a primitiva de triângulo.
     pub triangle_pipeline: RenderPipeline,
 
+    /// O pipeline que será usado para renderizar os triângulos iluminados pelas luzes do
+    /// quadro, com o modelo de Blinn-Phong.
+    pub lit_triangle_pipeline: RenderPipeline,
+
     /// O pipeline que será usado para renderizar a primitiva de linha.
     pub line_pipeline: RenderPipeline,
 
@@ -42,22 +76,41 @@ pub struct State {
 
     /// A textura de profundidade que será usada para renderizar os gráficos.
     pub depth_view: wgpu::TextureView,
+
+    /// O modo do teste de profundidade, alterado com `set_depth_mode`
+    depth_mode: DepthMode,
+
+    /// A cor usada para limpar a tela no início de cada quadro.
+    pub clear_color: wgpu::Color,
 }
 
 impl State {
-    pub async fn new(window: &Window, uniforms: UniformsFloat32) -> Self {
-        let size = window.inner_size();
+    /// O formato da textura de renderização usada no modo headless.
+    pub const HEADLESS_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
 
-        // Backends: Vulkan, Metal, DX12, DX11, Browser WebGPU e GL
-        // Usado para criar o dispositivo e a fila de comandos.
-        let backends = wgpu::Backends::all();
+    /// Quantos desenhos por quadro o buffer de uniforms comporta inicialmente. O buffer cresce
+    /// quando um quadro possui mais desenhos.
+    const INITIAL_UNIFORMS_CAPACITY: usize = 64;
 
-        let dx12_shader_compiler = Default::default();
+    /// A cor usada para limpar a tela quando nenhuma outra é definida.
+    pub const DEFAULT_CLEAR_COLOR: wgpu::Color = wgpu::Color {
+        r: 0.1,
+        g: 0.2,
+        b: 0.3,
+        a: 1.0,
+    };
 
-        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-            backends,
-            dx12_shader_compiler,
-        });
+    /// Cria um estado que renderiza na superfície da janela. Caso o modo de apresentação não
+    /// seja suportado pela superfície, usa `PresentMode::Vsync`, que é suportado por todas as
+    /// plataformas.
+    pub async fn new(
+        window: &Window,
+        uniforms: UniformsFloat32,
+        present_mode: PresentMode,
+    ) -> Self {
+        let size = window.inner_size();
+
+        let instance = Self::create_instance();
 
         let surface = unsafe { instance.create_surface(window) }.unwrap();
 
@@ -70,21 +123,7 @@ impl State {
             .await
             .unwrap();
 
-        let (device, queue) = adapter
-            .request_device(
-                &wgpu::DeviceDescriptor {
-                    features: wgpu::Features::empty(),
-                    limits: if cfg!(target_arch = "wasm32") {
-                        wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits())
-                    } else {
-                        wgpu::Limits::default().using_resolution(adapter.limits())
-                    },
-                    label: None,
-                },
-                None, // Trace path
-            )
-            .await
-            .unwrap();
+        let (device, queue) = Self::request_device(&adapter).await;
 
         let surface_caps = surface.get_capabilities(&adapter);
 
@@ -95,17 +134,106 @@ impl State {
             .find(|f| f.is_srgb())
             .unwrap_or(surface_caps.formats[0]);
 
+        // Os modos automáticos são resolvidos pelo wgpu e sempre são suportados
+        let present_mode = match present_mode.to_wgpu_present_mode() {
+            mode @ (wgpu::PresentMode::AutoVsync | wgpu::PresentMode::AutoNoVsync) => mode,
+            mode if surface_caps.present_modes.contains(&mode) => mode,
+            _ => wgpu::PresentMode::Fifo,
+        };
+
         let surface_config = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
             format: surface_format,
             width: size.width,
             height: size.height,
-            present_mode: surface_caps.present_modes[0],
+            present_mode,
             alpha_mode: surface_caps.alpha_modes[0],
             view_formats: vec![],
         };
 
-        let uniforms_buffer = UniformsFloat32::create_buffer(&device, uniforms);
+        Self::from_device(device, queue, Some(surface), surface_config, uniforms)
+    }
+
+    /// Cria um estado sem janela, que renderiza em uma textura offscreen de tamanho
+    /// `width` x `height`. Caso nenhum adaptador de hardware esteja disponível, tenta usar o
+    /// adaptador de fallback (software), permitindo renderizar em ambientes sem GPU.
+    pub async fn new_headless(width: u32, height: u32, uniforms: UniformsFloat32) -> Self {
+        let instance = Self::create_instance();
+
+        let mut adapter = None;
+
+        for force_fallback_adapter in [false, true] {
+            adapter = instance
+                .request_adapter(&wgpu::RequestAdapterOptionsBase {
+                    power_preference: wgpu::PowerPreference::default(),
+                    force_fallback_adapter,
+                    compatible_surface: None,
+                })
+                .await;
+
+            if adapter.is_some() {
+                break;
+            }
+        }
+
+        let adapter =
+            adapter.expect("Nenhum adaptador gráfico disponível para renderização headless!");
+
+        let (device, queue) = Self::request_device(&adapter).await;
+
+        let surface_config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            format: Self::HEADLESS_FORMAT,
+            width,
+            height,
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Opaque,
+            view_formats: vec![],
+        };
+
+        Self::from_device(device, queue, None, surface_config, uniforms)
+    }
+
+    fn create_instance() -> wgpu::Instance {
+        // Backends: Vulkan, Metal, DX12, DX11, Browser WebGPU e GL
+        // Usado para criar o dispositivo e a fila de comandos.
+        let backends = wgpu::Backends::all();
+
+        let dx12_shader_compiler = Default::default();
+
+        wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends,
+            dx12_shader_compiler,
+        })
+    }
+
+    async fn request_device(adapter: &wgpu::Adapter) -> (wgpu::Device, wgpu::Queue) {
+        adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    features: wgpu::Features::empty(),
+                    limits: if cfg!(target_arch = "wasm32") {
+                        wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits())
+                    } else {
+                        wgpu::Limits::default().using_resolution(adapter.limits())
+                    },
+                    label: None,
+                },
+                None, // Trace path
+            )
+            .await
+            .unwrap()
+    }
+
+    fn from_device(
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        surface: Option<wgpu::Surface>,
+        surface_config: wgpu::SurfaceConfiguration,
+        uniforms: UniformsFloat32,
+    ) -> Self {
+        let uniforms_capacity = Self::INITIAL_UNIFORMS_CAPACITY;
+        let uniforms_buffer = UniformsFloat32::create_buffer(&device, uniforms_capacity);
         let uniforms_bind_group_layout = UniformsFloat32::create_bind_group_layout(&device);
         let uniforms_bind_group = UniformsFloat32::create_bind_group(
             &device,
@@ -113,45 +241,274 @@ impl State {
             &uniforms_buffer,
         );
 
-        let triangle_pipeline = MainPipeline::new(
+        let lights_buffer = LightsUniformFloat32::create_buffer(&device);
+        let lights_bind_group_layout = LightsUniformFloat32::create_bind_group_layout(&device);
+        let lights_bind_group = LightsUniformFloat32::create_bind_group(
             &device,
-            surface_config.format,
-            wgpu::PrimitiveTopology::TriangleList,
-            &[&uniforms_bind_group_layout],
-        )
-        .pipeline;
+            &lights_bind_group_layout,
+            &lights_buffer,
+        );
 
-        let line_pipeline = MainPipeline::new(
-            &device,
-            surface_config.format,
-            wgpu::PrimitiveTopology::LineList,
-            &[&uniforms_bind_group_layout],
-        )
-        .pipeline;
+        let depth_mode = DepthMode::default();
 
-        let point_pipeline = MainPipeline::new(
-            &device,
-            surface_config.format,
-            wgpu::PrimitiveTopology::PointList,
-            &[&uniforms_bind_group_layout],
-        )
-        .pipeline;
+        let (triangle_pipeline, lit_triangle_pipeline, line_pipeline, point_pipeline) =
+            Self::create_pipelines(
+                &device,
+                surface_config.format,
+                &[&uniforms_bind_group_layout, &lights_bind_group_layout],
+                depth_mode,
+            );
 
         let depth_view = Texture::create_depth_texture(&device, &surface_config).view;
 
+        // Sem superfície, os gráficos são renderizados em uma textura offscreen
+        let render_target = if surface.is_none() {
+            Some(Texture::create_render_target(&device, &surface_config))
+        } else {
+            None
+        };
+
         Self {
             device,
             queue,
             surface,
             surface_config,
+            render_target,
             uniforms,
+            viewports: Vec::new(),
             uniforms_buffer,
+            uniforms_capacity,
             uniforms_bind_group_layout,
             uniforms_bind_group,
+            lights_buffer,
+            lights_bind_group_layout,
+            lights_bind_group,
             triangle_pipeline,
+            lit_triangle_pipeline,
             line_pipeline,
             point_pipeline,
             depth_view,
+            depth_mode,
+            clear_color: Self::DEFAULT_CLEAR_COLOR,
+        }
+    }
+
+    /// Cria os pipelines de triângulos, de triângulos iluminados, de linhas e de pontos.
+    fn create_pipelines(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        bind_group_layouts: &[&wgpu::BindGroupLayout],
+        depth_mode: DepthMode,
+    ) -> (
+        RenderPipeline,
+        RenderPipeline,
+        RenderPipeline,
+        RenderPipeline,
+    ) {
+        let create = |primitive, lit| {
+            MainPipeline::new(
+                device,
+                format,
+                primitive,
+                bind_group_layouts,
+                depth_mode,
+                lit,
+            )
+            .pipeline
+        };
+
+        (
+            create(wgpu::PrimitiveTopology::TriangleList, false),
+            create(wgpu::PrimitiveTopology::TriangleList, true),
+            create(wgpu::PrimitiveTopology::LineList, false),
+            create(wgpu::PrimitiveTopology::PointList, false),
+        )
+    }
+}
+
+impl State {
+    /// Configura a superfície com a configuração atual. Não faz nada no modo headless.
+    pub fn configure_surface(&self) {
+        if let Some(surface) = self.surface.as_ref() {
+            surface.configure(&self.device, &self.surface_config);
+        }
+    }
+
+    /// Redimensiona a superfície (ou a textura offscreen) e a textura de profundidade.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.surface_config.width = width;
+        self.surface_config.height = height;
+
+        self.configure_surface();
+
+        self.depth_view = Texture::create_depth_texture(&self.device, &self.surface_config).view;
+
+        if self.render_target.is_some() {
+            self.render_target = Some(Texture::create_render_target(
+                &self.device,
+                &self.surface_config,
+            ));
+        }
+    }
+
+    /// O modo do teste de profundidade usado pelos pipelines.
+    pub fn depth_mode(&self) -> DepthMode {
+        self.depth_mode
+    }
+
+    /// Define o modo do teste de profundidade, recriando os pipelines. Use
+    /// `DepthMode::ReversedZ` com as projeções `perspective_reversed_z`.
+    pub fn set_depth_mode(&mut self, depth_mode: DepthMode) {
+        if depth_mode == self.depth_mode {
+            return;
+        }
+
+        self.depth_mode = depth_mode;
+
+        (
+            self.triangle_pipeline,
+            self.lit_triangle_pipeline,
+            self.line_pipeline,
+            self.point_pipeline,
+        ) = Self::create_pipelines(
+            &self.device,
+            self.surface_config.format,
+            &[
+                &self.uniforms_bind_group_layout,
+                &self.lights_bind_group_layout,
+            ],
+            depth_mode,
+        );
+    }
+
+    /// As câmeras usadas para desenhar o quadro: as de `viewports` ou, quando não há nenhuma,
+    /// a câmera de `uniforms` na tela inteira.
+    pub fn frame_viewports(&self) -> Vec<CameraViewport> {
+        if self.viewports.is_empty() {
+            vec![CameraViewport::new(
+                self.uniforms.camera,
+                ScreenRegion::FULL,
+            )]
+        } else {
+            self.viewports.clone()
+        }
+    }
+
+    /// Escreve no buffer de uniforms um objeto para cada par de câmera e desenho, aumentando
+    /// o buffer caso necessário. Cada desenho é formado pela sua matriz `model` e pelo seu
+    /// material. Os objetos da câmera `c` começam no índice `c * draws.len()`. Retorna a
+    /// distância em bytes entre os objetos, usada como deslocamento dinâmico de cada desenho.
+    pub fn write_draw_uniforms(
+        &mut self,
+        cameras: &[CameraUniformFloat32],
+        draws: &[(Matrix4x4<f32>, MaterialUniformFloat32)],
+    ) -> wgpu::BufferAddress {
+        let stride = UniformsFloat32::stride(&self.device);
+        let count = cameras.len() * draws.len();
+
+        if count > self.uniforms_capacity {
+            self.uniforms_capacity = count.next_power_of_two();
+            self.uniforms_buffer =
+                UniformsFloat32::create_buffer(&self.device, self.uniforms_capacity);
+            self.uniforms_bind_group = UniformsFloat32::create_bind_group(
+                &self.device,
+                &self.uniforms_bind_group_layout,
+                &self.uniforms_buffer,
+            );
+        }
+
+        let mut data = vec![0u8; stride as usize * count];
+
+        let pairs = cameras
+            .iter()
+            .flat_map(|camera| draws.iter().map(move |draw| (camera, draw)));
+
+        for (slot, (camera, (model, material))) in data.chunks_mut(stride as usize).zip(pairs) {
+            let uniforms = UniformsFloat32::new(*camera, *model, *material);
+
+            slot[..UniformsFloat32::SIZE as usize].copy_from_slice(bytemuck::bytes_of(&uniforms));
+        }
+
+        if !data.is_empty() {
+            self.queue.write_buffer(&self.uniforms_buffer, 0, &data);
+        }
+
+        stride
+    }
+
+    /// Escreve as luzes usadas pelos triângulos iluminados do quadro.
+    pub fn write_lights(&self, lights: &LightsUniformFloat32) {
+        self.queue
+            .write_buffer(&self.lights_buffer, 0, bytemuck::bytes_of(lights));
+    }
+
+    /// Lê de volta os pixels da textura offscreen para uma imagem RGBA8. Retorna `None` caso
+    /// o estado não tenha sido criado em modo headless.
+    pub fn read_pixels(&self) -> Option<RgbaImage> {
+        let target = self.render_target.as_ref()?;
+
+        let width = self.surface_config.width;
+        let height = self.surface_config.height;
+
+        // Cada linha copiada para o buffer precisa estar alinhada em 256 bytes
+        let unpadded_bytes_per_row = width * 4;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = (unpadded_bytes_per_row + align - 1) / align * align;
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Read Pixels Buffer"),
+            size: (padded_bytes_per_row * height) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Read Pixels Encoder"),
+            });
+
+        encoder.copy_texture_to_buffer(
+            target.texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = buffer.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            sender.send(result).unwrap();
+        });
+
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .unwrap()
+            .expect("Falha ao ler os pixels da textura offscreen!");
+
+        // Remove o preenchimento de alinhamento de cada linha
+        let mut pixels = Vec::with_capacity((unpadded_bytes_per_row * height) as usize);
+        {
+            let data = slice.get_mapped_range();
+            for row in data.chunks(padded_bytes_per_row as usize) {
+                pixels.extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
+            }
         }
+        buffer.unmap();
+
+        Some(RgbaImage::new(width, height, pixels))
     }
 }
@@ -1,17 +1,21 @@
 use std::borrow::Cow;
 
-use cgdraw_core::graphic::{Texture, Vertex};
+use cgdraw_core::graphic::{DepthMode, Texture, Vertex};
 
 pub struct MainPipeline {
     pub pipeline: wgpu::RenderPipeline,
 }
 
 impl MainPipeline {
+    /// Cria o pipeline da primitiva informada. Com `lit`, os fragmentos são iluminados pelas
+    /// luzes do quadro (`fs_lit`); sem ele, recebem apenas a cor dos vértices (`fs_main`).
     pub fn new(
         device: &wgpu::Device,
         format: wgpu::TextureFormat,
         primitive: wgpu::PrimitiveTopology,
         bind_group_layouts: &[&wgpu::BindGroupLayout],
+        depth_mode: DepthMode,
+        lit: bool,
     ) -> Self {
         let shader_source = wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!(
             "../../../../assets/shaders/shader.wgsl"
@@ -40,7 +44,7 @@ impl MainPipeline {
 
         let fragment_state = wgpu::FragmentState {
             module: &shader_module,
-            entry_point: "fs_main",
+            entry_point: if lit { "fs_lit" } else { "fs_main" },
             targets: &[Some(wgpu::ColorTargetState {
                 format,
                 blend: Some(wgpu::BlendState {
@@ -71,7 +75,7 @@ impl MainPipeline {
             depth_stencil: Some(wgpu::DepthStencilState {
                 format: Texture::DEPTH_FORMAT,
                 depth_write_enabled: true,
-                depth_compare: wgpu::CompareFunction::Less,
+                depth_compare: depth_mode.to_wgpu_compare_function(),
                 stencil: wgpu::StencilState::default(),
                 bias: wgpu::DepthBiasState::default(),
             }),
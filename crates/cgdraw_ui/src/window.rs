@@ -1,36 +1,184 @@
-use cgdraw_core::keyboard::keys::VirtualKeyCode;
-use winit::{dpi::PhysicalSize, event, event_loop};
+use cgdraw_core::{
+    keyboard::{keys::VirtualKeyCode, modifiers::Modifiers},
+    mouse::buttons::{MouseButton, MouseScrollDelta},
+};
+use winit::{
+    dpi::{PhysicalPosition, PhysicalSize},
+    event, event_loop,
+};
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum WindowEvent {
     Resize { size: PhysicalSize<u32> },
     Close,
     Redraw,
     KeyPressed { key_code: VirtualKeyCode },
     KeyUp { key_code: VirtualKeyCode },
+    ModifiersChanged { modifiers: Modifiers },
+    MouseMoved { x: f32, y: f32, dx: f32, dy: f32 },
+    MouseButtonPressed { button: MouseButton, x: f32, y: f32 },
+    MouseButtonReleased { button: MouseButton, x: f32, y: f32 },
+    MouseWheel { delta: MouseScrollDelta },
     Resumed,
 }
 
+/// Indica ao laço de eventos se a janela deve continuar aberta depois de tratar um evento.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum WindowControl {
+    Continue,
+    Exit,
+}
+
 pub struct Window {
     pub event_loop: winit::event_loop::EventLoop<()>,
     pub window: winit::window::Window,
+
+    /// A tecla que fecha a janela ao ser pressionada. `None` desativa o atalho.
+    pub exit_key: Option<VirtualKeyCode>,
+}
+
+/// As configurações usadas para criar a janela.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowConfig {
+    /// O título da janela.
+    pub title: String,
+
+    /// O tamanho inicial da área de desenho da janela, em pixels.
+    pub size: (u32, u32),
+
+    /// O tamanho mínimo da área de desenho da janela, em pixels.
+    pub min_size: Option<(u32, u32)>,
+
+    /// Define se o usuário pode redimensionar a janela.
+    pub resizable: bool,
+
+    /// Define se a janela abre em tela cheia (sem bordas, no monitor atual).
+    pub fullscreen: bool,
+
+    /// Define se a janela possui bordas e barra de título.
+    pub decorations: bool,
+
+    /// A tecla que fecha a janela ao ser pressionada. `None` desativa o atalho.
+    pub exit_key: Option<VirtualKeyCode>,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: "CG Draw".to_string(),
+            size: (800, 600),
+            min_size: None,
+            resizable: true,
+            fullscreen: false,
+            decorations: true,
+            exit_key: Some(VirtualKeyCode::Escape),
+        }
+    }
 }
 
 impl Default for Window {
     fn default() -> Self {
+        Self::new(&WindowConfig::default())
+    }
+}
+
+impl Window {
+    /// Cria uma janela com as configurações informadas.
+    pub fn new(config: &WindowConfig) -> Self {
         let event_loop = winit::event_loop::EventLoop::new();
-        let window = winit::window::WindowBuilder::new()
-            .with_title("CG Draw")
-            .build(&event_loop)
-            .unwrap();
 
-        Self { window, event_loop }
+        let mut builder = winit::window::WindowBuilder::new()
+            .with_title(config.title.as_str())
+            .with_inner_size(PhysicalSize::new(config.size.0, config.size.1))
+            .with_resizable(config.resizable)
+            .with_decorations(config.decorations);
+
+        if let Some((width, height)) = config.min_size {
+            builder = builder.with_min_inner_size(PhysicalSize::new(width, height));
+        }
+
+        if config.fullscreen {
+            builder = builder.with_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
+        }
+
+        let window = builder.build(&event_loop).unwrap();
+
+        Self {
+            window,
+            event_loop,
+            exit_key: config.exit_key,
+        }
+    }
+}
+
+/// Converte os eventos do mouse do winit. A posição do cursor é guardada entre os eventos para
+/// calcular o deslocamento e a posição dos cliques.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MouseTracker {
+    cursor_position: Option<PhysicalPosition<f64>>,
+}
+
+impl MouseTracker {
+    /// Converte um evento do mouse. Retorna `None` para os eventos que não são do mouse e
+    /// quando o cursor sai da janela.
+    pub fn window_event(&mut self, event: &event::WindowEvent) -> Option<WindowEvent> {
+        let (x, y) = self
+            .cursor_position
+            .map_or((0.0, 0.0), |p| (p.x as f32, p.y as f32));
+
+        match event {
+            event::WindowEvent::CursorMoved { position, .. } => {
+                // O primeiro movimento depois que o cursor entra na janela não tem deslocamento
+                let (dx, dy) = match self.cursor_position {
+                    Some(_) => (position.x as f32 - x, position.y as f32 - y),
+                    None => (0.0, 0.0),
+                };
+
+                self.cursor_position = Some(*position);
+
+                Some(WindowEvent::MouseMoved {
+                    x: position.x as f32,
+                    y: position.y as f32,
+                    dx,
+                    dy,
+                })
+            }
+
+            event::WindowEvent::CursorLeft { .. } => {
+                self.cursor_position = None;
+                None
+            }
+
+            event::WindowEvent::MouseInput { state, button, .. } => {
+                let button = MouseButton::by_winit_button(*button);
+
+                match state {
+                    event::ElementState::Pressed => {
+                        Some(WindowEvent::MouseButtonPressed { button, x, y })
+                    }
+                    event::ElementState::Released => {
+                        Some(WindowEvent::MouseButtonReleased { button, x, y })
+                    }
+                }
+            }
+
+            event::WindowEvent::MouseWheel { delta, .. } => Some(WindowEvent::MouseWheel {
+                delta: MouseScrollDelta::by_winit_delta(*delta),
+            }),
+
+            _ => None,
+        }
     }
 }
 
 impl Window {
+    /// Executa o laço de eventos da janela. A janela é fechada quando o usuário a fecha,
+    /// quando a tecla `exit_key` é pressionada ou quando `handler` retorna
+    /// `WindowControl::Exit`. Em todos os casos, `WindowEvent::Close` é o último evento
+    /// recebido por `handler`.
     pub fn run<F>(self, mut handler: F) -> !
     where
-        F: 'static + FnMut(WindowEvent),
+        F: 'static + FnMut(WindowEvent) -> WindowControl,
     {
         fn input_window_events(event: &event::WindowEvent) -> Option<WindowEvent> {
             match event {
@@ -68,6 +216,12 @@ impl Window {
                     }
                 }
 
+                event::WindowEvent::ModifiersChanged(modifiers) => {
+                    Some(WindowEvent::ModifiersChanged {
+                        modifiers: Modifiers::by_winit_modifiers(*modifiers),
+                    })
+                }
+
                 event::WindowEvent::Resized(physical_size) => Some(WindowEvent::Resize {
                     size: *physical_size,
                 }),
@@ -82,13 +236,29 @@ impl Window {
             }
         }
 
+        let mut mouse = MouseTracker::default();
+
+        let exit_key = self.exit_key;
+
         self.event_loop.run(move |event, _, control_flow| {
+            // Último evento do laço, recebido qualquer que seja o motivo do fechamento
+            if let event::Event::LoopDestroyed = event {
+                handler(WindowEvent::Close);
+                return;
+            }
+
+            // A janela está fechando: ignora os eventos restantes
+            if *control_flow == event_loop::ControlFlow::Exit {
+                return;
+            }
+
             *control_flow = event_loop::ControlFlow::Poll;
 
-            match event {
+            let control = match event {
                 event::Event::MainEventsCleared => {
                     // Request redraw
                     self.window.request_redraw();
+                    WindowControl::Continue
                 }
 
                 // Resize Event
@@ -96,46 +266,36 @@ impl Window {
                     ref event,
                     window_id,
                 } if window_id == self.window.id() => {
-                    let result = input_window_events(event);
+                    let result = input_window_events(event).or_else(|| mouse.window_event(event));
 
-                    if let Some(e) = result {
-                        handler(e)
-                    }
+                    let is_exit_key = matches!(
+                        (&result, exit_key),
+                        (Some(WindowEvent::KeyPressed { key_code }), Some(exit_key))
+                            if *key_code == exit_key
+                    );
 
-                    match event {
-                        event::WindowEvent::CloseRequested
-                        | event::WindowEvent::KeyboardInput {
-                            input:
-                                event::KeyboardInput {
-                                    state: event::ElementState::Pressed,
-                                    virtual_keycode: Some(event::VirtualKeyCode::Escape),
-                                    ..
-                                },
-                            ..
-                        } => *control_flow = event_loop::ControlFlow::Exit,
+                    let control = match result {
+                        Some(e) => handler(e),
+                        None => WindowControl::Continue,
+                    };
 
-                        _ => {}
+                    if is_exit_key || matches!(event, event::WindowEvent::CloseRequested) {
+                        WindowControl::Exit
+                    } else {
+                        control
                     }
                 }
 
-                // Close Event
-                event::Event::WindowEvent {
-                    event: event::WindowEvent::CloseRequested,
-                    window_id,
-                } if window_id == self.window.id() => {
-                    // builder.close_requested();
-                    *control_flow = event_loop::ControlFlow::Exit;
-                }
-
                 // Redraw request
-                event::Event::RedrawRequested(_) => {
-                    handler(WindowEvent::Redraw);
-                }
+                event::Event::RedrawRequested(_) => handler(WindowEvent::Redraw),
 
-                event::Event::Resumed => {
-                    handler(WindowEvent::Resumed);
-                }
-                _ => (),
+                event::Event::Resumed => handler(WindowEvent::Resumed),
+
+                _ => WindowControl::Continue,
+            };
+
+            if control == WindowControl::Exit {
+                *control_flow = event_loop::ControlFlow::Exit;
             }
         });
     }
@@ -0,0 +1,141 @@
+use cgdraw_core::mouse::buttons::{MouseButton, MouseScrollDelta};
+use cgdraw_ui::window::{MouseTracker, WindowEvent};
+use winit::{
+    dpi::PhysicalPosition,
+    event::{self, DeviceId, ElementState, ModifiersState, TouchPhase},
+};
+
+fn device_id() -> DeviceId {
+    // SAFETY: o identificador falso só é comparado, nunca usado para acessar o dispositivo
+    unsafe { DeviceId::dummy() }
+}
+
+fn cursor_moved(x: f64, y: f64) -> event::WindowEvent<'static> {
+    #[allow(deprecated)]
+    event::WindowEvent::CursorMoved {
+        device_id: device_id(),
+        position: PhysicalPosition::new(x, y),
+        modifiers: ModifiersState::empty(),
+    }
+}
+
+fn mouse_input(state: ElementState) -> event::WindowEvent<'static> {
+    #[allow(deprecated)]
+    event::WindowEvent::MouseInput {
+        device_id: device_id(),
+        state,
+        button: event::MouseButton::Left,
+        modifiers: ModifiersState::empty(),
+    }
+}
+
+#[test]
+fn cursor_moves_report_the_offset_since_the_last_move() {
+    let mut mouse = MouseTracker::default();
+
+    // O primeiro movimento não tem uma posição anterior
+    assert_eq!(
+        mouse.window_event(&cursor_moved(10.0, 20.0)),
+        Some(WindowEvent::MouseMoved {
+            x: 10.0,
+            y: 20.0,
+            dx: 0.0,
+            dy: 0.0,
+        })
+    );
+
+    assert_eq!(
+        mouse.window_event(&cursor_moved(15.0, 12.0)),
+        Some(WindowEvent::MouseMoved {
+            x: 15.0,
+            y: 12.0,
+            dx: 5.0,
+            dy: -8.0,
+        })
+    );
+}
+
+#[test]
+fn leaving_the_window_resets_the_offset() {
+    let mut mouse = MouseTracker::default();
+
+    mouse.window_event(&cursor_moved(10.0, 20.0));
+
+    let left = event::WindowEvent::CursorLeft {
+        device_id: device_id(),
+    };
+    assert_eq!(mouse.window_event(&left), None);
+
+    assert_eq!(
+        mouse.window_event(&cursor_moved(100.0, 100.0)),
+        Some(WindowEvent::MouseMoved {
+            x: 100.0,
+            y: 100.0,
+            dx: 0.0,
+            dy: 0.0,
+        })
+    );
+}
+
+#[test]
+fn clicks_use_the_last_cursor_position() {
+    let mut mouse = MouseTracker::default();
+
+    mouse.window_event(&cursor_moved(30.0, 40.0));
+
+    assert_eq!(
+        mouse.window_event(&mouse_input(ElementState::Pressed)),
+        Some(WindowEvent::MouseButtonPressed {
+            button: MouseButton::Left,
+            x: 30.0,
+            y: 40.0,
+        })
+    );
+
+    assert_eq!(
+        mouse.window_event(&mouse_input(ElementState::Released)),
+        Some(WindowEvent::MouseButtonReleased {
+            button: MouseButton::Left,
+            x: 30.0,
+            y: 40.0,
+        })
+    );
+}
+
+#[test]
+fn wheel_deltas_keep_their_unit() {
+    let mut mouse = MouseTracker::default();
+
+    let wheel = |delta| {
+        #[allow(deprecated)]
+        event::WindowEvent::MouseWheel {
+            device_id: device_id(),
+            delta,
+            phase: TouchPhase::Moved,
+            modifiers: ModifiersState::empty(),
+        }
+    };
+
+    assert_eq!(
+        mouse.window_event(&wheel(event::MouseScrollDelta::LineDelta(0.0, 1.0))),
+        Some(WindowEvent::MouseWheel {
+            delta: MouseScrollDelta::Lines { x: 0.0, y: 1.0 },
+        })
+    );
+
+    assert_eq!(
+        mouse.window_event(&wheel(event::MouseScrollDelta::PixelDelta(
+            PhysicalPosition::new(0.0, -40.0)
+        ))),
+        Some(WindowEvent::MouseWheel {
+            delta: MouseScrollDelta::Pixels { x: 0.0, y: -40.0 },
+        })
+    );
+}
+
+#[test]
+fn other_events_are_ignored() {
+    let mut mouse = MouseTracker::default();
+
+    assert_eq!(mouse.window_event(&event::WindowEvent::Focused(true)), None);
+}
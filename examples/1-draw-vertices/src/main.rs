@@ -1,7 +1,9 @@
 use cgdraw::{event::AppEvent, graphics::Primitive, AppBuilder, Color};
 
 fn main() {
-    let builder = AppBuilder::default();
+    let builder = AppBuilder::default()
+        .title("CG Draw - Draw Vertices")
+        .size(800, 600);
 
     let app = builder.build();
 
@@ -1,5 +1,3 @@
-use std::f32::consts::PI;
-
 use cgdraw::{
     angle::Deg,
     math::{perspective, Matrix4x4, Point3, Vec3},
@@ -11,11 +9,6 @@ pub struct ExampleCamera {
     attributes: CameraAttributes<f32>,
     screen_width: u32,
     screen_height: u32,
-
-    pub rotate_speed: f32,
-    pub move_speed: f32,
-
-    pub rotate: Vec3<f32>,
 }
 
 impl Default for ExampleCamera {
@@ -42,9 +35,6 @@ impl Default for ExampleCamera {
             attributes: CameraAttributes::new(up, pos, target),
             screen_width: 0,
             screen_height: 0,
-            move_speed: (2.0 * PI) / 15.0,
-            rotate_speed: (2.0 * PI) / 15.0,
-            rotate: Vec3::new(0.0, 0.0, 0.0),
         }
     }
 }
@@ -54,6 +44,11 @@ impl ExampleCamera {
         self.screen_width = width;
         self.screen_height = height;
     }
+
+    /// O tamanho da tela em pixels.
+    pub fn screen_size(&self) -> (f32, f32) {
+        (self.screen_width as f32, self.screen_height as f32)
+    }
 }
 
 impl Camera<f32> for ExampleCamera {
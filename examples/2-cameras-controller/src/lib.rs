@@ -1,3 +1,4 @@
 pub mod camera;
+pub mod scene;
 
 pub use camera::ExampleCamera;
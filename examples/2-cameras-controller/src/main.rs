@@ -1,169 +1,99 @@
-use cameras_controller_example::ExampleCamera;
+use cameras_controller_example::scene::{self, draw};
 use cgdraw::{
-    angle::Rad,
+    angle::Deg,
     event::AppEvent,
-    graphics::{Graphics, Primitive},
-    math::{Matrix4x4, Vec3},
-    AppBuilder, Camera, Color,
+    graphics::ScreenRegion,
+    light::Light,
+    math::{Point3, Vec3},
+    AppBuilder, Camera, Color, FlyCamera, MouseButton, OrbitCamera, VirtualKeyCode,
 };
 
-/// Desenha um cubo 3D.
-fn cube(g: &mut Graphics) {
-    // Front Face
-    g.begin(Primitive::TriangleList);
-    g.color(Color::RED);
-    g.v3d(-1.0, 1.0, 1.0);
-    g.v3d(-1.0, -1.0, 1.0);
-    g.v3d(1.0, -1.0, 1.0);
-    g.v3d(1.0, 1.0, 1.0);
-    g.indices([0, 1, 2, 0, 2, 3].to_vec());
-    g.end();
-
-    // Right Face
-    g.begin(Primitive::TriangleList);
-    g.color(Color::GRAY);
-    g.v3d(1.0, 1.0, -1.0);
-    g.v3d(1.0, -1.0, -1.0);
-    g.v3d(1.0, -1.0, 1.0);
-    g.v3d(1.0, 1.0, 1.0);
-    g.indices([2, 1, 0, 3, 2, 0].to_vec());
-    g.end();
-
-    // Left Face
-    g.begin(Primitive::TriangleList);
-    g.color(Color::GOLD);
-    g.v3d(-1.0, 1.0, -1.0);
-    g.v3d(-1.0, -1.0, -1.0);
-    g.v3d(-1.0, -1.0, 1.0);
-    g.v3d(-1.0, 1.0, 1.0);
-    g.indices([0, 1, 2, 0, 2, 3].to_vec());
-    g.end();
-
-    // Bottom Face
-    g.begin(Primitive::TriangleList);
-    g.color(Color::CYAN);
-    g.v3d(-1.0, -1.0, -1.0);
-    g.v3d(-1.0, -1.0, 1.0);
-    g.v3d(1.0, -1.0, 1.0);
-    g.v3d(1.0, -1.0, -1.0);
-    g.indices([2, 1, 0, 3, 2, 0].to_vec());
-    g.end();
-
-    // Top Face
-    g.begin(Primitive::TriangleList);
-    g.color(Color::ORANGE);
-    g.v3d(-1.0, 1.0, -1.0);
-    g.v3d(-1.0, 1.0, 1.0);
-    g.v3d(1.0, 1.0, 1.0);
-    g.v3d(1.0, 1.0, -1.0);
-    g.indices([0, 1, 2, 0, 2, 3].to_vec());
-    g.end();
-
-    // Back Face
-    g.begin(Primitive::TriangleList);
-    g.color(Color::VIOLET);
-    g.v3d(-1.0, 1.0, -1.0);
-    g.v3d(-1.0, -1.0, -1.0);
-    g.v3d(1.0, -1.0, -1.0);
-    g.v3d(1.0, 1.0, -1.0);
-    g.indices([2, 1, 0, 2, 0, 3].to_vec());
-    g.end();
+/// Cria uma câmera fixa olhando para a origem, para as vistas de cima, de frente e de lado.
+fn fixed_view(yaw: f32, pitch: f32) -> OrbitCamera<f32> {
+    let mut camera = OrbitCamera::new(Point3::new(0.0, 0.0, 0.0), 15.0);
+    camera.set_angles(Deg(yaw).into(), Deg(pitch).into());
+    camera.snap();
+    camera
 }
 
-/// Desenha linhas para melhorar a percepção do espaço 3D.
-fn wireframe(g: &mut Graphics) {
-    g.color(Color::GRAY);
-
-    g.begin(Primitive::LineList);
-    {
-        for j in -10..=10 {
-            g.v3d(-10.0, 0.0, j as f32);
-            g.v3d(10.0, 0.0, j as f32);
-
-            g.v3d(j as f32, 0.0, -10.0);
-            g.v3d(j as f32, 0.0, 10.0);
-        }
-    }
-    g.end();
-}
-
-/// Desenha o eixo de coordenadas.
-fn draw_origin(g: &mut Graphics) {
-    g.color(Color::BLUE);
-
-    g.begin(Primitive::LineList);
-    {
-        g.v3d(0.0, 0.0, 0.0);
-        g.v3d(0.0, 0.0, 1.0);
-        g.indices([0, 1].to_vec());
-    }
-    g.end();
-
-    g.color(Color::GREEN);
-
-    g.begin(Primitive::LineList);
-    {
-        g.v3d(0.0, 0.0, 0.0);
-        g.v3d(0.0, 1.0, 0.0);
-        g.indices([0, 1].to_vec());
-    }
-    g.end();
-
-    g.color(Color::RED);
-    g.begin(Primitive::LineList);
-    {
-        g.v3d(0.0, 0.0, 0.0);
-        g.v3d(1.0, 0.0, 0.0);
-        g.indices([0, 1].to_vec());
-    }
-    g.end();
-}
+fn main() {
+    // Pega as configurações setadas pelo usuário e cria uma instância de APP
+    let builder = AppBuilder::default()
+        .title("CG Draw - Cameras Controller")
+        .size(800, 600);
 
-/// Função para desenhar os objetos na tela.
-fn draw(g: &mut Graphics) {
-    g.t.translate(0.0, 2.0, 0.0);
-    cube(g);
-    g.t.load_identity();
+    let app = builder.build();
 
-    g.t.scale(3.0, 3.0, 3.0);
-    draw_origin(g);
-    g.t.load_identity();
+    // WASD ou setas para mover, E/Q para subir e descer e o botão direito do mouse para olhar
+    let mut camera = FlyCamera::new(Point3::new(4.0, 3.0, 12.0));
+    camera.look_at(Point3::new(0.0, 0.0, 0.0));
 
-    wireframe(g);
-}
+    // Com `V`, a tela é dividida nas vistas de cima, de frente, de lado e em perspectiva
+    let mut four_views = false;
+    let mut top = fixed_view(0.0, 90.0);
+    let mut front = fixed_view(0.0, 0.0);
+    let mut side = fixed_view(90.0, 0.0);
 
-fn main() {
-    // Pega as configurações setadas pelo usuário e cria uma instância de APP
-    let builder = AppBuilder::default();
+    // Com `L`, a cena é iluminada por uma luz ambiente fraca e um sol
+    let mut lights = false;
 
-    let app = builder.build();
+    // Clicar no cubo o seleciona, e clicar fora dele desfaz a seleção
+    let mut selected = false;
 
-    let mut camera_a = ExampleCamera::default();
+    // A grade é enviada para a GPU no primeiro quadro e reutilizada nos seguintes
+    let mut grid = None;
 
     app.run(move |event| match event {
         AppEvent::Update {
             handler,
             delta_time,
         } => {
-            let mut camera_view_proj = camera_a.calc_view_proj();
-
-            let rotate_value = camera_a.rotate_speed * delta_time.as_secs_f32();
+            camera.update(handler.input(), delta_time);
+
+            if four_views {
+                // Cada câmera recebe a proporção da sua região
+                handler.add_viewport(&mut top, ScreenRegion::new(0.0, 0.0, 0.5, 0.5), None);
+                handler.add_viewport(&mut front, ScreenRegion::new(0.5, 0.0, 0.5, 0.5), None);
+                handler.add_viewport(&mut side, ScreenRegion::new(0.0, 0.5, 0.5, 0.5), None);
+                handler.add_viewport(&mut camera, ScreenRegion::new(0.5, 0.5, 0.5, 0.5), None);
+            } else {
+                // Também atualiza a proporção da câmera para o tamanho da janela
+                handler.set_camera(&mut camera);
+            }
+        }
 
-            camera_a.rotate = camera_a.rotate + Vec3::new(rotate_value, rotate_value, 0.0);
+        AppEvent::KeyPressed {
+            key_code: VirtualKeyCode::V,
+        } => four_views = !four_views,
 
-            camera_view_proj = camera_view_proj
-                * Matrix4x4::from_rotate_y(Rad(camera_a.rotate.y))
-                * Matrix4x4::from_rotate_x(Rad(camera_a.rotate.x));
+        AppEvent::KeyPressed {
+            key_code: VirtualKeyCode::L,
+        } => lights = !lights,
 
-            handler.camera_view_proj(camera_view_proj.into());
-        }
+        AppEvent::MouseButtonPressed {
+            button: MouseButton::Left,
+            x,
+            y,
+        } if !four_views => {
+            // Com as quatro vistas, a câmera ocupa apenas um quarto da janela
+            let (width, height) = camera.screen_size();
+            let ray = camera.ray_from_screen(x, y, width, height);
 
-        AppEvent::Resize { width, height } => {
-            camera_a.screen_resize(width, height);
+            selected = scene::pick_cube(&ray);
         }
 
         AppEvent::Draw { graphics } => {
-            draw(graphics);
+            let grid = grid.get_or_insert_with(|| scene::grid(graphics));
+
+            if lights {
+                graphics.light(Light::ambient(Color::WHITE).intensity(0.2));
+                graphics.light(Light::directional(
+                    Vec3::new(-1.0, -2.0, -1.5),
+                    Color::WHITE,
+                ));
+            }
+
+            draw(graphics, grid, selected);
         }
 
         _ => {}
@@ -0,0 +1,179 @@
+use cgdraw::{
+    graphics::{Graphics, Mesh, Primitive},
+    math::{Aabb3, Matrix4x4, Point3, Ray3, Transform, Vec3},
+    Color,
+};
+
+/// A posição do cubo na cena. É a mesma para desenhá-lo e para testar os cliques nele.
+pub fn cube_transform() -> Transform<f32> {
+    Transform::from_translation(Vec3::new(0.0, 2.0, 0.0))
+}
+
+/// Testa se um raio do mundo atinge o cubo. O raio é levado para o espaço local do cubo,
+/// onde ele ocupa a caixa de -1 a 1 em cada eixo.
+pub fn pick_cube(ray: &Ray3<f32>) -> bool {
+    let bounds = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    let local_ray = ray.transform(&Matrix4x4::from(cube_transform().inverse()));
+
+    local_ray.intersect_aabb(&bounds).is_some()
+}
+
+/// Desenha um cubo 3D.
+pub fn cube(g: &mut Graphics) {
+    // Front Face
+    g.begin(Primitive::TriangleList);
+    g.color(Color::RED);
+    g.v3d(-1.0, 1.0, 1.0);
+    g.v3d(-1.0, -1.0, 1.0);
+    g.v3d(1.0, -1.0, 1.0);
+    g.v3d(1.0, 1.0, 1.0);
+    g.indices([0, 1, 2, 0, 2, 3].to_vec());
+    g.end();
+
+    // Right Face
+    g.begin(Primitive::TriangleList);
+    g.color(Color::GRAY);
+    g.v3d(1.0, 1.0, -1.0);
+    g.v3d(1.0, -1.0, -1.0);
+    g.v3d(1.0, -1.0, 1.0);
+    g.v3d(1.0, 1.0, 1.0);
+    g.indices([2, 1, 0, 3, 2, 0].to_vec());
+    g.end();
+
+    // Left Face
+    g.begin(Primitive::TriangleList);
+    g.color(Color::GOLD);
+    g.v3d(-1.0, 1.0, -1.0);
+    g.v3d(-1.0, -1.0, -1.0);
+    g.v3d(-1.0, -1.0, 1.0);
+    g.v3d(-1.0, 1.0, 1.0);
+    g.indices([0, 1, 2, 0, 2, 3].to_vec());
+    g.end();
+
+    // Bottom Face
+    g.begin(Primitive::TriangleList);
+    g.color(Color::CYAN);
+    g.v3d(-1.0, -1.0, -1.0);
+    g.v3d(-1.0, -1.0, 1.0);
+    g.v3d(1.0, -1.0, 1.0);
+    g.v3d(1.0, -1.0, -1.0);
+    g.indices([2, 1, 0, 3, 2, 0].to_vec());
+    g.end();
+
+    // Top Face
+    g.begin(Primitive::TriangleList);
+    g.color(Color::ORANGE);
+    g.v3d(-1.0, 1.0, -1.0);
+    g.v3d(-1.0, 1.0, 1.0);
+    g.v3d(1.0, 1.0, 1.0);
+    g.v3d(1.0, 1.0, -1.0);
+    g.indices([0, 1, 2, 0, 2, 3].to_vec());
+    g.end();
+
+    // Back Face
+    g.begin(Primitive::TriangleList);
+    g.color(Color::VIOLET);
+    g.v3d(-1.0, 1.0, -1.0);
+    g.v3d(-1.0, -1.0, -1.0);
+    g.v3d(1.0, -1.0, -1.0);
+    g.v3d(1.0, 1.0, -1.0);
+    g.indices([2, 1, 0, 2, 0, 3].to_vec());
+    g.end();
+}
+
+/// Desenha as arestas de um cubo um pouco maior que o de `cube`, destacando-o.
+pub fn cube_outline(g: &mut Graphics) {
+    g.color(Color::WHITE);
+
+    g.with_pushed(|g| {
+        g.t.scale(1.05, 1.05, 1.05);
+
+        g.begin(Primitive::LineList);
+        for (x, y, z) in [
+            (-1.0, -1.0, -1.0),
+            (1.0, -1.0, -1.0),
+            (1.0, 1.0, -1.0),
+            (-1.0, 1.0, -1.0),
+            (-1.0, -1.0, 1.0),
+            (1.0, -1.0, 1.0),
+            (1.0, 1.0, 1.0),
+            (-1.0, 1.0, 1.0),
+        ] {
+            g.v3d(x, y, z);
+        }
+        g.indices(
+            [
+                0, 1, 1, 2, 2, 3, 3, 0, 4, 5, 5, 6, 6, 7, 7, 4, 0, 4, 1, 5, 2, 6, 3, 7,
+            ]
+            .to_vec(),
+        );
+        g.end();
+    });
+}
+
+/// Monta a grade de linhas que melhora a percepção do espaço 3D. Como ela não muda, é
+/// criada uma única vez e reutilizada em todos os quadros.
+pub fn grid(g: &mut Graphics) -> Mesh {
+    g.build_mesh(Primitive::LineList, |m| {
+        m.color(Color::GRAY);
+
+        for j in -10..=10 {
+            m.v3d(-10.0, 0.0, j as f32);
+            m.v3d(10.0, 0.0, j as f32);
+
+            m.v3d(j as f32, 0.0, -10.0);
+            m.v3d(j as f32, 0.0, 10.0);
+        }
+    })
+}
+
+/// Desenha o eixo de coordenadas.
+pub fn draw_origin(g: &mut Graphics) {
+    g.color(Color::BLUE);
+
+    g.begin(Primitive::LineList);
+    {
+        g.v3d(0.0, 0.0, 0.0);
+        g.v3d(0.0, 0.0, 1.0);
+        g.indices([0, 1].to_vec());
+    }
+    g.end();
+
+    g.color(Color::GREEN);
+
+    g.begin(Primitive::LineList);
+    {
+        g.v3d(0.0, 0.0, 0.0);
+        g.v3d(0.0, 1.0, 0.0);
+        g.indices([0, 1].to_vec());
+    }
+    g.end();
+
+    g.color(Color::RED);
+    g.begin(Primitive::LineList);
+    {
+        g.v3d(0.0, 0.0, 0.0);
+        g.v3d(1.0, 0.0, 0.0);
+        g.indices([0, 1].to_vec());
+    }
+    g.end();
+}
+
+/// Função para desenhar os objetos na tela. Com `selected`, o cubo é destacado.
+pub fn draw(g: &mut Graphics, grid: &Mesh, selected: bool) {
+    g.with_pushed(|g| {
+        g.t.apply(cube_transform());
+        cube(g);
+
+        if selected {
+            cube_outline(g);
+        }
+    });
+
+    g.with_pushed(|g| {
+        g.t.scale(3.0, 3.0, 3.0);
+        draw_origin(g);
+    });
+
+    g.draw_mesh(grid, Matrix4x4::identity());
+}
@@ -0,0 +1,81 @@
+use cameras_controller_example::{
+    scene::{draw, grid},
+    ExampleCamera,
+};
+use cgdraw::{
+    angle::{Deg, Rad},
+    graphics::{DepthMode, Graphics},
+    math::{perspective_reversed_z, Matrix4x4, Point3, Vec3},
+    testing::FrameAssert,
+    Camera,
+};
+
+fn scene_rotation() -> Matrix4x4<f32> {
+    Matrix4x4::from_rotate_y(Rad(0.6)) * Matrix4x4::from_rotate_x(Rad(0.4))
+}
+
+fn view_proj() -> Matrix4x4<f32> {
+    let mut camera = ExampleCamera::default();
+    camera.screen_resize(256, 256);
+
+    camera.calc_view_proj() * scene_rotation()
+}
+
+/// A mesma câmera de `ExampleCamera`, com a projeção reversed-Z.
+fn reversed_z_view_proj() -> Matrix4x4<f32> {
+    let view = Matrix4x4::look_to_rh(
+        Point3::new(0.0, 0.0, 20.0),
+        Vec3::new(0.0, 0.0, -1.0),
+        Vec3::new(0.0, 1.0, 0.0),
+    );
+
+    perspective_reversed_z(1.0, Deg(30.0).into(), 0.1, 100.0) * view * scene_rotation()
+}
+
+fn draw_scene(g: &mut Graphics) {
+    let grid = grid(g);
+    draw(g, &grid, false);
+}
+
+/// Garante que a cena do exemplo (cubo, eixos e grade) continua sendo desenhada da mesma
+/// forma, vista de um ângulo onde três faces do cubo aparecem.
+#[test]
+fn cube_scene_matches_golden_image() {
+    FrameAssert::default()
+        .size(256, 256)
+        .view_proj(view_proj())
+        .allowed_mismatches(64)
+        .assert_matches("cube.png", draw_scene);
+}
+
+/// A mesma cena renderizada pelo rasterizador por software.
+#[test]
+fn cube_scene_matches_golden_image_in_software() {
+    FrameAssert::default()
+        .size(256, 256)
+        .view_proj(view_proj())
+        .software(true)
+        .assert_matches("cube_software.png", draw_scene);
+}
+
+/// Com reversed-Z, a cena deve ser idêntica à renderizada com a profundidade padrão.
+#[test]
+fn cube_scene_with_reversed_z_matches_golden_image() {
+    FrameAssert::default()
+        .size(256, 256)
+        .view_proj(reversed_z_view_proj())
+        .depth_mode(DepthMode::ReversedZ)
+        .allowed_mismatches(64)
+        .assert_matches("cube.png", draw_scene);
+}
+
+/// A cena com reversed-Z renderizada pelo rasterizador por software.
+#[test]
+fn cube_scene_with_reversed_z_matches_golden_image_in_software() {
+    FrameAssert::default()
+        .size(256, 256)
+        .view_proj(reversed_z_view_proj())
+        .depth_mode(DepthMode::ReversedZ)
+        .software(true)
+        .assert_matches("cube_software.png", draw_scene);
+}
@@ -0,0 +1,18 @@
+use cameras_controller_example::scene::pick_cube;
+use cgdraw::math::{Point3, Ray3, Vec3};
+
+/// Um raio vindo de +Z, paralelo ao chão, na altura `y`.
+fn ray_at_height(y: f32) -> Ray3<f32> {
+    Ray3::new(Point3::new(0.0, y, 10.0), Vec3::new(0.0, 0.0, -1.0))
+}
+
+#[test]
+fn clicks_pick_the_cube_where_it_is_drawn() {
+    // O cubo é desenhado de y = 1 a y = 3
+    assert!(pick_cube(&ray_at_height(2.0)));
+    assert!(pick_cube(&ray_at_height(2.9)));
+
+    // Abaixo dele só há a grade
+    assert!(!pick_cube(&ray_at_height(0.0)));
+    assert!(!pick_cube(&ray_at_height(-0.5)));
+}
@@ -1,30 +1,68 @@
 pub use cgdraw_app::builder::AppBuilder;
 pub use cgdraw_app::App;
 
-pub use cgdraw_core::color::Color;
+pub use cgdraw_core::color::{Color, SrgbColor};
 pub use cgdraw_core::keyboard::keys::VirtualKeyCode;
+pub use cgdraw_core::mouse::buttons::{MouseButton, MouseScrollDelta};
 
 pub use cgdraw_camera::Camera;
 pub use cgdraw_camera::CameraAttributes;
+pub use cgdraw_camera::FlyCamera;
+pub use cgdraw_camera::OrbitCamera;
+pub use cgdraw_camera::OrthoCamera2D;
 
 pub mod consts {}
 
+pub mod testing;
+
 pub mod graphics {
+    pub use cgdraw_app::graphics::CullingStats;
     pub use cgdraw_app::graphics::Graphics;
+    pub use cgdraw_app::graphics::Mesh;
+    pub use cgdraw_app::graphics::MeshBuilder;
     pub use cgdraw_app::graphics::Primitive;
+    pub use cgdraw_core::graphic::CameraViewport;
+    pub use cgdraw_core::graphic::DepthMode;
+    pub use cgdraw_core::graphic::PresentMode;
+    pub use cgdraw_core::graphic::RgbaImage;
+    pub use cgdraw_core::graphic::ScreenRegion;
     pub use cgdraw_core::graphic::Vertex;
+    pub use cgdraw_software::SoftwareRenderer;
+}
+
+pub mod light {
+    pub use cgdraw_core::light::*;
+    pub use cgdraw_core::uniforms::MAX_LIGHTS;
 }
 
 pub mod event {
     pub use cgdraw_app::events::AppEvent;
 }
 
+pub mod input {
+    pub use cgdraw_app::input::InputState;
+    pub use cgdraw_core::keyboard::modifiers::Modifiers;
+}
+
 pub mod math {
+    pub use cgdraw_core::frustum;
+    pub use cgdraw_core::frustum_lh;
     pub use cgdraw_core::orthographic;
+    pub use cgdraw_core::orthographic_lh;
     pub use cgdraw_core::perspective;
+    pub use cgdraw_core::perspective_infinite;
+    pub use cgdraw_core::perspective_infinite_lh;
+    pub use cgdraw_core::perspective_lh;
+    pub use cgdraw_core::perspective_reversed_z;
+    pub use cgdraw_core::perspective_reversed_z_lh;
+    pub use cgdraw_math::euler::*;
+    pub use cgdraw_math::geometry::*;
     pub use cgdraw_math::matrix::*;
     pub use cgdraw_math::point::*;
+    pub use cgdraw_math::quaternion::*;
+    pub use cgdraw_math::transform::*;
     pub use cgdraw_math::vector::*;
+    pub use cgdraw_math::viewport::*;
 }
 
 pub mod angle {
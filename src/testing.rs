@@ -0,0 +1,323 @@
+//! Utilitários para testes de regressão visual (golden images).
+//!
+//! Os quadros são renderizados em modo headless e comparados com imagens de referência
+//! salvas em disco. Para criar ou atualizar as imagens de referência, execute os testes com
+//! a variável de ambiente `CGDRAW_UPDATE_GOLDEN=1`.
+
+use std::path::{Path, PathBuf};
+
+use cgdraw_app::graphics::Graphics;
+use cgdraw_core::{
+    graphic::{DepthMode, RgbaImage},
+    uniforms::UniformsFloat32,
+};
+use cgdraw_math::matrix::Matrix4x4;
+use cgdraw_render::{Render, RenderState};
+use cgdraw_software::SoftwareRenderer;
+use cgdraw_state::State;
+
+/// Variável de ambiente que, quando definida como `1`, sobrescreve as imagens de referência
+/// com os quadros renderizados em vez de compará-los.
+pub const UPDATE_GOLDEN_ENV: &str = "CGDRAW_UPDATE_GOLDEN";
+
+/// Renderiza um único quadro em modo headless e retorna os pixels renderizados.
+pub fn render_frame<F>(width: u32, height: u32, view_proj: Matrix4x4<f32>, draw: F) -> RgbaImage
+where
+    F: FnOnce(&mut Graphics),
+{
+    render_frame_with_depth_mode(width, height, view_proj, DepthMode::default(), draw)
+}
+
+fn render_frame_with_depth_mode<F>(
+    width: u32,
+    height: u32,
+    view_proj: Matrix4x4<f32>,
+    depth_mode: DepthMode,
+    draw: F,
+) -> RgbaImage
+where
+    F: FnOnce(&mut Graphics),
+{
+    let mut uniforms = UniformsFloat32::default();
+    uniforms.camera.view_proj = view_proj.into();
+
+    let mut state = pollster::block_on(State::new_headless(width, height, uniforms));
+    state.set_depth_mode(depth_mode);
+
+    let mut render = Render::new(&mut state, RenderState::default());
+    draw(&mut Graphics::new(&mut render.render_state, render.state));
+    render.build();
+
+    state
+        .read_pixels()
+        .expect("O estado headless não possui uma textura offscreen!")
+}
+
+/// Renderiza um único quadro usando o rasterizador por software, sem precisar de um adaptador
+/// do wgpu.
+pub fn render_frame_software<F>(
+    width: u32,
+    height: u32,
+    view_proj: Matrix4x4<f32>,
+    draw: F,
+) -> RgbaImage
+where
+    F: FnOnce(&mut Graphics),
+{
+    render_frame_software_with_depth_mode(width, height, view_proj, DepthMode::default(), draw)
+}
+
+fn render_frame_software_with_depth_mode<F>(
+    width: u32,
+    height: u32,
+    view_proj: Matrix4x4<f32>,
+    depth_mode: DepthMode,
+    draw: F,
+) -> RgbaImage
+where
+    F: FnOnce(&mut Graphics),
+{
+    let mut renderer = SoftwareRenderer::new(width, height);
+    renderer.set_depth_mode(depth_mode);
+    renderer.uniforms.camera.view_proj = view_proj.into();
+
+    draw(&mut Graphics::new_software(&mut renderer));
+
+    renderer.to_image()
+}
+
+/// Resultado da comparação entre duas imagens.
+pub struct FrameDiff {
+    /// A quantidade de pixels cuja diferença excede a tolerância.
+    pub mismatched_pixels: usize,
+
+    /// A maior diferença encontrada entre dois canais de um mesmo pixel.
+    pub max_difference: u8,
+
+    /// Imagem que destaca em vermelho os pixels diferentes sobre a imagem esperada em tons de
+    /// cinza.
+    pub image: RgbaImage,
+}
+
+/// Compara duas imagens do mesmo tamanho, considerando iguais os pixels cujos canais diferem
+/// no máximo `tolerance`.
+pub fn diff_images(expected: &RgbaImage, actual: &RgbaImage, tolerance: u8) -> FrameDiff {
+    assert_eq!(
+        (expected.width, expected.height),
+        (actual.width, actual.height),
+        "As imagens comparadas possuem tamanhos diferentes!"
+    );
+
+    let mut mismatched_pixels = 0;
+    let mut max_difference = 0;
+    let mut pixels = Vec::with_capacity(expected.pixels.len());
+
+    for (e, a) in expected.pixels.chunks(4).zip(actual.pixels.chunks(4)) {
+        let difference = e
+            .iter()
+            .zip(a)
+            .map(|(e, a)| e.abs_diff(*a))
+            .max()
+            .unwrap_or(0);
+
+        max_difference = max_difference.max(difference);
+
+        if difference > tolerance {
+            mismatched_pixels += 1;
+            pixels.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            let luma = ((e[0] as u32 + e[1] as u32 + e[2] as u32) / 3 / 3) as u8;
+            pixels.extend_from_slice(&[luma, luma, luma, 255]);
+        }
+    }
+
+    FrameDiff {
+        mismatched_pixels,
+        max_difference,
+        image: RgbaImage::new(expected.width, expected.height, pixels),
+    }
+}
+
+/// Configuração de uma asserção de quadro contra uma imagem de referência.
+pub struct FrameAssert {
+    width: u32,
+    height: u32,
+    tolerance: u8,
+    allowed_mismatches: usize,
+    view_proj: Matrix4x4<f32>,
+    depth_mode: DepthMode,
+    directory: PathBuf,
+    software: bool,
+}
+
+impl Default for FrameAssert {
+    /// Quadro de 256x256 pixels, câmera identidade, tolerância de 2 por canal e imagens de
+    /// referência no diretório `tests/golden` do pacote que está sendo testado.
+    fn default() -> Self {
+        let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default();
+
+        Self {
+            width: 256,
+            height: 256,
+            tolerance: 2,
+            allowed_mismatches: 0,
+            view_proj: Matrix4x4::identity(),
+            depth_mode: DepthMode::default(),
+            directory: PathBuf::from(manifest_dir).join("tests").join("golden"),
+            software: false,
+        }
+    }
+}
+
+impl FrameAssert {
+    /// Define o tamanho do quadro renderizado.
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Define a diferença máxima permitida por canal para que dois pixels sejam iguais.
+    pub fn tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Define quantos pixels podem exceder a tolerância sem que a asserção falhe.
+    pub fn allowed_mismatches(mut self, allowed_mismatches: usize) -> Self {
+        self.allowed_mismatches = allowed_mismatches;
+        self
+    }
+
+    /// Define a matriz de visualização da projeção da câmera usada para renderizar o quadro.
+    pub fn view_proj(mut self, view_proj: Matrix4x4<f32>) -> Self {
+        self.view_proj = view_proj;
+        self
+    }
+
+    /// Define o modo do teste de profundidade, que deve combinar com a projeção de `view_proj`.
+    pub fn depth_mode(mut self, depth_mode: DepthMode) -> Self {
+        self.depth_mode = depth_mode;
+        self
+    }
+
+    /// Define o diretório onde ficam as imagens de referência.
+    pub fn directory<P: AsRef<Path>>(mut self, directory: P) -> Self {
+        self.directory = directory.as_ref().to_path_buf();
+        self
+    }
+
+    /// Define se o quadro será renderizado pelo rasterizador por software em vez da GPU.
+    pub fn software(mut self, software: bool) -> Self {
+        self.software = software;
+        self
+    }
+
+    /// Renderiza o quadro e o compara com a imagem de referência `name`. Em caso de falha,
+    /// salva o quadro renderizado (`*.actual.png`) e a imagem de diferença (`*.diff.png`) ao
+    /// lado da imagem de referência.
+    pub fn assert_matches<F>(&self, name: &str, draw: F)
+    where
+        F: FnOnce(&mut Graphics),
+    {
+        let actual = if self.software {
+            render_frame_software_with_depth_mode(
+                self.width,
+                self.height,
+                self.view_proj,
+                self.depth_mode,
+                draw,
+            )
+        } else {
+            render_frame_with_depth_mode(
+                self.width,
+                self.height,
+                self.view_proj,
+                self.depth_mode,
+                draw,
+            )
+        };
+
+        let reference = self.directory.join(name);
+
+        if std::env::var(UPDATE_GOLDEN_ENV).map_or(false, |v| v == "1") {
+            std::fs::create_dir_all(&self.directory).unwrap();
+            actual.save_png(&reference).unwrap();
+            return;
+        }
+
+        let expected = RgbaImage::load_png(&reference).unwrap_or_else(|e| {
+            panic!(
+                "Não foi possível carregar a imagem de referência {:?}: {}. Execute os testes com \
+                 {}=1 para criá-la.",
+                reference, e, UPDATE_GOLDEN_ENV
+            )
+        });
+
+        let actual_path = reference.with_extension("actual.png");
+        let diff_path = reference.with_extension("diff.png");
+
+        if (expected.width, expected.height) != (actual.width, actual.height) {
+            actual.save_png(&actual_path).unwrap();
+
+            panic!(
+                "O quadro renderizado ({}x{}) não tem o tamanho da imagem de referência {:?} \
+                 ({}x{}). Quadro salvo em {:?}.",
+                actual.width,
+                actual.height,
+                reference,
+                expected.width,
+                expected.height,
+                actual_path
+            );
+        }
+
+        let diff = diff_images(&expected, &actual, self.tolerance);
+
+        if diff.mismatched_pixels > self.allowed_mismatches {
+            actual.save_png(&actual_path).unwrap();
+            diff.image.save_png(&diff_path).unwrap();
+
+            panic!(
+                "O quadro renderizado difere da imagem de referência {:?}: {} pixels diferentes \
+                 (máximo permitido: {}), maior diferença por canal: {} (tolerância: {}). Quadro \
+                 salvo em {:?} e diferença em {:?}.",
+                reference,
+                diff.mismatched_pixels,
+                self.allowed_mismatches,
+                diff.max_difference,
+                self.tolerance,
+                actual_path,
+                diff_path
+            );
+        }
+
+        // Remove os artefatos de uma falha anterior
+        let _ = std::fs::remove_file(actual_path);
+        let _ = std::fs::remove_file(diff_path);
+    }
+}
+
+/// Renderiza o quadro com a configuração padrão de [`FrameAssert`] e o compara com a imagem
+/// de referência `name`, localizada em `tests/golden`.
+///
+/// ```no_run
+/// use cgdraw::{graphics::Primitive, testing::assert_frame_matches, Color};
+///
+/// assert_frame_matches("quad.png", |g| {
+///     g.color(Color::GOLD);
+///     g.begin(Primitive::TriangleList);
+///     g.v3d(-0.5, -0.5, 0.5);
+///     g.v3d(0.5, -0.5, 0.5);
+///     g.v3d(0.5, 0.5, 0.5);
+///     g.v3d(-0.5, 0.5, 0.5);
+///     g.indices([0, 1, 2, 2, 3, 0].to_vec());
+///     g.end();
+/// });
+/// ```
+pub fn assert_frame_matches<F>(name: &str, draw: F)
+where
+    F: FnOnce(&mut Graphics),
+{
+    FrameAssert::default().assert_matches(name, draw);
+}